@@ -5,8 +5,11 @@ from typing import Any as _Any
 
 from ._pydantic_core import (
     ArgsKwargs,
+    FieldInfo,
     MultiHostUrl,
     PydanticCustomError,
+    PydanticDropField,
+    PydanticDropFieldType,
     PydanticKnownError,
     PydanticOmit,
     PydanticSerializationError,
@@ -21,8 +24,10 @@ from ._pydantic_core import (
     TzInfo,
     Url,
     ValidationError,
+    ValidationWarning,
     __version__,
     from_json,
+    set_schema_cache_size,
     to_json,
     to_jsonable_python,
     validate_core_schema,
@@ -50,12 +55,16 @@ __all__ = [
     'Url',
     'MultiHostUrl',
     'ArgsKwargs',
+    'FieldInfo',
     'PydanticUndefined',
     'PydanticUndefinedType',
+    'PydanticDropField',
+    'PydanticDropFieldType',
     'SchemaError',
     'ErrorDetails',
     'InitErrorDetails',
     'ValidationError',
+    'ValidationWarning',
     'PydanticCustomError',
     'PydanticKnownError',
     'PydanticOmit',
@@ -66,6 +75,7 @@ __all__ = [
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'set_schema_cache_size',
     'validate_core_schema',
 ]
 
@@ -13,12 +13,14 @@ __all__ = [
     'build_info',
     '_recursion_limit',
     'ArgsKwargs',
+    'FieldInfo',
     'SchemaValidator',
     'SchemaSerializer',
     'Url',
     'MultiHostUrl',
     'SchemaError',
     'ValidationError',
+    'ValidationWarning',
     'PydanticCustomError',
     'PydanticKnownError',
     'PydanticOmit',
@@ -27,11 +29,14 @@ __all__ = [
     'PydanticSerializationUnexpectedValue',
     'PydanticUndefined',
     'PydanticUndefinedType',
+    'PydanticDropField',
+    'PydanticDropFieldType',
     'Some',
     'to_json',
     'from_json',
     'to_jsonable_python',
     'list_all_errors',
+    'set_schema_cache_size',
     'TzInfo',
     'validate_core_schema',
 ]
@@ -74,6 +79,20 @@ class SchemaValidator:
         """
         Create a new SchemaValidator.
 
+        Arguments:
+            schema: The [`CoreSchema`][pydantic_core.core_schema.CoreSchema] to use for validation.
+            config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to configure validation.
+        """
+    @classmethod
+    def from_schema_cached(cls, schema: CoreSchema, config: CoreConfig | None = None) -> SchemaValidator:
+        """
+        Create a new SchemaValidator, or return the one created by a previous call with the same `schema` and
+        `config` objects.
+
+        Cached validators are looked up by the identity of `schema` and `config`, not by their contents, so they
+        must not be mutated after being passed to this method. The number of cached validators is set with
+        [`set_schema_cache_size`][pydantic_core.set_schema_cache_size].
+
         Arguments:
             schema: The [`CoreSchema`][pydantic_core.core_schema.CoreSchema] to use for validation.
             config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to configure validation.
@@ -83,6 +102,35 @@ class SchemaValidator:
         """
         The title of the schema, as used in the heading of [`ValidationError.__str__()`][pydantic_core.ValidationError].
         """
+    def definition_refs(self) -> list[str]:
+        """
+        The `ref`s of the definitions used by the schema, sorted alphabetically.
+        """
+    def get_definition(self, reference: str) -> str:
+        """
+        Get a debug representation of the validator built for a definition, useful when debugging schemas.
+
+        Arguments:
+            reference: The `ref` of the definition.
+
+        Raises:
+            KeyError: If the schema has no definition with this `ref`.
+        """
+    def rebuild(self, schema: CoreSchema | None = None) -> None:
+        """
+        Rebuild the validator in place, e.g. once forward references used by the schema can be resolved.
+
+        The config the validator was created with is kept, and the title is recalculated.
+
+        Arguments:
+            schema: The new [`CoreSchema`][pydantic_core.core_schema.CoreSchema] to use for validation, if omitted
+                the schema the validator was created with is built again.
+
+        Raises:
+            SchemaError: If the schema is invalid, in which case the validator is left unchanged.
+            TypeError: If the validator was created by
+                [`from_schema_cached`][pydantic_core.SchemaValidator.from_schema_cached], since it may be shared.
+        """
     def validate_python(
         self,
         input: Any,
@@ -91,6 +139,8 @@ class SchemaValidator:
         from_attributes: bool | None = None,
         context: dict[str, Any] | None = None,
         self_instance: Any | None = None,
+        include: set[str] | None = None,
+        exclude: set[str] | None = None,
     ) -> Any:
         """
         Validate a Python object against the schema and return the validated object.
@@ -105,13 +155,20 @@ class SchemaValidator:
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation, this is used when running
                 validation from the `__init__` method of a model.
+            include: Names of the fields to validate, for partial validation; other fields are omitted from the
+                output and missing ones are not required. Fields of nested models and typed dicts can be selected
+                with dotted paths, e.g. `{'a', 'b.c'}`.
+            exclude: Names of the fields to omit from validation and the output, dotted paths can be used for
+                nested fields as with `include`.
 
         Raises:
             ValidationError: If validation fails.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated object.
+            The validated object, or a `(value, warnings)` tuple of the validated object and a list of
+            [`ValidationWarning`][pydantic_core.ValidationWarning]s if
+            [`CoreConfig.collect_warnings`][pydantic_core.core_schema.CoreConfig] is set.
         """
     def isinstance_python(
         self,
@@ -161,7 +218,8 @@ class SchemaValidator:
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated Python object.
+            The validated Python object, or a `(value, warnings)` tuple if
+            [`CoreConfig.collect_warnings`][pydantic_core.core_schema.CoreConfig] is set.
         """
     def validate_strings(
         self, input: _StringInput, *, strict: bool | None = None, context: dict[str, Any] | None = None
@@ -184,7 +242,8 @@ class SchemaValidator:
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated Python object.
+            The validated Python object, or a `(value, warnings)` tuple if
+            [`CoreConfig.collect_warnings`][pydantic_core.core_schema.CoreConfig] is set.
         """
     def validate_assignment(
         self,
@@ -195,7 +254,8 @@ class SchemaValidator:
         strict: bool | None = None,
         from_attributes: bool | None = None,
         context: dict[str, Any] | None = None,
-    ) -> dict[str, Any] | tuple[dict[str, Any], dict[str, Any] | None, set[str]]:
+        fields_set: frozenset[str] | None = None,
+    ) -> dict[str, Any] | tuple[dict[str, Any], dict[str, Any] | None, set[str]] | tuple[Any, frozenset[str]]:
         """
         Validate an assignment to a field on a model.
 
@@ -209,13 +269,16 @@ class SchemaValidator:
                 If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            fields_set: The current `__fields_set__` of the model, if provided the return value is a tuple of
+                `(result, new_fields_set)` where `new_fields_set` is a frozenset including `field_name`.
 
         Raises:
             ValidationError: If validation fails.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            Either the model dict or a tuple of `(model_data, model_extra, fields_set)`
+            Either the model dict or a tuple of `(model_data, model_extra, fields_set)`,
+            wrapped in a tuple with the new fields set if `fields_set` is provided.
         """
     def get_default_value(self, *, strict: bool | None = None, context: Any = None) -> Some | None:
         """
@@ -234,6 +297,25 @@ class SchemaValidator:
         Returns:
             `None` if the schema has no default value, otherwise a [`Some`][pydantic_core.Some] containing the default.
         """
+    def get_name(self) -> str:
+        """
+        Get the name of the validator, this is the same as `title`.
+        """
+    def get_fields(self) -> list[str] | None:
+        """
+        Get the names of the fields for a typed dict schema.
+
+        Returns:
+            The field names in definition order, or `None` if the schema is not a typed dict.
+        """
+    def get_model_fields(self) -> dict[str, FieldInfo] | None:
+        """
+        Get information about the fields of a typed dict schema.
+
+        Returns:
+            A dict of field names to [`FieldInfo`][pydantic_core.FieldInfo] in definition order,
+                or `None` if the schema is not a typed dict.
+        """
 
 _IncEx: TypeAlias = set[int] | set[str] | dict[int, _IncEx] | dict[str, _IncEx] | None
 
@@ -870,6 +952,41 @@ class ArgsKwargs:
     @property
     def kwargs(self) -> dict[str, Any] | None: ...
 
+@final
+class FieldInfo:
+    """
+    Information about a field of a typed dict schema, see
+    [`SchemaValidator.get_model_fields`][pydantic_core.SchemaValidator.get_model_fields].
+    """
+
+    @property
+    def required(self) -> bool: ...
+    @property
+    def alias(self) -> str | None: ...
+    @property
+    def has_default(self) -> bool: ...
+    @property
+    def default(self) -> Any | None:
+        """
+        The default value, `None` if there is no default or it's created by a `default_factory`.
+        """
+
+@final
+class ValidationWarning:
+    """
+    A warning collected during validation when [`CoreConfig.collect_warnings`][pydantic_core.core_schema.CoreConfig]
+    is set, it has the same structure as an error but doesn't stop validation.
+    """
+
+    @property
+    def type(self) -> str: ...
+    @property
+    def loc(self) -> tuple[int | str, ...]: ...
+    @property
+    def msg(self) -> str: ...
+    @property
+    def input(self) -> Any: ...
+
 @final
 class PydanticUndefinedType:
     def __copy__(self) -> Self: ...
@@ -877,6 +994,13 @@ class PydanticUndefinedType:
 
 PydanticUndefined: PydanticUndefinedType
 
+@final
+class PydanticDropFieldType:
+    def __copy__(self) -> Self: ...
+    def __deepcopy__(self, memo: Any) -> Self: ...
+
+PydanticDropField: PydanticDropFieldType
+
 def list_all_errors() -> list[ErrorTypeInfo]:
     """
     Get information about all built-in errors.
@@ -892,6 +1016,15 @@ class TzInfo(datetime.tzinfo):
     def fromutc(self, dt: datetime.datetime) -> datetime.datetime: ...
     def __deepcopy__(self, _memo: dict[Any, Any]) -> TzInfo: ...
 
+def set_schema_cache_size(size: int) -> None:
+    """
+    Set the maximum number of validators kept by
+    [`SchemaValidator.from_schema_cached`][pydantic_core.SchemaValidator.from_schema_cached], the default is 128.
+
+    Arguments:
+        size: The maximum number of cached validators, `0` disables the cache.
+    """
+
 def validate_core_schema(schema: CoreSchema, *, strict: bool | None = None) -> CoreSchema:
     """Validate a CoreSchema
     This currently uses lax mode for validation (i.e. will coerce strings to dates and such)
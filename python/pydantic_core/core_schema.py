@@ -57,6 +57,8 @@ class CoreConfig(TypedDict, total=False):
         loc_by_alias: Whether to use the used alias (or first alias for "field required" errors) instead of
             `field_names` to construct error `loc`s. Default is `True`.
         revalidate_instances: Whether instances of models and dataclasses should re-validate. Default is 'never'.
+        copy_on_model_validation: Whether model instances which aren't re-validated are returned as-is (`'none'`),
+            or copied with `copy.copy` (`'shallow'`) or `copy.deepcopy` (`'deep'`). Default is 'none'.
         validate_default: Whether to validate default values during validation. Default is `False`.
         populate_by_name: Whether an aliased field may be populated by its name as given by the model attribute,
             as well as the alias. (Replaces 'allow_population_by_field_name' in Pydantic v1.) Default is `False`.
@@ -70,13 +72,21 @@ class CoreConfig(TypedDict, total=False):
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
         ser_json_inf_nan: The serialization option for infinity and NaN values
             in float fields. Default is 'null'.
-        hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
+        hide_input_in_errors: Whether to hide input data from `ValidationError` representation. In a model's
+            config, the input of errors raised while validating the model is also replaced with `None`.
         validation_error_cause: Whether to add user-python excs to the __cause__ of a ValidationError.
             Requires exceptiongroup backport pre Python 3.11.
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
         regex_engine: The regex engine to use for regex pattern validation. Default is 'rust-regex'. See `StringSchema`.
         cache_strings: Whether to cache strings. Default is `True`, `True` or `'all'` is required to cache strings
             during general validation since validators don't know if they're in a key or a value.
+        strip_json_bom: Whether to strip a leading UTF-8 byte order mark from JSON input. Default is `False`.
+        collect_warnings: Whether to collect warnings raised during validation, e.g. for deprecated fields, instead of
+            emitting them, validation then returns a `(value, warnings)` tuple. Default is `False`.
+        validate_assignment: Whether a model class's `__setattr__` should validate assignments using the class's
+            `__pydantic_validator__`. Default is `False`.
+        get_validators: Whether to set a Pydantic V1 style `__get_validators__` classmethod on model classes, it
+            returns `[cls.__pydantic_validator__.validate_python]`. Default is `False`.
     """
 
     title: str
@@ -91,6 +101,8 @@ class CoreConfig(TypedDict, total=False):
     loc_by_alias: bool
     # whether instances of models and dataclasses (including subclass instances) should re-validate, default 'never'
     revalidate_instances: Literal['always', 'never', 'subclass-instances']
+    # whether model instances which aren't re-validated are copied, default 'none'
+    copy_on_model_validation: Literal['none', 'shallow', 'deep']
     # whether to validate default values during validation, default False
     validate_default: bool
     # used on typed-dicts and arguments
@@ -113,6 +125,10 @@ class CoreConfig(TypedDict, total=False):
     coerce_numbers_to_str: bool  # default: False
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
     cache_strings: Union[bool, Literal['all', 'keys', 'none']]  # default: 'True'
+    strip_json_bom: bool  # default: False
+    collect_warnings: bool  # default: False
+    validate_assignment: bool  # default: False
+    get_validators: bool  # default: False
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -194,6 +210,11 @@ class ValidationInfo(Protocol):
         """
         ...
 
+    @property
+    def metadata(self) -> Mapping[str, Any] | None:
+        """Read-only metadata set on the validator function's schema."""
+        ...
+
 
 ExpectedSerializationTypes = Literal[
     'none',
@@ -459,12 +480,26 @@ def computed_field(
 
 class AnySchema(TypedDict, total=False):
     type: Required[Literal['any']]
+    json_scalars_only: bool
+    schema: CoreSchema
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
 
-def any_schema(*, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None) -> AnySchema:
+def any_schema(
+    *,
+    json_scalars_only: bool | None = None,
+    schema: CoreSchema | None = None,
+    ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> AnySchema:
     """
     Returns a schema that matches any value, e.g.:
 
@@ -477,21 +512,46 @@ def any_schema(*, ref: str | None = None, metadata: Any = None, serialization: S
     ```
 
     Args:
+        json_scalars_only: Whether to reject JSON objects and arrays, Python inputs are not restricted
+        schema: If set, this schema is only a wrapper, e.g. to attach `metadata`, and validation and serialization
+            are delegated to `schema`
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='any', ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='any',
+        json_scalars_only=json_scalars_only,
+        schema=schema,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class NoneSchema(TypedDict, total=False):
     type: Required[Literal['none']]
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
 
-def none_schema(*, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None) -> NoneSchema:
+def none_schema(
+    *,
+    ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> NoneSchema:
     """
     Returns a schema that matches a None value, e.g.:
 
@@ -505,22 +565,40 @@ def none_schema(*, ref: str | None = None, metadata: Any = None, serialization:
 
     Args:
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='none', ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='none',
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class BoolSchema(TypedDict, total=False):
     type: Required[Literal['bool']]
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
 
 def bool_schema(
-    strict: bool | None = None, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    strict: bool | None = None,
+    ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> BoolSchema:
     """
     Returns a schema that matches a bool value, e.g.:
@@ -536,10 +614,21 @@ def bool_schema(
     Args:
         strict: Whether the value should be a bool or a value that can be converted to a bool
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='bool', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='bool',
+        strict=strict,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class IntSchema(TypedDict, total=False):
@@ -549,8 +638,14 @@ class IntSchema(TypedDict, total=False):
     ge: int
     lt: int
     gt: int
+    bit_width: Literal[8, 16, 32, 64]
+    unsigned: bool
     strict: bool
+    strict_no_bool: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -562,8 +657,13 @@ def int_schema(
     ge: int | None = None,
     lt: int | None = None,
     gt: int | None = None,
+    bit_width: Literal[8, 16, 32, 64] | None = None,
+    unsigned: bool | None = None,
     strict: bool | None = None,
+    strict_no_bool: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> IntSchema:
@@ -584,8 +684,14 @@ def int_schema(
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
+        bit_width: The value must fit in an integer of this many bits
+        unsigned: Whether the `bit_width` integer is unsigned, so the value must not be negative
         strict: Whether the value should be a int or a value that can be converted to a int
+        strict_no_bool: Whether to reject `bool` values, even in lax mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -596,8 +702,13 @@ def int_schema(
         ge=ge,
         lt=lt,
         gt=gt,
+        bit_width=bit_width,
+        unsigned=unsigned,
         strict=strict,
+        strict_no_bool=strict_no_bool,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -606,13 +717,19 @@ def int_schema(
 class FloatSchema(TypedDict, total=False):
     type: Required[Literal['float']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: True
+    allow_decimal_coercion: bool  # whether `Decimal` inputs may be coerced to float. default: True
+    numeric_string_locale: Literal['de', 'fr']
     multiple_of: float
     le: float
     ge: float
     lt: float
     gt: float
+    decimal_places: int
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -620,13 +737,18 @@ class FloatSchema(TypedDict, total=False):
 def float_schema(
     *,
     allow_inf_nan: bool | None = None,
+    allow_decimal_coercion: bool | None = None,
+    numeric_string_locale: Literal['de', 'fr'] | None = None,
     multiple_of: float | None = None,
     le: float | None = None,
     ge: float | None = None,
     lt: float | None = None,
     gt: float | None = None,
+    decimal_places: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> FloatSchema:
@@ -643,26 +765,38 @@ def float_schema(
 
     Args:
         allow_inf_nan: Whether to allow inf and nan values
+        allow_decimal_coercion: Whether to allow `Decimal` inputs, which may lose precision when converted to float
+        numeric_string_locale: In lax mode, parse strings using this locale's thousands and decimal separators,
+            e.g. `'1.234,5'` with `'de'` or `'1 234,5'` with `'fr'`
         multiple_of: The value must be a multiple of this number
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
+        decimal_places: The number of decimal places to round the value to, using round-half-even
         strict: Whether the value should be a float or a value that can be converted to a float
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
         type='float',
         allow_inf_nan=allow_inf_nan,
+        allow_decimal_coercion=allow_decimal_coercion,
+        numeric_string_locale=numeric_string_locale,
         multiple_of=multiple_of,
         le=le,
         ge=ge,
         lt=lt,
         gt=gt,
+        decimal_places=decimal_places,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -680,6 +814,9 @@ class DecimalSchema(TypedDict, total=False):
     decimal_places: int
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -696,6 +833,8 @@ def decimal_schema(
     decimal_places: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> DecimalSchema:
@@ -722,6 +861,9 @@ def decimal_schema(
         decimal_places: The maximum number of decimal places allowed
         strict: Whether the value should be a float or a value that can be converted to a float
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -737,6 +879,8 @@ def decimal_schema(
         allow_inf_nan=allow_inf_nan,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -745,15 +889,30 @@ def decimal_schema(
 class StringSchema(TypedDict, total=False):
     type: Required[Literal['str']]
     pattern: str
+    patterns: List[str]
+    not_pattern: str
+    not_patterns: List[str]
+    format: Literal['ipv4', 'ipv6', 'ip', 'email', 'uri', 'hostname', 'idna']
     max_length: int
     min_length: int
+    max_codepoints: int
+    json_max_length_bytes: int
+    starts_with: str
+    starts_with_any: List[str]
+    ends_with: str
     strip_whitespace: bool
     to_lower: bool
     to_upper: bool
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
     strict: bool
+    strict_subclass_check: bool
     coerce_numbers_to_str: bool
+    coerce_bool_to_str: bool
+    reject_empty_after_strip: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -761,15 +920,29 @@ class StringSchema(TypedDict, total=False):
 def str_schema(
     *,
     pattern: str | None = None,
+    patterns: list[str] | None = None,
+    not_pattern: str | None = None,
+    not_patterns: list[str] | None = None,
+    format: Literal['ipv4', 'ipv6', 'ip', 'email', 'uri', 'hostname', 'idna'] | None = None,
     max_length: int | None = None,
     min_length: int | None = None,
+    max_codepoints: int | None = None,
+    json_max_length_bytes: int | None = None,
+    starts_with: str | None = None,
+    starts_with_any: list[str] | None = None,
+    ends_with: str | None = None,
     strip_whitespace: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
     regex_engine: Literal['rust-regex', 'python-re'] | None = None,
     strict: bool | None = None,
+    strict_subclass_check: bool | None = None,
     coerce_numbers_to_str: bool | None = None,
+    coerce_bool_to_str: bool | None = None,
+    reject_empty_after_strip: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> StringSchema:
@@ -786,8 +959,17 @@ def str_schema(
 
     Args:
         pattern: A regex pattern that the value must match
+        patterns: A list of regex patterns that the value must all match, can't be combined with `pattern`
+        not_pattern: A regex pattern that the value must not match
+        not_patterns: A list of regex patterns that the value must not match, can't be combined with `not_pattern`
+        format: A format the value must have, the value is checked but still returned as a string
         max_length: The value must be at most this length
         min_length: The value must be at least this length
+        max_codepoints: The value must have at most this many Unicode codepoints, with its own error type
+        json_max_length_bytes: When validating JSON, the value's UTF-8 encoding must be at most this many bytes
+        starts_with: The value must start with this prefix
+        starts_with_any: The value must start with at least one of these prefixes
+        ends_with: The value must end with this suffix
         strip_whitespace: Whether to strip whitespace from the value
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
@@ -798,23 +980,43 @@ def str_schema(
             - `python-re` use the [`re`](https://docs.python.org/3/library/re.html) module,
               which supports all regex features, but may be slower.
         strict: Whether the value should be a string or a value that can be converted to a string
+        strict_subclass_check: Whether strict mode also rejects subclasses of `str`
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
+        coerce_bool_to_str: Whether to coerce `bool` values to `'true'` or `'false'` (not applicable in `strict` mode).
+        reject_empty_after_strip: Whether to strip whitespace and then reject the value if it's empty
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
         type='str',
         pattern=pattern,
+        patterns=patterns,
+        not_pattern=not_pattern,
+        not_patterns=not_patterns,
+        format=format,
         max_length=max_length,
         min_length=min_length,
+        max_codepoints=max_codepoints,
+        json_max_length_bytes=json_max_length_bytes,
+        starts_with=starts_with,
+        starts_with_any=starts_with_any,
+        ends_with=ends_with,
         strip_whitespace=strip_whitespace,
         to_lower=to_lower,
         to_upper=to_upper,
         regex_engine=regex_engine,
         strict=strict,
+        strict_subclass_check=strict_subclass_check,
         coerce_numbers_to_str=coerce_numbers_to_str,
+        coerce_bool_to_str=coerce_bool_to_str,
+        reject_empty_after_strip=reject_empty_after_strip,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -824,8 +1026,15 @@ class BytesSchema(TypedDict, total=False):
     type: Required[Literal['bytes']]
     max_length: int
     min_length: int
+    max_length_chars: int
+    min_length_chars: int
     strict: bool
+    json_format: Literal['raw', 'base64', 'hex']  # default: 'raw'
+    allow_buffer_protocol: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -834,8 +1043,14 @@ def bytes_schema(
     *,
     max_length: int | None = None,
     min_length: int | None = None,
+    max_length_chars: int | None = None,
+    min_length_chars: int | None = None,
     strict: bool | None = None,
+    json_format: Literal['raw', 'base64', 'hex'] | None = None,
+    allow_buffer_protocol: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> BytesSchema:
@@ -853,8 +1068,16 @@ def bytes_schema(
     Args:
         max_length: The value must be at most this length
         min_length: The value must be at least this length
+        max_length_chars: The value, decoded as UTF-8, must be at most this many characters
+        min_length_chars: The value, decoded as UTF-8, must be at least this many characters
         strict: Whether the value should be a bytes or a value that can be converted to a bytes
+        json_format: How JSON strings are decoded to bytes, either used as-is (`raw`), or decoded from `base64` or `hex`
+        allow_buffer_protocol: Whether to also accept objects supporting the buffer protocol, e.g. `memoryview`,
+            including in strict mode, their raw bytes are returned
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -862,8 +1085,14 @@ def bytes_schema(
         type='bytes',
         max_length=max_length,
         min_length=min_length,
+        max_length_chars=max_length_chars,
+        min_length_chars=min_length_chars,
         strict=strict,
+        json_format=json_format,
+        allow_buffer_protocol=allow_buffer_protocol,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -872,15 +1101,18 @@ def bytes_schema(
 class DateSchema(TypedDict, total=False):
     type: Required[Literal['date']]
     strict: bool
-    le: date
-    ge: date
-    lt: date
-    gt: date
+    le: Union[date, str]
+    ge: Union[date, str]
+    lt: Union[date, str]
+    gt: Union[date, str]
     now_op: Literal['past', 'future']
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -888,13 +1120,15 @@ class DateSchema(TypedDict, total=False):
 def date_schema(
     *,
     strict: bool | None = None,
-    le: date | None = None,
-    ge: date | None = None,
-    lt: date | None = None,
-    gt: date | None = None,
+    le: date | str | None = None,
+    ge: date | str | None = None,
+    lt: date | str | None = None,
+    gt: date | str | None = None,
     now_op: Literal['past', 'future'] | None = None,
     now_utc_offset: int | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> DateSchema:
@@ -910,6 +1144,8 @@ def date_schema(
     assert v.validate_python(date(2019, 6, 1)) == date(2019, 6, 1)
     ```
 
+    The `le`, `ge`, `lt` and `gt` constraints may also be given as ISO 8601 date strings, e.g. `le='2020-01-01'`.
+
     Args:
         strict: Whether the value should be a date or a value that can be converted to a date
         le: The value must be less than or equal to this date
@@ -919,6 +1155,9 @@ def date_schema(
         now_op: The value must be in the past or future relative to the current date
         now_utc_offset: The value must be in the past or future relative to the current date with this utc offset
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -932,6 +1171,8 @@ def date_schema(
         now_op=now_op,
         now_utc_offset=now_utc_offset,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -940,13 +1181,16 @@ def date_schema(
 class TimeSchema(TypedDict, total=False):
     type: Required[Literal['time']]
     strict: bool
-    le: time
-    ge: time
-    lt: time
-    gt: time
+    le: Union[time, str]
+    ge: Union[time, str]
+    lt: Union[time, str]
+    gt: Union[time, str]
     tz_constraint: Union[Literal['aware', 'naive'], int]
     microseconds_precision: Literal['truncate', 'error']
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -954,13 +1198,15 @@ class TimeSchema(TypedDict, total=False):
 def time_schema(
     *,
     strict: bool | None = None,
-    le: time | None = None,
-    ge: time | None = None,
-    lt: time | None = None,
-    gt: time | None = None,
+    le: time | str | None = None,
+    ge: time | str | None = None,
+    lt: time | str | None = None,
+    gt: time | str | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> TimeSchema:
@@ -976,6 +1222,8 @@ def time_schema(
     assert v.validate_python(time(9, 0, 0)) == time(9, 0, 0)
     ```
 
+    The `le`, `ge`, `lt` and `gt` constraints may also be given as ISO 8601 time strings, e.g. `le='12:00:00'`.
+
     Args:
         strict: Whether the value should be a time or a value that can be converted to a time
         le: The value must be less than or equal to this time
@@ -985,6 +1233,9 @@ def time_schema(
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -998,6 +1249,8 @@ def time_schema(
         tz_constraint=tz_constraint,
         microseconds_precision=microseconds_precision,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1015,8 +1268,12 @@ class DatetimeSchema(TypedDict, total=False):
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
-    microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
+    microseconds_precision: Literal['truncate', 'round', 'error']  # default: 'truncate'
+    timestamp_unit: Literal['seconds', 'milliseconds', 'microseconds']
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -1031,8 +1288,11 @@ def datetime_schema(
     now_op: Literal['past', 'future'] | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
     now_utc_offset: int | None = None,
-    microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    microseconds_precision: Literal['truncate', 'round', 'error'] = 'truncate',
+    timestamp_unit: Literal['seconds', 'milliseconds', 'microseconds'] | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> DatetimeSchema:
@@ -1059,8 +1319,14 @@ def datetime_schema(
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
             TODO: use of a tzinfo where offset changes based on the datetime is not yet supported
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
-        microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        microseconds_precision: The behavior when seconds have more than 6 digits: truncate them, round to the
+            nearest microsecond, or raise a `datetime_sub_microsecond` error
+        timestamp_unit: The unit of int and float unix timestamps in lax mode, by default timestamps larger
+            than `2e10` are interpreted as milliseconds and smaller ones as seconds
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1075,7 +1341,10 @@ def datetime_schema(
         tz_constraint=tz_constraint,
         now_utc_offset=now_utc_offset,
         microseconds_precision=microseconds_precision,
+        timestamp_unit=timestamp_unit,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1084,12 +1353,15 @@ def datetime_schema(
 class TimedeltaSchema(TypedDict, total=False):
     type: Required[Literal['timedelta']]
     strict: bool
-    le: timedelta
-    ge: timedelta
-    lt: timedelta
-    gt: timedelta
+    le: Union[timedelta, str]
+    ge: Union[timedelta, str]
+    lt: Union[timedelta, str]
+    gt: Union[timedelta, str]
     microseconds_precision: Literal['truncate', 'error']
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -1097,12 +1369,14 @@ class TimedeltaSchema(TypedDict, total=False):
 def timedelta_schema(
     *,
     strict: bool | None = None,
-    le: timedelta | None = None,
-    ge: timedelta | None = None,
-    lt: timedelta | None = None,
-    gt: timedelta | None = None,
+    le: timedelta | str | None = None,
+    ge: timedelta | str | None = None,
+    lt: timedelta | str | None = None,
+    gt: timedelta | str | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> TimedeltaSchema:
@@ -1118,6 +1392,8 @@ def timedelta_schema(
     assert v.validate_python(timedelta(hours=12)) == timedelta(hours=12)
     ```
 
+    The `le`, `ge`, `lt` and `gt` constraints may also be given as ISO 8601 duration strings, e.g. `le='P1D'`.
+
     Args:
         strict: Whether the value should be a timedelta or a value that can be converted to a timedelta
         le: The value must be less than or equal to this timedelta
@@ -1126,6 +1402,9 @@ def timedelta_schema(
         gt: The value must be strictly greater than this timedelta
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1138,6 +1417,8 @@ def timedelta_schema(
         gt=gt,
         microseconds_precision=microseconds_precision,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1147,12 +1428,21 @@ class LiteralSchema(TypedDict, total=False):
     type: Required[Literal['literal']]
     expected: Required[List[Any]]
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
 
 def literal_schema(
-    expected: list[Any], *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    expected: list[Any],
+    *,
+    ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> LiteralSchema:
     """
     Returns a schema that matches a literal value, e.g.:
@@ -1168,10 +1458,21 @@ def literal_schema(
     Args:
         expected: The value must be one of these values
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='literal', expected=expected, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='literal',
+        expected=expected,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class EnumSchema(TypedDict, total=False):
@@ -1182,6 +1483,9 @@ class EnumSchema(TypedDict, total=False):
     missing: Callable[[Any], Any]
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -1194,6 +1498,8 @@ def enum_schema(
     missing: Callable[[Any], Any] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> EnumSchema:
@@ -1221,6 +1527,9 @@ def enum_schema(
         missing: A function to use when the value is not found in the enum, from `_missing_`
         strict: Whether to use strict mode, defaults to False
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1232,6 +1541,8 @@ def enum_schema(
         missing=missing,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1246,6 +1557,9 @@ class IsInstanceSchema(TypedDict, total=False):
     cls: Required[Any]
     cls_repr: str
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -1255,6 +1569,8 @@ def is_instance_schema(
     *,
     cls_repr: str | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> IsInstanceSchema:
@@ -1273,14 +1589,24 @@ def is_instance_schema(
     ```
 
     Args:
-        cls: The value must be an instance of this class
+        cls: The value must be an instance of this class, or of any class in a tuple of classes
         cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='is-instance', cls=cls, cls_repr=cls_repr, ref=ref, metadata=metadata, serialization=serialization
+        type='is-instance',
+        cls=cls,
+        cls_repr=cls_repr,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
@@ -1289,6 +1615,9 @@ class IsSubclassSchema(TypedDict, total=False):
     cls: Required[Type[Any]]
     cls_repr: str
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -1298,6 +1627,8 @@ def is_subclass_schema(
     *,
     cls_repr: str | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> IsInstanceSchema:
@@ -1322,23 +1653,45 @@ def is_subclass_schema(
         cls: The value must be a subclass of this class
         cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='is-subclass', cls=cls, cls_repr=cls_repr, ref=ref, metadata=metadata, serialization=serialization
+        type='is-subclass',
+        cls=cls,
+        cls_repr=cls_repr,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
 class CallableSchema(TypedDict, total=False):
     type: Required[Literal['callable']]
+    min_args: int
+    max_args: int
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
 
 def callable_schema(
-    *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    *,
+    min_args: int | None = None,
+    max_args: int | None = None,
+    ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> CallableSchema:
     """
     Returns a schema that checks if a value is callable, equivalent to python's `callable` method, e.g.:
@@ -1352,11 +1705,25 @@ def callable_schema(
     ```
 
     Args:
+        min_args: The callable must be callable with this many positional arguments, checked with `inspect.signature`
+        max_args: The callable must accept at least this many positional arguments, checked with `inspect.signature`
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='callable', ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='callable',
+        min_args=min_args,
+        max_args=max_args,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class UuidSchema(TypedDict, total=False):
@@ -1364,6 +1731,9 @@ class UuidSchema(TypedDict, total=False):
     version: Literal[1, 3, 4, 5]
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -1373,11 +1743,20 @@ def uuid_schema(
     version: Literal[1, 3, 4, 5] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> UuidSchema:
     return _dict_not_none(
-        type='uuid', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+        type='uuid',
+        version=version,
+        strict=strict,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
@@ -1399,8 +1778,14 @@ class ListSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    exact_length: int
+    output_type: Literal['list', 'tuple']
+    mutate_in_place: bool
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
 
@@ -1410,8 +1795,13 @@ def list_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    exact_length: int | None = None,
+    output_type: Literal['list', 'tuple'] | None = None,
+    mutate_in_place: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
 ) -> ListSchema:
@@ -1430,8 +1820,15 @@ def list_schema(
         items_schema: The value must be a list of items that match this schema
         min_length: The value must be a list with at least this many items
         max_length: The value must be a list with at most this many items
+        exact_length: The value must be a list with exactly this many items
+        output_type: Whether validated items are returned as a `list` (the default) or a `tuple`
+        mutate_in_place: If the input is a Python `list`, replace its items with the validated items and return it
+            instead of a new list, only safe if the caller doesn't use the input list afterwards
         strict: The value must be a list with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1440,8 +1837,13 @@ def list_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        exact_length=exact_length,
+        output_type=output_type,
+        mutate_in_place=mutate_in_place,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1454,6 +1856,8 @@ def tuple_positional_schema(
     extras_schema: CoreSchema | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
 ) -> TupleSchema:
@@ -1478,6 +1882,9 @@ def tuple_positional_schema(
             if the length is variable. So this field won't be set from a `typing.Tuple` annotation on a pydantic model.
         strict: The value must be a tuple with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1491,6 +1898,8 @@ def tuple_positional_schema(
         variadic_item_index=variadic_item_index,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1502,8 +1911,11 @@ def tuple_variable_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    exact_length: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
 ) -> TupleSchema:
@@ -1524,8 +1936,12 @@ def tuple_variable_schema(
         items_schema: The value must be a tuple with items that match this schema
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
+        exact_length: The value must be a tuple with exactly this many items
         strict: The value must be a tuple with exactly this many items
         ref: Optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1534,8 +1950,11 @@ def tuple_variable_schema(
         variadic_item_index=0,
         min_length=min_length,
         max_length=max_length,
+        exact_length=exact_length,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1547,8 +1966,12 @@ class TupleSchema(TypedDict, total=False):
     variadic_item_index: int
     min_length: int
     max_length: int
+    exact_length: int
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
 
@@ -1559,8 +1982,11 @@ def tuple_schema(
     variadic_item_index: int | None = None,
     min_length: int | None = None,
     max_length: int | None = None,
+    exact_length: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
 ) -> TupleSchema:
@@ -1583,8 +2009,12 @@ def tuple_schema(
         variadic_item_index: The index of the schema in `items_schema` to be treated as variadic (following PEP 646)
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
+        exact_length: The value must be a tuple with exactly this many items
         strict: The value must be a tuple with exactly this many items
         ref: Optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1594,8 +2024,11 @@ def tuple_schema(
         variadic_item_index=variadic_item_index,
         min_length=min_length,
         max_length=max_length,
+        exact_length=exact_length,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1606,8 +2039,12 @@ class SetSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    exact_length: int
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -1617,8 +2054,11 @@ def set_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    exact_length: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> SetSchema:
@@ -1639,8 +2079,12 @@ def set_schema(
         items_schema: The value must be a set with items that match this schema
         min_length: The value must be a set with at least this many items
         max_length: The value must be a set with at most this many items
+        exact_length: The value must be a set with exactly this many items
         strict: The value must be a set with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1649,8 +2093,11 @@ def set_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        exact_length=exact_length,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1661,8 +2108,12 @@ class FrozenSetSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    exact_length: int
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -1672,8 +2123,11 @@ def frozenset_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    exact_length: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> FrozenSetSchema:
@@ -1694,8 +2148,12 @@ def frozenset_schema(
         items_schema: The value must be a frozenset with items that match this schema
         min_length: The value must be a frozenset with at least this many items
         max_length: The value must be a frozenset with at most this many items
+        exact_length: The value must be a frozenset with exactly this many items
         strict: The value must be a frozenset with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1704,8 +2162,11 @@ def frozenset_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        exact_length=exact_length,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1717,6 +2178,9 @@ class GeneratorSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
 
@@ -1727,6 +2191,8 @@ def generator_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
 ) -> GeneratorSchema:
@@ -1754,6 +2220,9 @@ def generator_schema(
         min_length: The value must be a generator that yields at least this many items
         max_length: The value must be a generator that yields at most this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1763,6 +2232,8 @@ def generator_schema(
         min_length=min_length,
         max_length=max_length,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1790,8 +2261,16 @@ class DictSchema(TypedDict, total=False):
     values_schema: CoreSchema  # default: AnySchema
     min_length: int
     max_length: int
+    forbidden_keys: List[str]
+    required_keys: List[str]
+    allow_pairs_list: bool
+    duplicate_keys_behavior: Literal['use_last', 'use_first', 'error']  # default: 'use_last'
+    strict_keys: bool  # default: False
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: IncExDictOrElseSerSchema
 
@@ -1802,8 +2281,15 @@ def dict_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    forbidden_keys: list[str] | None = None,
+    required_keys: list[str] | None = None,
+    allow_pairs_list: bool | None = None,
+    duplicate_keys_behavior: Literal['use_last', 'use_first', 'error'] | None = None,
+    strict_keys: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> DictSchema:
@@ -1825,8 +2311,17 @@ def dict_schema(
         values_schema: The value must be a dict with values that match this schema
         min_length: The value must be a dict with at least this many items
         max_length: The value must be a dict with at most this many items
+        forbidden_keys: String keys which the dict must not contain
+        required_keys: String keys which the dict must contain
+        allow_pairs_list: In lax mode, also accept a list of `[key, value]` pairs and convert it to a dict
+        duplicate_keys_behavior: Which value to keep when a key occurs more than once, e.g. in JSON or after
+            key validation, `'use_last'` (the default), `'use_first'`, or `'error'` to raise a validation error
+        strict_keys: Whether to reject keys which aren't strings before they're passed to `keys_schema`
         strict: Whether the keys and values should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -1836,8 +2331,15 @@ def dict_schema(
         values_schema=values_schema,
         min_length=min_length,
         max_length=max_length,
+        forbidden_keys=forbidden_keys,
+        required_keys=required_keys,
+        allow_pairs_list=allow_pairs_list,
+        duplicate_keys_behavior=duplicate_keys_behavior,
+        strict_keys=strict_keys,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -1860,6 +2362,7 @@ class WithInfoValidatorFunctionSchema(TypedDict, total=False):
     type: Required[Literal['with-info']]
     function: Required[WithInfoValidatorFunction]
     field_name: str
+    metadata: Dict[str, Any]
 
 
 ValidationFunction = Union[NoInfoValidatorFunctionSchema, WithInfoValidatorFunctionSchema]
@@ -1868,7 +2371,11 @@ ValidationFunction = Union[NoInfoValidatorFunctionSchema, WithInfoValidatorFunct
 class _ValidatorFunctionSchema(TypedDict, total=False):
     function: Required[ValidationFunction]
     schema: Required[CoreSchema]
+    skip_on_missing_context: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -1925,6 +2432,8 @@ def with_info_before_validator_function(
     schema: CoreSchema,
     *,
     field_name: str | None = None,
+    info_metadata: Dict[str, Any] | None = None,
+    skip_on_missing_context: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1953,6 +2462,8 @@ def with_info_before_validator_function(
     Args:
         function: The validator function to call
         field_name: The name of the field
+        info_metadata: Read-only metadata passed to the function as `info.metadata`
+        skip_on_missing_context: Whether to skip calling the function when validating without a `context`
         schema: The schema to validate the output of the validator function
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1960,8 +2471,9 @@ def with_info_before_validator_function(
     """
     return _dict_not_none(
         type='function-before',
-        function=_dict_not_none(type='with-info', function=function, field_name=field_name),
+        function=_dict_not_none(type='with-info', function=function, field_name=field_name, metadata=info_metadata),
         schema=schema,
+        skip_on_missing_context=skip_on_missing_context,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2018,6 +2530,8 @@ def with_info_after_validator_function(
     schema: CoreSchema,
     *,
     field_name: str | None = None,
+    info_metadata: Dict[str, Any] | None = None,
+    skip_on_missing_context: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2047,14 +2561,17 @@ def with_info_after_validator_function(
         function: The validator function to call after the schema is validated
         schema: The schema to validate before the validator function
         field_name: The name of the field this validators is applied to, if any
+        info_metadata: Read-only metadata passed to the function as `info.metadata`
+        skip_on_missing_context: Whether to skip calling the function when validating without a `context`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
         type='function-after',
-        function=_dict_not_none(type='with-info', function=function, field_name=field_name),
+        function=_dict_not_none(type='with-info', function=function, field_name=field_name, metadata=info_metadata),
         schema=schema,
+        skip_on_missing_context=skip_on_missing_context,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2088,11 +2605,20 @@ class WithInfoWrapValidatorFunctionSchema(TypedDict, total=False):
 WrapValidatorFunction = Union[NoInfoWrapValidatorFunctionSchema, WithInfoWrapValidatorFunctionSchema]
 
 
+# (input_value: Any, error: ValidationError, info: ValidationInfo, /) -> Any
+WrapValidatorOnErrorFunction = Callable[[Any, Any, ValidationInfo], Any]
+
+
 class WrapValidatorFunctionSchema(TypedDict, total=False):
     type: Required[Literal['function-wrap']]
     function: Required[WrapValidatorFunction]
     schema: Required[CoreSchema]
+    on_error: WrapValidatorOnErrorFunction
+    skip_on_missing_context: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -2101,7 +2627,10 @@ def no_info_wrap_validator_function(
     function: NoInfoWrapValidatorFunction,
     schema: CoreSchema,
     *,
+    on_error: WrapValidatorOnErrorFunction | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> WrapValidatorFunctionSchema:
@@ -2129,7 +2658,12 @@ def no_info_wrap_validator_function(
     Args:
         function: The validator function to call
         schema: The schema to validate the output of the validator function
+        on_error: A function called with the input, the `ValidationError` and the validation info if validation
+            fails, its return value is used instead of raising the error
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -2137,7 +2671,10 @@ def no_info_wrap_validator_function(
         type='function-wrap',
         function={'type': 'no-info', 'function': function},
         schema=schema,
+        on_error=on_error,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -2148,7 +2685,12 @@ def with_info_wrap_validator_function(
     schema: CoreSchema,
     *,
     field_name: str | None = None,
+    info_metadata: Dict[str, Any] | None = None,
+    skip_on_missing_context: bool | None = None,
+    on_error: WrapValidatorOnErrorFunction | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> WrapValidatorFunctionSchema:
@@ -2178,15 +2720,26 @@ def with_info_wrap_validator_function(
         function: The validator function to call
         schema: The schema to validate the output of the validator function
         field_name: The name of the field this validators is applied to, if any
+        info_metadata: Read-only metadata passed to the function as `info.metadata`
+        skip_on_missing_context: Whether to skip calling the function when validating without a `context`
+        on_error: A function called with the input, the `ValidationError` and the validation info if validation
+            fails, its return value is used instead of raising the error
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
         type='function-wrap',
-        function=_dict_not_none(type='with-info', function=function, field_name=field_name),
+        function=_dict_not_none(type='with-info', function=function, field_name=field_name, metadata=info_metadata),
         schema=schema,
+        on_error=on_error,
+        skip_on_missing_context=skip_on_missing_context,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -2196,6 +2749,9 @@ class PlainValidatorFunctionSchema(TypedDict, total=False):
     type: Required[Literal['function-plain']]
     function: Required[ValidationFunction]
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -2204,6 +2760,8 @@ def no_info_plain_validator_function(
     function: NoInfoValidatorFunction,
     *,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> PlainValidatorFunctionSchema:
@@ -2225,6 +2783,9 @@ def no_info_plain_validator_function(
     Args:
         function: The validator function to call
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -2232,6 +2793,8 @@ def no_info_plain_validator_function(
         type='function-plain',
         function={'type': 'no-info', 'function': function},
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -2241,7 +2804,10 @@ def with_info_plain_validator_function(
     function: WithInfoValidatorFunction,
     *,
     field_name: str | None = None,
+    info_metadata: Dict[str, Any] | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> PlainValidatorFunctionSchema:
@@ -2263,14 +2829,20 @@ def with_info_plain_validator_function(
     Args:
         function: The validator function to call
         field_name: The name of the field this validators is applied to, if any
+        info_metadata: Read-only metadata passed to the function as `info.metadata`
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
         type='function-plain',
-        function=_dict_not_none(type='with-info', function=function, field_name=field_name),
+        function=_dict_not_none(type='with-info', function=function, field_name=field_name, metadata=info_metadata),
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -2285,6 +2857,9 @@ class WithDefaultSchema(TypedDict, total=False):
     validate_default: bool  # default: False
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -2298,6 +2873,8 @@ def with_default_schema(
     validate_default: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> WithDefaultSchema:
@@ -2323,6 +2900,9 @@ def with_default_schema(
         validate_default: Whether the default value should be validated
         strict: Whether the underlying schema should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -2334,6 +2914,8 @@ def with_default_schema(
         validate_default=validate_default,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -2345,8 +2927,13 @@ def with_default_schema(
 class NullableSchema(TypedDict, total=False):
     type: Required[Literal['nullable']]
     schema: Required[CoreSchema]
+    none_types: List[Any]
+    null_value: Any
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -2354,8 +2941,12 @@ class NullableSchema(TypedDict, total=False):
 def nullable_schema(
     schema: CoreSchema,
     *,
+    none_types: list[Any] | None = None,
+    null_value: Any = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> NullableSchema:
@@ -2372,13 +2963,28 @@ def nullable_schema(
 
     Args:
         schema: The schema to wrap
+        none_types: Other values which should be treated as `None`, and validated to `None`, inputs match if they
+            have the same type and are equal, any NaN matches a NaN
+        null_value: The value to return for `None` inputs (and `none_types`) instead of `None`
         strict: Whether the underlying schema should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='nullable', schema=schema, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+        type='nullable',
+        schema=schema,
+        none_types=none_types,
+        null_value=null_value,
+        strict=strict,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
@@ -2390,9 +2996,14 @@ class UnionSchema(TypedDict, total=False):
     custom_error_type: str
     custom_error_message: str
     custom_error_context: Dict[str, Union[str, int, float]]
-    mode: Literal['smart', 'left_to_right']  # default: 'smart'
+    mode: Literal['smart', 'left_to_right', 'best_match']  # default: 'smart'
+    exact_json_type: bool  # default: False
+    union_debug: bool
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -2404,9 +3015,13 @@ def union_schema(
     custom_error_type: str | None = None,
     custom_error_message: str | None = None,
     custom_error_context: dict[str, str | int] | None = None,
-    mode: Literal['smart', 'left_to_right'] | None = None,
+    mode: Literal['smart', 'left_to_right', 'best_match'] | None = None,
+    exact_json_type: bool | None = None,
+    union_debug: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> UnionSchema:
@@ -2424,6 +3039,7 @@ def union_schema(
 
     Args:
         choices: The schemas to match. If a tuple, the second item is used as the label for the case.
+            Choices are tried in order of their schema's `priority` key, highest first, defaulting to `0`.
         auto_collapse: whether to automatically collapse unions with one element to the inner validator, default true
         custom_error_type: The custom error type to use if the validation fails
         custom_error_message: The custom error message to use if the validation fails
@@ -2431,8 +3047,18 @@ def union_schema(
         mode: How to select which choice to return
             * `smart` (default) will try to return the choice which is the closest match to the input value
             * `left_to_right` will return the first choice in `choices` which succeeds validation
+            * `best_match` will return the first choice which succeeds validation without coercion,
+              otherwise the first choice which succeeds validation
+        exact_json_type: Whether JSON input should first be matched, in strict mode, against the choices whose
+            schema type corresponds to its JSON type, e.g. so `true` is validated as a `bool` and `1` as an `int`,
+            preferring exact matches as in `smart` mode, before falling back to `mode`
+        union_debug: Whether to log which choices were tried and which was selected to the `pydantic_core.union`
+            logger, at `DEBUG` level
         strict: Whether the underlying schemas should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -2444,8 +3070,12 @@ def union_schema(
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
         mode=mode,
+        exact_json_type=exact_json_type,
+        union_debug=union_debug,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -2461,6 +3091,9 @@ class TaggedUnionSchema(TypedDict, total=False):
     strict: bool
     from_attributes: bool  # default: True
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -2475,6 +3108,8 @@ def tagged_union_schema(
     strict: bool | None = None,
     from_attributes: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> TaggedUnionSchema:
@@ -2530,6 +3165,9 @@ def tagged_union_schema(
         strict: Whether the underlying schemas should be validated with strict mode
         from_attributes: Whether to use the attributes of the object to retrieve the discriminator value
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -2543,6 +3181,8 @@ def tagged_union_schema(
         strict=strict,
         from_attributes=from_attributes,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -2552,12 +3192,21 @@ class ChainSchema(TypedDict, total=False):
     type: Required[Literal['chain']]
     steps: Required[List[CoreSchema]]
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
 
 def chain_schema(
-    steps: list[CoreSchema], *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    steps: list[CoreSchema],
+    *,
+    ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> ChainSchema:
     """
     Returns a schema that chains the provided validation schemas, e.g.:
@@ -2580,10 +3229,21 @@ def chain_schema(
     Args:
         steps: The schemas to chain
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='chain', steps=steps, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='chain',
+        steps=steps,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class LaxOrStrictSchema(TypedDict, total=False):
@@ -2592,6 +3252,9 @@ class LaxOrStrictSchema(TypedDict, total=False):
     strict_schema: Required[CoreSchema]
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -2602,6 +3265,8 @@ def lax_or_strict_schema(
     *,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> LaxOrStrictSchema:
@@ -2636,6 +3301,9 @@ def lax_or_strict_schema(
         strict_schema: The strict schema to use
         strict: Whether the strict schema should be used
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -2645,6 +3313,8 @@ def lax_or_strict_schema(
         strict_schema=strict_schema,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -2655,6 +3325,9 @@ class JsonOrPythonSchema(TypedDict, total=False):
     json_schema: Required[CoreSchema]
     python_schema: Required[CoreSchema]
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -2664,6 +3337,8 @@ def json_or_python_schema(
     python_schema: CoreSchema,
     *,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> JsonOrPythonSchema:
@@ -2694,6 +3369,9 @@ def json_or_python_schema(
         json_schema: The schema to use for Json inputs
         python_schema: The schema to use for Python inputs
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -2702,15 +3380,24 @@ def json_or_python_schema(
         json_schema=json_schema,
         python_schema=python_schema,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
 
 
+class TypedDictFieldRequiredIf(TypedDict):
+    field: str
+    value: Any
+
+
 class TypedDictField(TypedDict, total=False):
     type: Required[Literal['typed-dict-field']]
     schema: Required[CoreSchema]
     required: bool
+    required_if: TypedDictFieldRequiredIf
+    alias: str
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
@@ -2721,6 +3408,8 @@ def typed_dict_field(
     schema: CoreSchema,
     *,
     required: bool | None = None,
+    required_if: TypedDictFieldRequiredIf | None = None,
+    alias: str | None = None,
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
@@ -2738,6 +3427,10 @@ def typed_dict_field(
     Args:
         schema: The schema to use for the field
         required: Whether the field is required
+        required_if: Makes an optional field required when the validated value of another field, `field`,
+            equals `value`
+        alias: The alias to use for both validation and serialization, unless
+            `validation_alias` or `serialization_alias` is set
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
@@ -2747,6 +3440,8 @@ def typed_dict_field(
         type='typed-dict-field',
         schema=schema,
         required=required,
+        required_if=required_if,
+        alias=alias,
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
@@ -2754,17 +3449,48 @@ def typed_dict_field(
     )
 
 
+class FieldOrdering(TypedDict, total=False):
+    field: Required[str]
+    # exactly one of these must be set
+    gt_field: str
+    ge_field: str
+    lt_field: str
+    le_field: str
+
+
+class PatternProperty(TypedDict, total=False):
+    pattern: Required[str]
+    schema: Required[CoreSchema]
+
+
 class TypedDictSchema(TypedDict, total=False):
     type: Required[Literal['typed-dict']]
     fields: Required[Dict[str, TypedDictField]]
     computed_fields: List[ComputedField]
     strict: bool
+    strict_dict_only: bool
+    field_aliases: Dict[str, str]
     extras_schema: CoreSchema
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: ExtraBehavior
     total: bool  # default: True
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
+    discriminator: str
+    cls: Type[Any]
+    post_init: str
+    post_init_function: Callable[[Dict[str, Any]], Any]
+    output_class: Callable[..., Any]
+    from_sequence: List[str]
+    require_exactly_one_of: List[str]
+    require_at_least_one_of: List[str]
+    require_at_most_one_of: List[str]
+    field_ordering: List[FieldOrdering]
+    pattern_properties: List[PatternProperty]
+    title: str
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
     config: CoreConfig
@@ -2775,11 +3501,27 @@ def typed_dict_schema(
     *,
     computed_fields: list[ComputedField] | None = None,
     strict: bool | None = None,
+    strict_dict_only: bool | None = None,
+    field_aliases: dict[str, str] | None = None,
     extras_schema: CoreSchema | None = None,
     extra_behavior: ExtraBehavior | None = None,
     total: bool | None = None,
     populate_by_name: bool | None = None,
+    discriminator: str | None = None,
+    cls: Type[Any] | None = None,
+    post_init: str | None = None,
+    post_init_function: Callable[[Dict[str, Any]], Any] | None = None,
+    output_class: Callable[..., Any] | None = None,
+    from_sequence: list[str] | None = None,
+    require_exactly_one_of: list[str] | None = None,
+    require_at_least_one_of: list[str] | None = None,
+    require_at_most_one_of: list[str] | None = None,
+    field_ordering: list[FieldOrdering] | None = None,
+    pattern_properties: list[PatternProperty] | None = None,
+    title: str | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
     config: CoreConfig | None = None,
@@ -2801,12 +3543,34 @@ def typed_dict_schema(
         fields: The fields to use for the typed dict
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
         strict: Whether the typed dict is strict
+        strict_dict_only: Whether strict mode also rejects subclasses of `dict`, e.g. `OrderedDict`
+        field_aliases: A mapping of field names to old names, an old name's value is used when the field is missing
         extras_schema: The extra validator to use for the typed dict
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the typed dict
         total: Whether the typed dict is total
         populate_by_name: Whether the typed dict should populate by name
+        discriminator: The name of a field with a single literal value, the field name and value are used
+            as the name of the typed dict, e.g. in union errors
+        cls: The class the typed dict was created from, required by `post_init`
+        post_init: The name of a method of `cls` to call with the validated dict and the validation context
+        post_init_function: A function to call with the validated dict, its return value is used as the result
+        output_class: A class, e.g. a `NamedTuple` or dataclass, called with the validated fields as keyword
+            arguments to create the result instead of returning a dict
+        from_sequence: Field names, in order, to map the items of a tuple or list input onto in lax mode,
+            e.g. for database rows
+        require_exactly_one_of: Field names of which exactly one must be present in the validated dict
+        require_at_least_one_of: Field names of which at least one must be present in the validated dict
+        require_at_most_one_of: Field names of which at most one may be present in the validated dict
+        field_ordering: Comparisons between the values of two fields, e.g. `{'field': 'end', 'ge_field': 'start'}`,
+            checked once all fields are valid and skipped if either field is missing
+        pattern_properties: Schemas for keys which aren't fields, e.g. `{'pattern': '^x-', 'schema': str_schema()}`,
+            the first entry whose regex pattern matches the key is used, other keys are handled by `extra_behavior`
+        title: The title used in validation errors when this is the top level schema, unless config sets `title`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2814,11 +3578,27 @@ def typed_dict_schema(
         fields=fields,
         computed_fields=computed_fields,
         strict=strict,
+        strict_dict_only=strict_dict_only,
+        field_aliases=field_aliases,
         extras_schema=extras_schema,
         extra_behavior=extra_behavior,
         total=total,
         populate_by_name=populate_by_name,
+        discriminator=discriminator,
+        cls=cls,
+        post_init=post_init,
+        post_init_function=post_init_function,
+        output_class=output_class,
+        from_sequence=from_sequence,
+        require_exactly_one_of=require_exactly_one_of,
+        require_at_least_one_of=require_at_least_one_of,
+        require_at_most_one_of=require_at_most_one_of,
+        field_ordering=field_ordering,
+        pattern_properties=pattern_properties,
+        title=title,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
         config=config,
@@ -2883,7 +3663,12 @@ class ModelFieldsSchema(TypedDict, total=False):
     extra_behavior: ExtraBehavior
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
     from_attributes: bool
+    fields_set_include_defaults: bool
+    nested_fields_set: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -2898,7 +3683,11 @@ def model_fields_schema(
     extra_behavior: ExtraBehavior | None = None,
     populate_by_name: bool | None = None,
     from_attributes: bool | None = None,
+    fields_set_include_defaults: bool | None = None,
+    nested_fields_set: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> ModelFieldsSchema:
@@ -2923,10 +3712,17 @@ def model_fields_schema(
         strict: Whether the typed dict is strict
         extras_schema: The extra validator to use for the typed dict
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the typed dict
         populate_by_name: Whether the typed dict should populate by name
         from_attributes: Whether the typed dict should be populated from attributes
+        fields_set_include_defaults: Whether fields set to their default value are included in the fields set,
+            by default only fields present in the input are included
+        nested_fields_set: Whether the fields set of a field's value, e.g. a nested model, is also included in
+            dotted form, e.g. `'address.city'`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2939,7 +3735,11 @@ def model_fields_schema(
         extra_behavior=extra_behavior,
         populate_by_name=populate_by_name,
         from_attributes=from_attributes,
+        fields_set_include_defaults=fields_set_include_defaults,
+        nested_fields_set=nested_fields_set,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -2952,12 +3752,18 @@ class ModelSchema(TypedDict, total=False):
     custom_init: bool
     root_model: bool
     post_init: str
+    class_new_args: List[Any]
+    class_kwargs: Dict[str, Any]
     revalidate_instances: Literal['always', 'never', 'subclass-instances']  # default: 'never'
+    copy_on_model_validation: Literal['none', 'shallow', 'deep']  # default: 'none'
     strict: bool
     frozen: bool
     extra_behavior: ExtraBehavior
     config: CoreConfig
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -2969,12 +3775,17 @@ def model_schema(
     custom_init: bool | None = None,
     root_model: bool | None = None,
     post_init: str | None = None,
+    class_new_args: list[Any] | None = None,
+    class_kwargs: dict[str, Any] | None = None,
     revalidate_instances: Literal['always', 'never', 'subclass-instances'] | None = None,
+    copy_on_model_validation: Literal['none', 'shallow', 'deep'] | None = None,
     strict: bool | None = None,
     frozen: bool | None = None,
     extra_behavior: ExtraBehavior | None = None,
     config: CoreConfig | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> ModelSchema:
@@ -3015,13 +3826,24 @@ def model_schema(
         custom_init: Whether the model has a custom init method
         root_model: Whether the model is a `RootModel`
         post_init: The call after init to use for the model
+        class_new_args: Arguments to pass to the class's `__new__` when creating an instance, e.g. `['']` for a
+            subclass of `str`, by default no arguments are passed
+        class_kwargs: Keyword arguments from the class definition, passed to the class's
+            `__pydantic_init_subclass__` method (if it exists) once per class, after the first instance is
+            constructed, if the method raises it's called again for the next instance
         revalidate_instances: whether instances of models and dataclasses (including subclass instances)
             should re-validate defaults to config.revalidate_instances, else 'never'
+        copy_on_model_validation: whether instances which aren't re-validated are returned as-is ('none'),
+            or copied with `copy.copy` ('shallow') or `copy.deepcopy` ('deep'), defaults to
+            config.copy_on_model_validation, else 'none'
         strict: Whether the model is strict
         frozen: Whether the model is frozen
         extra_behavior: The extra behavior to use for the model, used in serialization
         config: The config to use for the model
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -3032,12 +3854,17 @@ def model_schema(
         custom_init=custom_init,
         root_model=root_model,
         post_init=post_init,
+        class_new_args=class_new_args,
+        class_kwargs=class_kwargs,
         revalidate_instances=revalidate_instances,
+        copy_on_model_validation=copy_on_model_validation,
         strict=strict,
         frozen=frozen,
         extra_behavior=extra_behavior,
         config=config,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -3119,6 +3946,9 @@ class DataclassArgsSchema(TypedDict, total=False):
     populate_by_name: bool  # default: False
     collect_init_only: bool  # default: False
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
     extra_behavior: ExtraBehavior
@@ -3132,6 +3962,8 @@ def dataclass_args_schema(
     populate_by_name: bool | None = None,
     collect_init_only: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
     extra_behavior: ExtraBehavior | None = None,
@@ -3160,6 +3992,9 @@ def dataclass_args_schema(
         populate_by_name: Whether to populate by name
         collect_init_only: Whether to collect init only fields into a dict to pass to `__post_init__`
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
         extra_behavior: How to handle extra fields
@@ -3172,6 +4007,8 @@ def dataclass_args_schema(
         populate_by_name=populate_by_name,
         collect_init_only=collect_init_only,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
         extra_behavior=extra_behavior,
@@ -3189,6 +4026,9 @@ class DataclassSchema(TypedDict, total=False):
     strict: bool  # default: False
     frozen: bool  # default False
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
     slots: bool
@@ -3205,6 +4045,8 @@ def dataclass_schema(
     revalidate_instances: Literal['always', 'never', 'subclass-instances'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
     frozen: bool | None = None,
@@ -3226,6 +4068,9 @@ def dataclass_schema(
             should re-validate defaults to config.revalidate_instances, else 'never'
         strict: Whether to require an exact instance of `cls`
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
         frozen: Whether the dataclass is frozen
@@ -3242,6 +4087,8 @@ def dataclass_schema(
         revalidate_instances=revalidate_instances,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
         frozen=frozen,
@@ -3294,6 +4141,9 @@ class ArgumentsSchema(TypedDict, total=False):
     var_args_schema: CoreSchema
     var_kwargs_schema: CoreSchema
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -3305,6 +4155,8 @@ def arguments_schema(
     var_args_schema: CoreSchema | None = None,
     var_kwargs_schema: CoreSchema | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> ArgumentsSchema:
@@ -3331,6 +4183,9 @@ def arguments_schema(
         var_args_schema: The variable args schema to use for the arguments schema
         var_kwargs_schema: The variable kwargs schema to use for the arguments schema
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -3341,6 +4196,8 @@ def arguments_schema(
         var_args_schema=var_args_schema,
         var_kwargs_schema=var_kwargs_schema,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -3353,6 +4210,9 @@ class CallSchema(TypedDict, total=False):
     function_name: str  # default function.__name__
     return_schema: CoreSchema
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -3364,6 +4224,8 @@ def call_schema(
     function_name: str | None = None,
     return_schema: CoreSchema | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> CallSchema:
@@ -3396,6 +4258,9 @@ def call_schema(
         function_name: The function name to use for the call schema, if not provided `function.__name__` is used
         return_schema: The return schema to use for the call schema
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -3406,6 +4271,8 @@ def call_schema(
         function_name=function_name,
         return_schema=return_schema,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -3418,6 +4285,9 @@ class CustomErrorSchema(TypedDict, total=False):
     custom_error_message: str
     custom_error_context: Dict[str, Union[str, int, float]]
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -3429,6 +4299,8 @@ def custom_error_schema(
     custom_error_message: str | None = None,
     custom_error_context: dict[str, Any] | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> CustomErrorSchema:
@@ -3453,6 +4325,9 @@ def custom_error_schema(
         custom_error_message: The custom error message to use for the custom error schema
         custom_error_context: The custom error context to use for the custom error schema
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -3463,6 +4338,8 @@ def custom_error_schema(
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -3472,6 +4349,9 @@ class JsonSchema(TypedDict, total=False):
     type: Required[Literal['json']]
     schema: CoreSchema
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -3480,6 +4360,8 @@ def json_schema(
     schema: CoreSchema | None = None,
     *,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> JsonSchema:
@@ -3516,10 +4398,21 @@ def json_schema(
     Args:
         schema: The schema to use for the JSON schema
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='json', schema=schema, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='json',
+        schema=schema,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class UrlSchema(TypedDict, total=False):
@@ -3532,6 +4425,9 @@ class UrlSchema(TypedDict, total=False):
     default_path: str
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -3546,6 +4442,8 @@ def url_schema(
     default_path: str | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> UrlSchema:
@@ -3570,6 +4468,9 @@ def url_schema(
         default_path: The default path to use if the URL does not have a path
         strict: Whether to use strict URL parsing
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -3583,6 +4484,8 @@ def url_schema(
         default_path=default_path,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -3598,6 +4501,9 @@ class MultiHostUrlSchema(TypedDict, total=False):
     default_path: str
     strict: bool
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
@@ -3612,6 +4518,8 @@ def multi_host_url_schema(
     default_path: str | None = None,
     strict: bool | None = None,
     ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> MultiHostUrlSchema:
@@ -3636,6 +4544,9 @@ def multi_host_url_schema(
         default_path: The default path to use if the URL does not have a path
         strict: Whether to use strict URL parsing
         ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
@@ -3649,6 +4560,8 @@ def multi_host_url_schema(
         default_path=default_path,
         strict=strict,
         ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
         metadata=metadata,
         serialization=serialization,
     )
@@ -3689,12 +4602,20 @@ class DefinitionReferenceSchema(TypedDict, total=False):
     type: Required[Literal['definition-ref']]
     schema_ref: Required[str]
     ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
     metadata: Any
     serialization: SerSchema
 
 
 def definition_reference_schema(
-    schema_ref: str, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    schema_ref: str,
+    ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> DefinitionReferenceSchema:
     """
     Returns a schema that points to a schema stored in "definitions", this is useful for nested recursive
@@ -3720,7 +4641,72 @@ def definition_reference_schema(
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='definition-ref', schema_ref=schema_ref, ref=ref, metadata=metadata, serialization=serialization
+        type='definition-ref',
+        schema_ref=schema_ref,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class ExternalReferenceSchema(TypedDict, total=False):
+    type: Required[Literal['external-ref']]
+    schema_ref: Required[Any]  # a `SchemaValidator`
+    path: str
+    ref: str
+    deprecated: Union[bool, str]
+    deprecated_since: str
+    priority: int
+    metadata: Any
+    serialization: SerSchema
+
+
+def external_reference_schema(
+    schema_ref: Any,
+    *,
+    path: str | None = None,
+    ref: str | None = None,
+    deprecated: bool | str | None = None,
+    deprecated_since: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> ExternalReferenceSchema:
+    """
+    Returns a schema that validates using a separate `SchemaValidator`, this is useful for sharing a schema
+    between validators without duplicating it, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    address_validator = SchemaValidator(
+        core_schema.typed_dict_schema({'city': core_schema.typed_dict_field(core_schema.str_schema())})
+    )
+    schema = core_schema.list_schema(core_schema.external_reference_schema(address_validator))
+    v = SchemaValidator(schema)
+    assert v.validate_python([{'city': 'London'}]) == [{'city': 'London'}]
+    ```
+
+    Args:
+        schema_ref: The `SchemaValidator` to validate with
+        path: The ref of a definition within `schema_ref` to validate with, instead of its root schema
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        deprecated: If set, a `DeprecationWarning` is emitted whenever a value is validated, `True` uses a default
+            message, a string is used as the message
+        deprecated_since: The version the schema was deprecated in, appended to the warning message
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='external-ref',
+        schema_ref=schema_ref,
+        path=path,
+        ref=ref,
+        deprecated=deprecated,
+        deprecated_since=deprecated_since,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
@@ -3776,6 +4762,7 @@ if not MYPY:
         MultiHostUrlSchema,
         DefinitionsSchema,
         DefinitionReferenceSchema,
+        ExternalReferenceSchema,
         UuidSchema,
     ]
 elif False:
@@ -3831,6 +4818,7 @@ CoreSchemaType = Literal[
     'multi-host-url',
     'definitions',
     'definition-ref',
+    'external-ref',
     'uuid',
 ]
 
@@ -3843,8 +4831,15 @@ ErrorType = Literal[
     'no_such_attribute',
     'json_invalid',
     'json_type',
+    'json_scalar_type',
     'recursion_loop',
     'missing',
+    'missing_conditional_field',
+    'ambiguous_field',
+    'exactly_one_of',
+    'at_least_one_of',
+    'at_most_one_of',
+    'field_ordering_violation',
     'frozen_field',
     'frozen_instance',
     'extra_forbidden',
@@ -3863,31 +4858,53 @@ ErrorType = Literal[
     'finite_number',
     'too_short',
     'too_long',
+    'exact_length',
     'iterable_type',
     'iteration_error',
     'string_type',
+    'string_type_exact',
     'string_sub_type',
     'string_unicode',
     'string_too_short',
     'string_too_long',
+    'string_too_long_bytes',
+    'string_too_long_codepoints',
     'string_pattern_mismatch',
+    'string_pattern_match',
+    'string_format_mismatch',
+    'string_not_hostname',
+    'string_not_idna',
+    'string_starts_with',
+    'string_ends_with',
     'enum',
     'dict_type',
+    'dict_type_exact',
     'mapping_type',
+    'dict_forbidden_key',
+    'dict_missing_key',
+    'dict_pairs_invalid',
+    'dict_duplicate_key',
     'list_type',
     'tuple_type',
     'set_type',
+    'set_item_unhashable',
     'bool_type',
     'bool_parsing',
     'int_type',
     'int_parsing',
     'int_parsing_size',
     'int_from_float',
+    'int_from_bool_not_allowed',
+    'int_overflow',
     'float_type',
     'float_parsing',
+    'float_from_decimal_not_allowed',
     'bytes_type',
     'bytes_too_short',
     'bytes_too_long',
+    'bytes_too_short_chars',
+    'bytes_too_long_chars',
+    'bytes_invalid_encoding',
     'value_error',
     'assertion_error',
     'literal_error',
@@ -3905,6 +4922,8 @@ ErrorType = Literal[
     'datetime_from_date_parsing',
     'datetime_past',
     'datetime_future',
+    'datetime_sub_microsecond',
+    'datetime_from_timestamp_overflow',
     'timezone_naive',
     'timezone_aware',
     'timezone_offset',
@@ -3914,6 +4933,7 @@ ErrorType = Literal[
     'is_instance_of',
     'is_subclass_of',
     'callable_type',
+    'callable_arity_mismatch',
     'union_tag_invalid',
     'union_tag_not_found',
     'arguments_type',
@@ -102,3 +102,44 @@ impl PydanticUndefinedType {
         "PydanticUndefined"
     }
 }
+
+static DROP_FIELD_CELL: GILOnceCell<Py<PydanticDropFieldType>> = GILOnceCell::new();
+
+/// Sentinel which `after` validators can return to remove the field from the output entirely
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct PydanticDropFieldType {}
+
+#[pymethods]
+impl PydanticDropFieldType {
+    #[new]
+    pub fn py_new(_py: Python) -> PyResult<Self> {
+        Err(PyNotImplementedError::new_err(
+            "Creating instances of \"DropFieldType\" is not supported",
+        ))
+    }
+
+    #[staticmethod]
+    pub fn new(py: Python) -> Py<Self> {
+        DROP_FIELD_CELL
+            .get_or_init(py, || PydanticDropFieldType {}.into_py(py).extract(py).unwrap())
+            .clone()
+    }
+
+    fn __repr__(&self) -> &'static str {
+        "PydanticDropField"
+    }
+
+    fn __copy__(&self, py: Python) -> Py<Self> {
+        DROP_FIELD_CELL.get(py).unwrap().clone()
+    }
+
+    #[pyo3(signature = (_memo, /))]
+    fn __deepcopy__(&self, py: Python, _memo: &Bound<'_, PyAny>) -> Py<Self> {
+        self.__copy__(py)
+    }
+
+    fn __reduce__(&self) -> &'static str {
+        "PydanticDropField"
+    }
+}
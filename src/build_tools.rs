@@ -95,8 +95,9 @@ impl SchemaError {
                 }
             }
             ValError::InternalErr(err) => err,
-            ValError::Omit => Self::new_err("Unexpected Omit error."),
+            ValError::Omit | ValError::DropField => Self::new_err("Unexpected Omit error."),
             ValError::UseDefault => Self::new_err("Unexpected UseDefault error."),
+            ValError::Undefined => Self::new_err("Unexpected Undefined error."),
         }
     }
 
@@ -76,6 +76,22 @@ impl<T> DefinitionRef<T> {
     }
 }
 
+impl<T> Definitions<T> {
+    /// Get the value of a definition by its reference string
+    pub fn get(&self, reference: &str) -> Option<&T> {
+        self.0
+            .get(&reference.to_string())
+            .and_then(|definition| definition.value.get())
+    }
+
+    /// The reference strings of all definitions, sorted so the order is stable
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.0.keys().map(|reference| reference.as_str()).collect();
+        names.sort_unstable();
+        names
+    }
+}
+
 impl<T: Debug> Debug for DefinitionRef<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // To avoid possible infinite recursion from recursive definitions,
@@ -34,7 +34,13 @@ pub enum ValError {
     LineErrors(Vec<ValLineError>),
     InternalErr(PyErr),
     Omit,
+    // a function validator returned `PydanticDropField`, typed dict, model and dataclass fields are dropped like
+    // with `Omit`, anywhere else it's an error
+    DropField,
     UseDefault,
+    // a function validator returned `PydanticUndefined`, the default is used if there is one,
+    // otherwise the value is treated as missing
+    Undefined,
 }
 
 impl From<PyErr> for ValError {
@@ -98,6 +104,8 @@ pub struct ValLineError {
     // location is reversed so that adding an "outer" location item is pushing, it's reversed before showing to the user
     pub location: Location,
     pub input_value: InputValue,
+    // from `hide_input_in_errors` in a model's config, the input is replaced with `None` and not displayed
+    pub hide_input: bool,
 }
 
 impl ValLineError {
@@ -106,6 +114,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location: Location::default(),
+            hide_input: false,
         }
     }
 
@@ -114,6 +123,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location: Location::new_some(loc.into()),
+            hide_input: false,
         }
     }
 
@@ -122,6 +132,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location,
+            hide_input: false,
         }
     }
 
@@ -130,6 +141,7 @@ impl ValLineError {
             error_type,
             input_value,
             location: Location::default(),
+            hide_input: false,
         }
     }
 
@@ -145,6 +157,13 @@ impl ValLineError {
         self.error_type = error_type;
         self
     }
+
+    /// drop the input so it can't be seen in `errors()`, `json()` or the error's `repr`
+    pub fn with_hidden_input(mut self, py: Python) -> Self {
+        self.input_value = InputValue::Python(py.None());
+        self.hide_input = true;
+        self
+    }
 }
 
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -5,12 +5,14 @@ mod location;
 mod types;
 mod validation_exception;
 mod value_exception;
+mod warning;
 
 pub use self::line_error::{InputValue, ToErrorValue, ValError, ValLineError, ValResult};
 pub use self::location::LocItem;
-pub use self::types::{list_all_errors, ErrorType, ErrorTypeDefaults, Number};
+pub use self::types::{list_all_errors, ErrorType, ErrorTypeDefaults, Number, StringOrList};
 pub use self::validation_exception::ValidationError;
 pub use self::value_exception::{PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault};
+pub use self::warning::ValidationWarning;
 
 pub fn py_err_string(py: Python, err: PyErr) -> String {
     let value = err.value_bound(py);
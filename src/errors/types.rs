@@ -167,12 +167,35 @@ error_types! {
         error: {ctx_type: String, ctx_fn: field_from_context},
     },
     JsonType {},
+    JsonScalarType {},
     // ---------------------
     // recursion error
     RecursionLoop {},
     // ---------------------
     // typed dict specific errors
     Missing {},
+    MissingConditionalField {
+        condition_field: {ctx_type: String, ctx_fn: field_from_context},
+        condition_value: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    AmbiguousField {
+        field_name: {ctx_type: String, ctx_fn: field_from_context},
+        old_name: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    ExactlyOneOf {
+        fields: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    AtLeastOneOf {
+        fields: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    AtMostOneOf {
+        fields: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    FieldOrderingViolation {
+        field: {ctx_type: String, ctx_fn: field_from_context},
+        operator: {ctx_type: String, ctx_fn: field_from_context},
+        other_field: {ctx_type: String, ctx_fn: field_from_context},
+    },
     FrozenField {},
     FrozenInstance {},
     ExtraForbidden {},
@@ -227,6 +250,11 @@ error_types! {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
         actual_length: {ctx_type: Option<usize>, ctx_fn: field_from_context},
     },
+    ExactLength {
+        field_type: {ctx_type: String, ctx_fn: field_from_context},
+        exact_length: {ctx_type: usize, ctx_fn: field_from_context},
+        actual_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // ---------------------
     // generic collection and iteration errors
     IterableType {},
@@ -236,6 +264,7 @@ error_types! {
     // ---------------------
     // string errors
     StringType {},
+    StringTypeExact {},
     StringSubType {},
     StringUnicode {},
     StringTooShort {
@@ -244,9 +273,33 @@ error_types! {
     StringTooLong {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    StringTooLongBytes {
+        max_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    StringTooLongCodepoints {
+        max_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     StringPatternMismatch {
         pattern: {ctx_type: String, ctx_fn: field_from_context},
     },
+    StringPatternMatch {
+        pattern: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    StringFormatMismatch {
+        format: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    StringNotHostname {
+        label: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    StringNotIdna {
+        label: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    StringStartsWith {
+        prefix: {ctx_type: StringOrList, ctx_fn: field_from_context},
+    },
+    StringEndsWith {
+        suffix: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // enum errors
     Enum {
@@ -255,9 +308,22 @@ error_types! {
     // ---------------------
     // dict errors
     DictType {},
+    DictTypeExact {},
     MappingType {
         error: {ctx_type: Cow<'static, str>, ctx_fn: cow_field_from_context<String, _>},
     },
+    DictForbiddenKey {
+        key_name: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    DictMissingKey {
+        key_name: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    DictPairsInvalid {
+        index: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    DictDuplicateKey {
+        key_name: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // list errors
     ListType {},
@@ -267,6 +333,9 @@ error_types! {
     // ---------------------
     // set errors
     SetType {},
+    SetItemUnhashable {
+        type_name: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // bool errors
     BoolType {},
@@ -277,10 +346,16 @@ error_types! {
     IntParsing {},
     IntParsingSize {},
     IntFromFloat {},
+    IntFromBoolNotAllowed {},
+    IntOverflow {
+        bit_width: {ctx_type: u8, ctx_fn: field_from_context},
+        unsigned: {ctx_type: bool, ctx_fn: field_from_context},
+    },
     // ---------------------
     // float errors
     FloatType {},
     FloatParsing {},
+    FloatFromDecimalNotAllowed {},
     // ---------------------
     // bytes errors
     BytesType {},
@@ -290,6 +365,16 @@ error_types! {
     BytesTooLong {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    BytesTooShortChars {
+        min_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    BytesTooLongChars {
+        max_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    BytesInvalidEncoding {
+        encoding: {ctx_type: String, ctx_fn: field_from_context},
+        encoding_error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // python errors from functions
     ValueError {
@@ -341,6 +426,8 @@ error_types! {
     },
     DatetimePast {},
     DatetimeFuture {},
+    DatetimeSubMicrosecond {},
+    DatetimeFromTimestampOverflow {},
     // ---------------------
     // timezone errors
     TimezoneNaive {},
@@ -367,6 +454,11 @@ error_types! {
         class: {ctx_type: String, ctx_fn: field_from_context},
     },
     CallableType {},
+    CallableArityMismatch {
+        expected_min: {ctx_type: Option<usize>, ctx_fn: field_from_context},
+        expected_max: {ctx_type: Option<usize>, ctx_fn: field_from_context},
+        got: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // union errors
     UnionTagInvalid {
@@ -470,8 +562,15 @@ impl ErrorType {
             Self::NoSuchAttribute {..} => "Object has no attribute '{attribute}'",
             Self::JsonInvalid {..} => "Invalid JSON: {error}",
             Self::JsonType {..} => "JSON input should be string, bytes or bytearray",
+            Self::JsonScalarType {..} => "Input should be a JSON string, number, boolean or null",
             Self::RecursionLoop {..} => "Recursion error - cyclic reference detected",
             Self::Missing {..} => "Field required",
+            Self::MissingConditionalField {..} => "Field required when '{condition_field}' is {condition_value}",
+            Self::AmbiguousField {..} => "Field '{field_name}' and its old name '{old_name}' have different values",
+            Self::ExactlyOneOf {..} => "Exactly one of the fields {fields} should be provided",
+            Self::AtLeastOneOf {..} => "At least one of the fields {fields} should be provided",
+            Self::AtMostOneOf {..} => "At most one of the fields {fields} should be provided",
+            Self::FieldOrderingViolation {..} => "Field '{field}' should be {operator_description} field '{other_field}'",
             Self::FrozenField {..} => "Field is frozen",
             Self::FrozenInstance {..} => "Instance is frozen",
             Self::ExtraForbidden {..} => "Extra inputs are not permitted",
@@ -490,31 +589,53 @@ impl ErrorType {
             Self::FiniteNumber {..} => "Input should be a finite number",
             Self::TooShort {..} => "{field_type} should have at least {min_length} item{expected_plural} after validation, not {actual_length}",
             Self::TooLong {..} => "{field_type} should have at most {max_length} item{expected_plural} after validation, not {actual_length}",
+            Self::ExactLength {..} => "{field_type} should have exactly {exact_length} item{expected_plural} after validation, not {actual_length}",
             Self::IterableType {..} => "Input should be iterable",
             Self::IterationError {..} => "Error iterating over object, error: {error}",
             Self::StringType {..} => "Input should be a valid string",
+            Self::StringTypeExact {..} => "Input should be an exact string, not a subclass of str",
             Self::StringSubType {..} => "Input should be a string, not an instance of a subclass of str",
             Self::StringUnicode {..} => "Input should be a valid string, unable to parse raw data as a unicode string",
             Self::StringTooShort {..} => "String should have at least {min_length} character{expected_plural}",
             Self::StringTooLong {..} => "String should have at most {max_length} character{expected_plural}",
+            Self::StringTooLongBytes {..} => "String should have at most {max_length} byte{expected_plural}",
+            Self::StringTooLongCodepoints {..} => "String should have at most {max_length} codepoint{expected_plural}",
             Self::StringPatternMismatch {..} => "String should match pattern '{pattern}'",
+            Self::StringPatternMatch {..} => "String should not match pattern '{pattern}'",
+            Self::StringFormatMismatch {..} => "String should match the '{format}' format",
+            Self::StringNotHostname {..} => "String should be a valid hostname, '{label}' is invalid",
+            Self::StringNotIdna {..} => "String should be a valid internationalized domain name, '{label}' is invalid",
+            Self::StringStartsWith {..} => "String should start with {prefix}",
+            Self::StringEndsWith {..} => "String should end with '{suffix}'",
             Self::Enum {..} => "Input should be {expected}",
             Self::DictType {..} => "Input should be a valid dictionary",
+            Self::DictTypeExact {..} => "Input should be an exact dictionary, not a subclass of dict",
             Self::MappingType {..} => "Input should be a valid mapping, error: {error}",
+            Self::DictForbiddenKey {..} => "Dictionary should not contain the key '{key_name}'",
+            Self::DictMissingKey {..} => "Dictionary should contain the key '{key_name}'",
+            Self::DictPairsInvalid {..} => "Item {index} should be a key-value pair with exactly 2 items",
+            Self::DictDuplicateKey {..} => "Dictionary should not contain the key '{key_name}' more than once",
             Self::ListType {..} => "Input should be a valid list",
             Self::TupleType {..} => "Input should be a valid tuple",
             Self::SetType {..} => "Input should be a valid set",
+            Self::SetItemUnhashable {..} => "Set items should be hashable, got an instance of {type_name}",
             Self::BoolType {..} => "Input should be a valid boolean",
             Self::BoolParsing {..} => "Input should be a valid boolean, unable to interpret input",
             Self::IntType {..} => "Input should be a valid integer",
             Self::IntParsing {..} => "Input should be a valid integer, unable to parse string as an integer",
             Self::IntFromFloat {..} => "Input should be a valid integer, got a number with a fractional part",
+            Self::IntFromBoolNotAllowed {..} => "Input should be a valid integer, boolean values are not allowed",
+            Self::IntOverflow {..} => "Input should fit in {signedness} {bit_width} bit integer",
             Self::IntParsingSize {..} => "Unable to parse input string as an integer, exceeded maximum size",
             Self::FloatType {..} => "Input should be a valid number",
             Self::FloatParsing {..} => "Input should be a valid number, unable to parse string as a number",
+            Self::FloatFromDecimalNotAllowed {..} => "Input should be a valid number, Decimal values may not be coerced to float",
             Self::BytesType {..} => "Input should be a valid bytes",
             Self::BytesTooShort {..} => "Data should have at least {min_length} byte{expected_plural}",
             Self::BytesTooLong {..} => "Data should have at most {max_length} byte{expected_plural}",
+            Self::BytesTooShortChars {..} => "Data should have at least {min_length} character{expected_plural}",
+            Self::BytesTooLongChars {..} => "Data should have at most {max_length} character{expected_plural}",
+            Self::BytesInvalidEncoding {..} => "Data should be valid {encoding}: {encoding_error}",
             Self::ValueError {..} => "Value error, {error}",
             Self::AssertionError {..} => "Assertion failed, {error}",
             Self::CustomError {..} => "",  // custom errors are handled separately
@@ -533,6 +654,8 @@ impl ErrorType {
             Self::DatetimeFromDateParsing {..} => "Input should be a valid datetime or date, {error}",
             Self::DatetimePast {..} => "Input should be in the past",
             Self::DatetimeFuture {..} => "Input should be in the future",
+            Self::DatetimeSubMicrosecond {..} => "Input should not have sub-microsecond precision",
+            Self::DatetimeFromTimestampOverflow {..} => "Timestamp is out of range for a datetime",
             Self::TimezoneNaive {..} => "Input should not have timezone info",
             Self::TimezoneAware {..} => "Input should have timezone info",
             Self::TimezoneOffset {..} => "Timezone offset of {tz_expected} required, got {tz_actual}",
@@ -542,6 +665,7 @@ impl ErrorType {
             Self::IsInstanceOf {..} => "Input should be an instance of {class}",
             Self::IsSubclassOf {..} => "Input should be a subclass of {class}",
             Self::CallableType {..} => "Input should be callable",
+            Self::CallableArityMismatch {..} => "Callable should accept {expected} positional argument{expected_plural}, but it accepts {got}",
             Self::UnionTagInvalid {..} => "Input tag '{tag}' found using {discriminator} does not match any of the expected tags: {expected_tags}",
             Self::UnionTagNotFound {..} => "Unable to extract tag using discriminator {discriminator}",
             Self::ArgumentsType {..} => "Arguments must be a tuple, list or a dictionary",
@@ -616,6 +740,32 @@ impl ErrorType {
         match self {
             Self::NoSuchAttribute { attribute, .. } => render!(tmpl, attribute),
             Self::JsonInvalid { error, .. } => render!(tmpl, error),
+            Self::MissingConditionalField {
+                condition_field,
+                condition_value,
+                ..
+            } => render!(tmpl, condition_field, condition_value),
+            Self::AmbiguousField {
+                field_name, old_name, ..
+            } => render!(tmpl, field_name, old_name),
+            Self::ExactlyOneOf { fields, .. } => render!(tmpl, fields),
+            Self::AtLeastOneOf { fields, .. } => render!(tmpl, fields),
+            Self::AtMostOneOf { fields, .. } => render!(tmpl, fields),
+            Self::FieldOrderingViolation {
+                field,
+                operator,
+                other_field,
+                ..
+            } => {
+                let operator_description = match operator.as_str() {
+                    ">" => "greater than",
+                    ">=" => "greater than or equal to",
+                    "<" => "less than",
+                    "<=" => "less than or equal to",
+                    other => other,
+                };
+                render!(tmpl, field, operator_description, other_field)
+            }
             Self::GetAttributeError { error, .. } => render!(tmpl, error),
             Self::ModelType { class_name, .. } => render!(tmpl, class_name),
             Self::DataclassType { class_name, .. } => render!(tmpl, class_name),
@@ -644,6 +794,15 @@ impl ErrorType {
                 let actual_length = actual_length.map_or(Cow::Borrowed("more"), |v| Cow::Owned(v.to_string()));
                 to_string_render!(tmpl, field_type, max_length, actual_length, expected_plural,)
             }
+            Self::ExactLength {
+                field_type,
+                exact_length,
+                actual_length,
+                ..
+            } => {
+                let expected_plural = plural_s(*exact_length);
+                to_string_render!(tmpl, field_type, exact_length, actual_length, expected_plural,)
+            }
             Self::IterationError { error, .. } => render!(tmpl, error),
             Self::StringTooShort { min_length, .. } => {
                 let expected_plural = plural_s(*min_length);
@@ -653,9 +812,34 @@ impl ErrorType {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, max_length, expected_plural)
             }
+            Self::StringTooLongBytes { max_length, .. } => {
+                let expected_plural = plural_s(*max_length);
+                to_string_render!(tmpl, max_length, expected_plural)
+            }
+            Self::StringTooLongCodepoints { max_length, .. } => {
+                let expected_plural = plural_s(*max_length);
+                to_string_render!(tmpl, max_length, expected_plural)
+            }
             Self::StringPatternMismatch { pattern, .. } => render!(tmpl, pattern),
+            Self::StringPatternMatch { pattern, .. } => render!(tmpl, pattern),
+            Self::StringFormatMismatch { format, .. } => render!(tmpl, format),
+            Self::StringNotHostname { label, .. } => render!(tmpl, label),
+            Self::StringNotIdna { label, .. } => render!(tmpl, label),
+            Self::StringStartsWith { prefix, .. } => to_string_render!(tmpl, prefix),
+            Self::StringEndsWith { suffix, .. } => render!(tmpl, suffix),
             Self::Enum { expected, .. } => to_string_render!(tmpl, expected),
             Self::MappingType { error, .. } => render!(tmpl, error),
+            Self::DictForbiddenKey { key_name, .. } => render!(tmpl, key_name),
+            Self::DictMissingKey { key_name, .. } => render!(tmpl, key_name),
+            Self::DictPairsInvalid { index, .. } => to_string_render!(tmpl, index),
+            Self::DictDuplicateKey { key_name, .. } => render!(tmpl, key_name),
+            Self::SetItemUnhashable { type_name, .. } => render!(tmpl, type_name),
+            Self::IntOverflow {
+                bit_width, unsigned, ..
+            } => {
+                let signedness = if *unsigned { "an unsigned" } else { "a signed" };
+                to_string_render!(tmpl, signedness, bit_width)
+            }
             Self::BytesTooShort { min_length, .. } => {
                 let expected_plural = plural_s(*min_length);
                 to_string_render!(tmpl, min_length, expected_plural)
@@ -664,6 +848,19 @@ impl ErrorType {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, max_length, expected_plural)
             }
+            Self::BytesTooShortChars { min_length, .. } => {
+                let expected_plural = plural_s(*min_length);
+                to_string_render!(tmpl, min_length, expected_plural)
+            }
+            Self::BytesTooLongChars { max_length, .. } => {
+                let expected_plural = plural_s(*max_length);
+                to_string_render!(tmpl, max_length, expected_plural)
+            }
+            Self::BytesInvalidEncoding {
+                encoding,
+                encoding_error,
+                ..
+            } => render!(tmpl, encoding, encoding_error),
             Self::ValueError { error, .. } => {
                 let error = &error
                     .as_ref()
@@ -694,6 +891,19 @@ impl ErrorType {
             Self::TimeDeltaParsing { error, .. } => render!(tmpl, error),
             Self::IsInstanceOf { class, .. } => render!(tmpl, class),
             Self::IsSubclassOf { class, .. } => render!(tmpl, class),
+            Self::CallableArityMismatch {
+                expected_min,
+                expected_max,
+                got,
+                ..
+            } => {
+                let (expected, expected_plural) = match (expected_min, expected_max) {
+                    (Some(min), Some(max)) if min != max => (format!("{min} to {max}"), "s"),
+                    (Some(count), _) | (None, Some(count)) => (count.to_string(), plural_s(*count)),
+                    (None, None) => ("any number of".to_string(), "s"),
+                };
+                to_string_render!(tmpl, expected, expected_plural, got)
+            }
             Self::UnionTagInvalid {
                 discriminator,
                 tag,
@@ -816,3 +1026,50 @@ impl ToPyObject for Number {
         }
     }
 }
+
+/// A single string or a list of them, e.g. `starts_with` or `starts_with_any`, the list is rendered as
+/// `'a' or 'b'`
+#[derive(Clone, Debug)]
+pub enum StringOrList {
+    String(String),
+    List(Vec<String>),
+}
+
+impl Default for StringOrList {
+    fn default() -> Self {
+        Self::String(String::new())
+    }
+}
+
+impl FromPyObject<'_> for StringOrList {
+    fn extract_bound(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(string) = obj.extract::<String>() {
+            Ok(Self::String(string))
+        } else if let Ok(list) = obj.extract::<Vec<String>>() {
+            Ok(Self::List(list))
+        } else {
+            py_err!(PyTypeError; "Expected str or list of str, got {}", obj.get_type())
+        }
+    }
+}
+
+impl fmt::Display for StringOrList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(s) => write!(f, "'{s}'"),
+            Self::List(list) => {
+                let quoted: Vec<String> = list.iter().map(|s| format!("'{s}'")).collect();
+                write!(f, "{}", quoted.join(" or "))
+            }
+        }
+    }
+}
+
+impl ToPyObject for StringOrList {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::String(s) => s.into_py(py),
+            Self::List(list) => list.to_object(py),
+        }
+    }
+}
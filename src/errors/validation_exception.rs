@@ -80,7 +80,9 @@ impl ValidationError {
             }
             ValError::InternalErr(err) => err,
             ValError::Omit => Self::omit_error(),
+            ValError::DropField => Self::drop_field_error(),
             ValError::UseDefault => Self::use_default_error(),
+            ValError::Undefined => Self::undefined_error(),
         }
     }
 
@@ -101,10 +103,18 @@ impl ValidationError {
         py_schema_error_type!("Uncaught Omit error, please check your usage of `default` validators.")
     }
 
+    pub fn drop_field_error() -> PyErr {
+        PyTypeError::new_err("`PydanticDropField` can only be returned by validators of typed dict or model fields")
+    }
+
     pub fn use_default_error() -> PyErr {
         py_schema_error_type!("Uncaught UseDefault error, please check your usage of `default` validators.")
     }
 
+    pub fn undefined_error() -> PyErr {
+        py_schema_error_type!("Uncaught Undefined error, please check your usage of `PydanticUndefined` in validators.")
+    }
+
     fn maybe_add_cause(self_: PyRef<'_, Self>, py: Python) -> Option<PyErr> {
         let mut user_py_errs = vec![];
         for line_error in &self_.line_errors {
@@ -453,6 +463,7 @@ pub struct PyLineError {
     error_type: ErrorType,
     location: Location,
     input_value: PyObject,
+    hide_input: bool,
 }
 
 impl IntoPy<PyLineError> for ValLineError {
@@ -461,6 +472,7 @@ impl IntoPy<PyLineError> for ValLineError {
             error_type: self.error_type,
             location: self.location,
             input_value: self.input_value.to_object(py),
+            hide_input: self.hide_input,
         }
     }
 }
@@ -472,6 +484,7 @@ impl From<PyLineError> for ValLineError {
             error_type: other.error_type,
             location: other.location,
             input_value: InputValue::Python(other.input_value),
+            hide_input: other.hide_input,
         }
     }
 }
@@ -509,6 +522,7 @@ impl TryFrom<&Bound<'_, PyAny>> for PyLineError {
             error_type,
             location,
             input_value,
+            hide_input: false,
         })
     }
 }
@@ -567,7 +581,7 @@ impl PyLineError {
         };
         write!(output, "  {message} [type={}", self.error_type.type_string())?;
 
-        if !hide_input {
+        if !hide_input && !self.hide_input {
             let input_value = self.input_value.bind(py);
             let input_str = safe_repr(input_value);
             truncate_input_value!(output, &input_str.to_cow());
@@ -0,0 +1,47 @@
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+use crate::tools::safe_repr;
+
+/// A non-fatal problem found during validation, these are collected instead of being emitted as Python
+/// warnings when the `collect_warnings` config is set
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct ValidationWarning {
+    warning_type: String,
+    #[pyo3(get)]
+    loc: Py<PyTuple>,
+    #[pyo3(get)]
+    msg: String,
+    #[pyo3(get)]
+    input: PyObject,
+}
+
+impl ValidationWarning {
+    pub fn new(py: Python, warning_type: &str, loc: Option<&str>, msg: &str, input: PyObject) -> Self {
+        Self {
+            warning_type: warning_type.to_string(),
+            loc: PyTuple::new_bound(py, loc).unbind(),
+            msg: msg.to_string(),
+            input,
+        }
+    }
+}
+
+#[pymethods]
+impl ValidationWarning {
+    #[getter(r#type)]
+    pub fn warning_type(&self) -> &str {
+        &self.warning_type
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        format!(
+            "ValidationWarning(type={}, loc={}, msg={}, input={})",
+            safe_repr(self.warning_type.to_object(py).bind(py)),
+            safe_repr(self.loc.bind(py)),
+            safe_repr(self.msg.to_object(py).bind(py)),
+            safe_repr(self.input.bind(py)),
+        )
+    }
+}
@@ -15,7 +15,7 @@ use strum::EnumMessage;
 
 use super::Input;
 use crate::errors::ToErrorValue;
-use crate::errors::{ErrorType, ValError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::tools::py_err;
 
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -335,6 +335,7 @@ pub fn bytes_as_datetime<'py>(
         },
     ) {
         Ok(dt) => Ok(dt.into()),
+        Err(ParseError::SecondFractionTooLong) => Err(ValError::new(ErrorTypeDefaults::DatetimeSubMicrosecond, input)),
         Err(err) => Err(ValError::new(
             ErrorType::DatetimeParsing {
                 error: Cow::Borrowed(err.get_documentation().unwrap_or_default()),
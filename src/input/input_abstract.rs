@@ -42,6 +42,32 @@ impl TryFrom<&str> for InputType {
     }
 }
 
+/// The type of a JSON token, used where JSON types are distinct but the equivalent Python values compare equal,
+/// e.g. `true` and `1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonType {
+    Null,
+    Bool,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+impl fmt::Display for JsonType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Null => "null",
+            Self::Bool => "bool",
+            Self::Number => "number",
+            Self::String => "string",
+            Self::Array => "array",
+            Self::Object => "object",
+        };
+        f.write_str(name)
+    }
+}
+
 pub type ValMatch<T> = ValResult<ValidationMatch<T>>;
 
 /// all types have three methods: `validate_*`, `strict_*`, `lax_*`
@@ -55,10 +81,19 @@ pub trait Input<'py>: fmt::Debug + ToPyObject {
         false
     }
 
+    fn is_bool(&self) -> bool {
+        false
+    }
+
     fn as_python(&self) -> Option<&Bound<'py, PyAny>> {
         None
     }
 
+    /// The JSON token type of the input, `None` unless the input is JSON
+    fn json_type(&self) -> Option<JsonType> {
+        None
+    }
+
     fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>>;
 
     type Arguments<'a>: Arguments<'py>
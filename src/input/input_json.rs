@@ -15,7 +15,7 @@ use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
     float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
 };
-use super::input_abstract::{ConsumeIterator, Never, ValMatch};
+use super::input_abstract::{ConsumeIterator, JsonType, Never, ValMatch};
 use super::return_enums::ValidationMatch;
 use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_float, str_as_int};
 use super::{
@@ -50,6 +50,21 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         matches!(self, JsonValue::Null)
     }
 
+    fn is_bool(&self) -> bool {
+        matches!(self, JsonValue::Bool(_))
+    }
+
+    fn json_type(&self) -> Option<JsonType> {
+        Some(match self {
+            JsonValue::Null => JsonType::Null,
+            JsonValue::Bool(_) => JsonType::Bool,
+            JsonValue::Int(_) | JsonValue::BigInt(_) | JsonValue::Float(_) => JsonType::Number,
+            JsonValue::Str(_) => JsonType::String,
+            JsonValue::Array(_) => JsonType::Array,
+            JsonValue::Object(_) => JsonType::Object,
+        })
+    }
+
     fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>> {
         match self {
             JsonValue::Object(object) => {
@@ -306,6 +321,10 @@ impl<'py> Input<'py> for str {
         InputValue::Json(JsonValue::Str(self.to_owned().into()))
     }
 
+    fn json_type(&self) -> Option<JsonType> {
+        Some(JsonType::String)
+    }
+
     fn as_kwargs(&self, _py: Python<'py>) -> Option<Bound<'py, PyDict>> {
         None
     }
@@ -80,6 +80,10 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         PyAnyMethods::is_none(self)
     }
 
+    fn is_bool(&self) -> bool {
+        self.is_instance_of::<PyBool>()
+    }
+
     fn as_python(&self) -> Option<&Bound<'py, PyAny>> {
         Some(self)
     }
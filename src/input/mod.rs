@@ -12,11 +12,11 @@ mod shared;
 
 pub use datetime::TzInfo;
 pub(crate) use datetime::{
-    duration_as_pytimedelta, pydate_as_date, pydatetime_as_datetime, pytime_as_time, EitherDate, EitherDateTime,
-    EitherTime, EitherTimedelta,
+    duration_as_pytimedelta, int_as_datetime, pydate_as_date, pydatetime_as_datetime, pytime_as_time, EitherDate,
+    EitherDateTime, EitherTime, EitherTimedelta,
 };
 pub(crate) use input_abstract::{
-    Arguments, BorrowInput, ConsumeIterator, Input, InputType, KeywordArgs, PositionalArgs, ValidatedDict,
+    Arguments, BorrowInput, ConsumeIterator, Input, InputType, JsonType, KeywordArgs, PositionalArgs, ValidatedDict,
     ValidatedList, ValidatedSet, ValidatedTuple,
 };
 pub(crate) use input_python::{downcast_python_input, input_as_python_instance};
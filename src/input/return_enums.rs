@@ -138,6 +138,14 @@ pub(crate) fn validate_iter_to_vec<'py>(
                 max_length_check.incr()?;
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
             }
+            Err(ValError::Undefined) => {
+                max_length_check.incr()?;
+                errors.push(ValLineError::new_with_loc(
+                    ErrorTypeDefaults::Missing,
+                    item.borrow_input(),
+                    index,
+                ));
+            }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
         }
@@ -151,13 +159,13 @@ pub(crate) fn validate_iter_to_vec<'py>(
 }
 
 pub trait BuildSet {
-    fn build_add(&self, item: PyObject) -> PyResult<()>;
+    fn build_add(&self, item: &PyObject) -> PyResult<()>;
 
     fn build_len(&self) -> usize;
 }
 
 impl BuildSet for Bound<'_, PySet> {
-    fn build_add(&self, item: PyObject) -> PyResult<()> {
+    fn build_add(&self, item: &PyObject) -> PyResult<()> {
         self.add(item)
     }
 
@@ -167,11 +175,11 @@ impl BuildSet for Bound<'_, PySet> {
 }
 
 impl BuildSet for Bound<'_, PyFrozenSet> {
-    fn build_add(&self, item: PyObject) -> PyResult<()> {
+    fn build_add(&self, item: &PyObject) -> PyResult<()> {
         py_error_on_minusone(self.py(), unsafe {
             // Safety: self.as_ptr() the _only_ pointer to the `frozenset`, and it's allowed
             // to mutate this via the C API when nothing else can refer to it.
-            ffi::PySet_Add(self.as_ptr(), item.to_object(self.py()).as_ptr())
+            ffi::PySet_Add(self.as_ptr(), item.as_ptr())
         })
     }
 
@@ -195,8 +203,23 @@ pub(crate) fn validate_iter_to_set<'py>(
     for (index, item_result) in iter.enumerate() {
         let item = item_result.map_err(|e| any_next_error!(py, e, input, index))?;
         match validator.validate(py, item.borrow_input(), state) {
-            Ok(item) => {
-                set.build_add(item)?;
+            Ok(output) => {
+                match set.build_add(&output) {
+                    Ok(()) => (),
+                    // unhashable items raise a `TypeError` when added to the set
+                    Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                        errors.push(ValLineError::new_with_loc(
+                            ErrorType::SetItemUnhashable {
+                                type_name: output.bind(py).get_type().qualname()?,
+                                context: None,
+                            },
+                            item.borrow_input(),
+                            index,
+                        ));
+                        continue;
+                    }
+                    Err(err) => return Err(err.into()),
+                }
                 if let Some(max_length) = max_length {
                     if set.build_len() > max_length {
                         return Err(ValError::new(
@@ -217,6 +240,13 @@ pub(crate) fn validate_iter_to_set<'py>(
             Err(ValError::LineErrors(line_errors)) => {
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
             }
+            Err(ValError::Undefined) => {
+                errors.push(ValLineError::new_with_loc(
+                    ErrorTypeDefaults::Missing,
+                    item.borrow_input(),
+                    index,
+                ));
+            }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
         }
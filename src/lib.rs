@@ -27,16 +27,17 @@ mod validators;
 // required for benchmarks
 pub use self::input::TzInfo;
 pub use self::url::{PyMultiHostUrl, PyUrl};
-pub use argument_markers::{ArgsKwargs, PydanticUndefinedType};
+pub use argument_markers::{ArgsKwargs, PydanticDropFieldType, PydanticUndefinedType};
 pub use build_tools::SchemaError;
 pub use errors::{
     list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
+    ValidationWarning,
 };
 pub use serializers::{
     to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
     WarningsArg,
 };
-pub use validators::{validate_core_schema, PySome, SchemaValidator};
+pub use validators::{set_schema_cache_size, validate_core_schema, FieldInfo, PySome, SchemaValidator};
 
 use crate::input::Input;
 
@@ -110,9 +111,13 @@ fn _pydantic_core(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("_recursion_limit", recursion_guard::RECURSION_GUARD_LIMIT)?;
     m.add("PydanticUndefined", PydanticUndefinedType::new(py))?;
     m.add_class::<PydanticUndefinedType>()?;
+    m.add("PydanticDropField", PydanticDropFieldType::new(py))?;
+    m.add_class::<PydanticDropFieldType>()?;
     m.add_class::<PySome>()?;
     m.add_class::<SchemaValidator>()?;
+    m.add_class::<FieldInfo>()?;
     m.add_class::<ValidationError>()?;
+    m.add_class::<ValidationWarning>()?;
     m.add_class::<SchemaError>()?;
     m.add_class::<PydanticCustomError>()?;
     m.add_class::<PydanticKnownError>()?;
@@ -130,5 +135,6 @@ fn _pydantic_core(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(to_jsonable_python, m)?)?;
     m.add_function(wrap_pyfunction!(list_all_errors, m)?)?;
     m.add_function(wrap_pyfunction!(validate_core_schema, m)?)?;
+    m.add_function(wrap_pyfunction!(set_schema_cache_size, m)?)?;
     Ok(())
 }
@@ -97,6 +97,7 @@ combined_serializer! {
         super::type_serializers::other::IsInstanceBuilder;
         super::type_serializers::other::IsSubclassBuilder;
         super::type_serializers::other::CallableBuilder;
+        super::type_serializers::other::ExternalRefBuilder;
         super::type_serializers::definitions::DefinitionsSerializerBuilder;
         super::type_serializers::dataclass::DataclassArgsBuilder;
         super::type_serializers::function::FunctionBeforeSerializerBuilder;
@@ -194,7 +195,14 @@ impl CombinedSerializer {
         }
 
         let type_: Bound<'_, PyString> = schema.get_as_req(type_key)?;
-        Self::find_serializer(type_.to_str()?, schema, config, definitions)
+        let type_ = type_.to_str()?;
+        // an `any` schema with a `schema` key is just a wrapper, see `build_validator`
+        if type_ == "any" {
+            if let Some(inner_schema) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "schema"))? {
+                return Self::build(&inner_schema, config, definitions);
+            }
+        }
+        Self::find_serializer(type_, schema, config, definitions)
     }
 }
 
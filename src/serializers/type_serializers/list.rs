@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 
 use serde::ser::SerializeSeq;
 
@@ -12,13 +12,15 @@ use crate::tools::SchemaDict;
 use super::any::AnySerializer;
 use super::{
     infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
-    SchemaFilter, TypeSerializer,
+    SchemaFilter, SerMode, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
 pub struct ListSerializer {
     item_serializer: Box<CombinedSerializer>,
     filter: SchemaFilter<usize>,
+    // with `output_type='tuple'` validation returns a tuple, so tuples are serialized as well as lists
+    allow_tuple: bool,
     name: String,
 }
 
@@ -39,12 +41,26 @@ impl BuildSerializer for ListSerializer {
         Ok(Self {
             item_serializer: Box::new(item_serializer),
             filter: SchemaFilter::from_schema(schema)?,
+            allow_tuple: schema
+                .get_as::<Bound<'_, PyString>>(intern!(py, "output_type"))?
+                .map_or(Ok(false), |output_type| output_type.to_str().map(|s| s == "tuple"))?,
             name,
         }
         .into())
     }
 }
 
+impl ListSerializer {
+    /// `value` as a list, tuples are only accepted with `output_type='tuple'`
+    fn as_list<'py>(&self, value: &Bound<'py, PyAny>) -> Option<Bound<'py, PyList>> {
+        match value.downcast::<PyList>() {
+            Ok(py_list) => Some(py_list.clone()),
+            Err(_) if self.allow_tuple => value.downcast::<PyTuple>().ok().map(PyTupleMethods::to_list),
+            Err(_) => None,
+        }
+    }
+}
+
 impl_py_gc_traverse!(ListSerializer { item_serializer });
 
 impl TypeSerializer for ListSerializer {
@@ -55,8 +71,8 @@ impl TypeSerializer for ListSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        match value.downcast::<PyList>() {
-            Ok(py_list) => {
+        match self.as_list(value) {
+            Some(py_list) => {
                 let py = value.py();
                 let item_serializer = self.item_serializer.as_ref();
 
@@ -72,9 +88,13 @@ impl TypeSerializer for ListSerializer {
                         )?);
                     }
                 }
-                Ok(items.into_py(py))
+                match extra.mode {
+                    SerMode::Json => Ok(items.into_py(py)),
+                    _ if value.is_instance_of::<PyTuple>() => Ok(PyTuple::new_bound(py, items).into_py(py)),
+                    _ => Ok(items.into_py(py)),
+                }
             }
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
             }
@@ -93,8 +113,8 @@ impl TypeSerializer for ListSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        match value.downcast::<PyList>() {
-            Ok(py_list) => {
+        match self.as_list(value) {
+            Some(py_list) => {
                 let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
                 let item_serializer = self.item_serializer.as_ref();
 
@@ -116,7 +136,7 @@ impl TypeSerializer for ListSerializer {
                 }
                 seq.end()
             }
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
             }
@@ -111,3 +111,4 @@ macro_rules! any_build_serializer {
 any_build_serializer!(IsInstanceBuilder, "is-instance");
 any_build_serializer!(IsSubclassBuilder, "is-subclass");
 any_build_serializer!(CallableBuilder, "callable");
+any_build_serializer!(ExternalRefBuilder, "external-ref");
@@ -54,7 +54,10 @@ impl BuildSerializer for TypedDictBuilder {
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
                 fields.insert(key, SerField::new(py, key_py, None, None, required));
             } else {
-                let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
+                let alias: Option<String> = match field_info.get_as(intern!(py, "serialization_alias"))? {
+                    Some(alias) => Some(alias),
+                    None => field_info.get_as(intern!(py, "alias"))?,
+                };
 
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
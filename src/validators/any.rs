@@ -1,8 +1,10 @@
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::errors::ValResult;
+use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
+use crate::tools::SchemaDict;
 
 use super::{
     validation_state::Exactness, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator,
@@ -10,17 +12,24 @@ use super::{
 
 /// This might seem useless, but it's useful in DictValidator to avoid Option<Validator> a lot
 #[derive(Debug, Clone)]
-pub struct AnyValidator;
+pub struct AnyValidator {
+    // reject JSON objects and arrays, Python inputs are never restricted
+    json_scalars_only: bool,
+}
 
 impl BuildValidator for AnyValidator {
     const EXPECTED_TYPE: &'static str = "any";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         _config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        Ok(Self.into())
+        let py = schema.py();
+        Ok(Self {
+            json_scalars_only: schema.get_as(intern!(py, "json_scalars_only"))?.unwrap_or(false),
+        }
+        .into())
     }
 }
 
@@ -33,6 +42,12 @@ impl Validator for AnyValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        if self.json_scalars_only
+            && input.as_python().is_none()
+            && (input.validate_dict(true).is_ok() || input.validate_list(true).is_ok())
+        {
+            return Err(ValError::new(ErrorTypeDefaults::JsonScalarType, input));
+        }
         // in a union, Any should be preferred to doing lax coercions
         state.floor_exactness(Exactness::Strict);
         Ok(input.to_object(py))
@@ -1,10 +1,11 @@
+use base64::Engine;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBytes, PyDict, PyMemoryView, PyString};
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherBytes, Input, InputType, ValidationMatch};
 
 use crate::tools::SchemaDict;
 
@@ -13,6 +14,117 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationSta
 #[derive(Debug, Clone)]
 pub struct BytesValidator {
     strict: bool,
+    json_format: BytesJsonFormat,
+    allow_buffer_protocol: bool,
+}
+
+/// Validate bytes, falling back to reading the raw bytes of objects supporting the buffer protocol,
+/// e.g. `memoryview` or `array.array`, if `allow_buffer_protocol` is set
+fn validate_bytes_or_buffer<'a, 'py>(
+    input: &'a (impl Input<'py> + ?Sized),
+    strict: bool,
+    allow_buffer_protocol: bool,
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<EitherBytes<'a, 'py>> {
+    match input.validate_bytes(strict) {
+        Ok(val_match) => Ok(val_match.unpack(state)),
+        Err(err) if allow_buffer_protocol => match input.as_python().map(PyMemoryView::from_bound) {
+            Some(Ok(view)) => {
+                let py_bytes = view
+                    .call_method0(intern!(view.py(), "tobytes"))?
+                    .downcast_into::<PyBytes>()
+                    .map_err(PyErr::from)?;
+                Ok(ValidationMatch::strict(EitherBytes::Py(py_bytes)).unpack(state))
+            }
+            _ => Err(err),
+        },
+        Err(err) => Err(err),
+    }
+}
+
+/// How strings are decoded to bytes when validating JSON
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum BytesJsonFormat {
+    #[default]
+    Raw,
+    Base64,
+    Hex,
+}
+
+impl BytesJsonFormat {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let json_format: Option<Bound<'_, PyString>> = schema.get_as(intern!(schema.py(), "json_format"))?;
+        match json_format.as_ref().map(|s| s.to_str()).transpose()? {
+            None | Some("raw") => Ok(Self::Raw),
+            Some("base64") => Ok(Self::Base64),
+            Some("hex") => Ok(Self::Hex),
+            Some(s) => py_schema_err!("Invalid json_format: `{}`, expected raw, base64 or hex", s),
+        }
+    }
+
+    fn decode<'a, 'py>(
+        self,
+        input: &(impl Input<'py> + ?Sized),
+        either_bytes: EitherBytes<'a, 'py>,
+        state: &ValidationState<'_, 'py>,
+    ) -> ValResult<EitherBytes<'a, 'py>> {
+        let cow = match either_bytes {
+            EitherBytes::Cow(cow) if state.extra().input_type == InputType::Json => cow,
+            other => return Ok(other),
+        };
+        let decoded = match self {
+            Self::Raw => return Ok(EitherBytes::Cow(cow)),
+            // accept both the standard and URL-safe alphabets, the latter is what `ser_json_bytes='base64'` produces
+            Self::Base64 => {
+                let standard: Vec<u8> = cow
+                    .iter()
+                    .map(|b| match b {
+                        b'-' => b'+',
+                        b'_' => b'/',
+                        b => *b,
+                    })
+                    .collect();
+                base64::engine::general_purpose::STANDARD
+                    .decode(standard)
+                    .map_err(|err| err.to_string())
+            }
+            Self::Hex => decode_hex(&cow),
+        };
+        match decoded {
+            Ok(bytes) => Ok(bytes.into()),
+            Err(encoding_error) => Err(ValError::new(
+                ErrorType::BytesInvalidEncoding {
+                    encoding: self.name().to_string(),
+                    encoding_error,
+                    context: None,
+                },
+                input,
+            )),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Raw => "raw",
+            Self::Base64 => "base64",
+            Self::Hex => "hex",
+        }
+    }
+}
+
+fn decode_hex(data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() % 2 != 0 {
+        return Err("Odd number of digits".to_string());
+    }
+    data.chunks(2)
+        .enumerate()
+        .map(|(index, pair)| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| format!("Invalid hex digits at offset {}", index * 2))
+        })
+        .collect()
 }
 
 impl BuildValidator for BytesValidator {
@@ -25,12 +137,16 @@ impl BuildValidator for BytesValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let use_constrained = schema.get_item(intern!(py, "max_length"))?.is_some()
-            || schema.get_item(intern!(py, "min_length"))?.is_some();
+            || schema.get_item(intern!(py, "min_length"))?.is_some()
+            || schema.get_item(intern!(py, "max_length_chars"))?.is_some()
+            || schema.get_item(intern!(py, "min_length_chars"))?.is_some();
         if use_constrained {
             BytesConstrainedValidator::build(schema, config)
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                json_format: BytesJsonFormat::from_py(schema)?,
+                allow_buffer_protocol: schema.get_as(intern!(py, "allow_buffer_protocol"))?.unwrap_or(false),
             }
             .into())
         }
@@ -46,9 +162,9 @@ impl Validator for BytesValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        input
-            .validate_bytes(state.strict_or(self.strict))
-            .map(|m| m.unpack(state).into_py(py))
+        let strict = state.strict_or(self.strict);
+        let either_bytes = validate_bytes_or_buffer(input, strict, self.allow_buffer_protocol, state)?;
+        Ok(self.json_format.decode(input, either_bytes, state)?.into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -61,6 +177,11 @@ pub struct BytesConstrainedValidator {
     strict: bool,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    // constraints on the number of characters when the data is decoded as UTF-8
+    max_length_chars: Option<usize>,
+    min_length_chars: Option<usize>,
+    json_format: BytesJsonFormat,
+    allow_buffer_protocol: bool,
 }
 
 impl_py_gc_traverse!(BytesConstrainedValidator {});
@@ -72,7 +193,9 @@ impl Validator for BytesConstrainedValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_bytes = input.validate_bytes(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        let either_bytes = validate_bytes_or_buffer(input, strict, self.allow_buffer_protocol, state)?;
+        let either_bytes = self.json_format.decode(input, either_bytes, state)?;
         let len = either_bytes.len()?;
 
         if let Some(min_length) = self.min_length {
@@ -97,6 +220,43 @@ impl Validator for BytesConstrainedValidator {
                 ));
             }
         }
+        if self.min_length_chars.is_some() || self.max_length_chars.is_some() {
+            let chars = match std::str::from_utf8(either_bytes.as_slice()) {
+                Ok(s) => s.chars().count(),
+                Err(err) => {
+                    return Err(ValError::new(
+                        ErrorType::BytesInvalidEncoding {
+                            encoding: "utf-8".to_string(),
+                            encoding_error: err.to_string(),
+                            context: None,
+                        },
+                        input,
+                    ))
+                }
+            };
+            if let Some(min_length) = self.min_length_chars {
+                if chars < min_length {
+                    return Err(ValError::new(
+                        ErrorType::BytesTooShortChars {
+                            min_length,
+                            context: None,
+                        },
+                        input,
+                    ));
+                }
+            }
+            if let Some(max_length) = self.max_length_chars {
+                if chars > max_length {
+                    return Err(ValError::new(
+                        ErrorType::BytesTooLongChars {
+                            max_length,
+                            context: None,
+                        },
+                        input,
+                    ));
+                }
+            }
+        }
         Ok(either_bytes.into_py(py))
     }
 
@@ -112,6 +272,10 @@ impl BytesConstrainedValidator {
             strict: is_strict(schema, config)?,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            min_length_chars: schema.get_as(intern!(py, "min_length_chars"))?,
+            max_length_chars: schema.get_as(intern!(py, "max_length_chars"))?,
+            json_format: BytesJsonFormat::from_py(schema)?,
+            allow_buffer_protocol: schema.get_as(intern!(py, "allow_buffer_protocol"))?.unwrap_or(false),
         }
         .into())
     }
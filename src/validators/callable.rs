@@ -1,28 +1,64 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::intern;
 use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
 use pyo3::types::PyDict;
 
-use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
+use crate::tools::SchemaDict;
 
 use super::validation_state::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
-pub struct CallableValidator;
+pub struct CallableValidator {
+    min_args: Option<usize>,
+    max_args: Option<usize>,
+    // `weakref.WeakKeyDictionary` mapping callables to their arity, only set if `min_args` or `max_args` is set
+    arity_cache: Option<PyObject>,
+}
 
 impl BuildValidator for CallableValidator {
     const EXPECTED_TYPE: &'static str = "callable";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         _config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        Ok(Self.into())
+        let py = schema.py();
+        let min_args: Option<usize> = schema.get_as(intern!(py, "min_args"))?;
+        let max_args: Option<usize> = schema.get_as(intern!(py, "max_args"))?;
+        if let (Some(min_args), Some(max_args)) = (min_args, max_args) {
+            if min_args > max_args {
+                return py_schema_err!(
+                    "`min_args` ({}) must not be greater than `max_args` ({})",
+                    min_args,
+                    max_args
+                );
+            }
+        }
+        let arity_cache = match min_args.is_some() || max_args.is_some() {
+            true => Some(
+                py.import_bound(intern!(py, "weakref"))?
+                    .getattr(intern!(py, "WeakKeyDictionary"))?
+                    .call0()?
+                    .unbind(),
+            ),
+            false => None,
+        };
+        Ok(Self {
+            min_args,
+            max_args,
+            arity_cache,
+        }
+        .into())
     }
 }
 
-impl_py_gc_traverse!(CallableValidator {});
+impl_py_gc_traverse!(CallableValidator { arity_cache });
 
 impl Validator for CallableValidator {
     fn validate<'py>(
@@ -32,13 +68,93 @@ impl Validator for CallableValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         state.floor_exactness(Exactness::Lax);
-        match input.as_python().is_some_and(PyAnyMethods::is_callable) {
-            true => Ok(input.to_object(py)),
-            false => Err(ValError::new(ErrorTypeDefaults::CallableType, input)),
+        let Some(callable) = input.as_python().filter(|obj| obj.is_callable()) else {
+            return Err(ValError::new(ErrorTypeDefaults::CallableType, input));
+        };
+        if let Some(ref arity_cache) = self.arity_cache {
+            let (required, maximum) = cached_arity(arity_cache.bind(py), callable)?;
+            let too_few = self.min_args.is_some_and(|min_args| required > min_args);
+            let too_many = self
+                .max_args
+                .is_some_and(|max_args| maximum.is_some_and(|maximum| maximum < max_args));
+            if too_few || too_many {
+                let got = match maximum {
+                    Some(maximum) if maximum == required => required.to_string(),
+                    Some(maximum) => format!("{required} to {maximum}"),
+                    None => format!("{required} or more"),
+                };
+                return Err(ValError::new(
+                    ErrorType::CallableArityMismatch {
+                        expected_min: self.min_args,
+                        expected_max: self.max_args,
+                        got,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
         }
+        Ok(callable.clone().unbind())
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 }
+
+type Arity = (usize, Option<usize>);
+
+/// Look up the arity of `callable` in `arity_cache`, inspecting and caching it if it's not there yet;
+/// callables which can't be weakly referenced or hashed are inspected every time
+fn cached_arity(arity_cache: &Bound<'_, PyAny>, callable: &Bound<'_, PyAny>) -> PyResult<Arity> {
+    let py = callable.py();
+    match arity_cache.call_method1(intern!(py, "get"), (callable,)) {
+        Ok(cached) if !PyAnyMethods::is_none(&cached) => return cached.extract(),
+        Ok(_) => (),
+        Err(err) if err.is_instance_of::<PyTypeError>(py) => return inspect_arity(callable),
+        Err(err) => return Err(err),
+    }
+    let arity = inspect_arity(callable)?;
+    arity_cache.set_item(callable, arity)?;
+    Ok(arity)
+}
+
+static INSPECT_SIGNATURE: GILOnceCell<PyObject> = GILOnceCell::new();
+
+/// Number of positional arguments `callable` requires, and the number it accepts (`None` if it takes `*args`),
+/// callables without a signature, e.g. some builtins, are assumed to accept any number of arguments
+fn inspect_arity(callable: &Bound<'_, PyAny>) -> PyResult<Arity> {
+    let py = callable.py();
+    let signature_fn = INSPECT_SIGNATURE.get_or_try_init(py, || {
+        Ok::<_, PyErr>(py.import_bound(intern!(py, "inspect"))?.getattr("signature")?.unbind())
+    })?;
+    let signature = match signature_fn.call1(py, (callable,)) {
+        Ok(signature) => signature.into_bound(py),
+        Err(err) if err.is_instance_of::<PyValueError>(py) || err.is_instance_of::<PyTypeError>(py) => {
+            return Ok((0, None))
+        }
+        Err(err) => return Err(err),
+    };
+    let empty = signature.getattr(intern!(py, "empty"))?;
+    let mut required = 0;
+    let mut maximum = Some(0);
+    for param in signature
+        .getattr(intern!(py, "parameters"))?
+        .call_method0(intern!(py, "values"))?
+        .iter()?
+    {
+        let param = param?;
+        // `inspect.Parameter.kind` values: 0 positional only, 1 positional or keyword, 2 var positional
+        match param.getattr(intern!(py, "kind"))?.extract::<u8>()? {
+            0 | 1 => {
+                if param.getattr(intern!(py, "default"))?.is(&empty) {
+                    required += 1;
+                }
+                maximum = maximum.map(|maximum| maximum + 1);
+            }
+            2 => maximum = None,
+            _ => (),
+        }
+    }
+    Ok((required, maximum))
+}
@@ -89,7 +89,7 @@ impl BuildValidator for DataclassArgsValidator {
                 Err(err) => return py_schema_err!("Field '{}':\n  {}", name, err),
             };
 
-            if let CombinedValidator::WithDefault(ref v) = validator {
+            if let CombinedValidator::WithDefault(v) = validator.unwrap_deprecated() {
                 if v.omit_on_error() {
                     return py_schema_err!("Field `{}`: omit_on_error cannot be used with arguments", name);
                 }
@@ -176,7 +176,7 @@ impl Validator for DataclassArgsValidator {
                         // Default value exists, and passed validation if required
                         set_item!(field, value);
                     }
-                    Ok(None) | Err(ValError::Omit) => continue,
+                    Ok(None) | Err(ValError::Omit | ValError::DropField) => continue,
                     // Note: this will always use the field name even if there is an alias
                     // However, we don't mind so much because this error can only happen if the
                     // default value fails validation, which is arguably a developer error.
@@ -218,6 +218,9 @@ impl Validator for DataclassArgsValidator {
                     Err(ValError::LineErrors(line_errors)) => {
                         errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
                     }
+                    Err(ValError::Undefined) => {
+                        errors.push(ValLineError::new_with_loc(ErrorTypeDefaults::Missing, input, index));
+                    }
                     Err(err) => return Err(err),
                 },
                 // found a keyword argument, validate it
@@ -230,6 +233,14 @@ impl Validator for DataclassArgsValidator {
                                 .map(|err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)),
                         );
                     }
+                    Err(ValError::Undefined) => {
+                        errors.push(field.lookup_key.error(
+                            ErrorTypeDefaults::Missing,
+                            input,
+                            self.loc_by_alias,
+                            &field.name,
+                        ));
+                    }
                     Err(err) => return Err(err),
                 },
                 // found neither, check if there is a default value, otherwise error
@@ -248,7 +259,7 @@ impl Validator for DataclassArgsValidator {
                                 &field.name,
                             ));
                         }
-                        Err(ValError::Omit) => continue,
+                        Err(ValError::Omit | ValError::DropField) => continue,
                         Err(ValError::LineErrors(line_errors)) => {
                             for err in line_errors {
                                 // Note: this will always use the field name even if there is an alias
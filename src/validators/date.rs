@@ -8,7 +8,6 @@ use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{EitherDate, Input};
 
-use crate::tools::SchemaDict;
 use crate::validators::datetime::{NowConstraint, NowOp};
 
 use super::Exactness;
@@ -175,9 +174,21 @@ impl DateConstraints {
     }
 }
 
+/// Constraints may be given either as a `date` or as an ISO 8601 date string
 fn convert_pydate(schema: &Bound<'_, PyDict>, field: &Bound<'_, PyString>) -> PyResult<Option<Date>> {
-    match schema.get_as::<Bound<'_, PyDate>>(field)? {
-        Some(date) => Ok(Some(EitherDate::Py(date).as_raw()?)),
+    match schema.get_item(field)? {
+        Some(value) => {
+            if let Ok(py_str) = value.downcast::<PyString>() {
+                return Date::parse_str(py_str.to_str()?).map(Some).map_err(|err| {
+                    py_schema_error_type!(
+                        "Invalid `{}` constraint, {}",
+                        field,
+                        err.get_documentation().unwrap_or_default()
+                    )
+                });
+            }
+            Ok(Some(EitherDate::Py(value.downcast_into::<PyDate>()?).as_raw()?))
+        }
         None => Ok(None),
     }
 }
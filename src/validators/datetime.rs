@@ -1,7 +1,7 @@
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDateTime, PyDelta, PyDict, PyString};
 use speedate::{DateTime, Time};
 use std::cmp::Ordering;
 use strum::EnumMessage;
@@ -10,7 +10,7 @@ use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::build_tools::{py_schema_err, schema_or_config_same};
 use crate::errors::ToErrorValue;
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDateTime, Input};
+use crate::input::{int_as_datetime, EitherDateTime, Input};
 
 use crate::tools::SchemaDict;
 
@@ -21,7 +21,124 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationSta
 pub struct DateTimeValidator {
     strict: bool,
     constraints: Option<DateTimeConstraints>,
-    microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    microseconds_precision: DateTimeMicrosecondsPrecision,
+    timestamp_unit: Option<TimestampUnit>,
+}
+
+/// Like `speedate::MicrosecondsPrecisionOverflowBehavior`, but with an extra "round" mode which is
+/// only supported for datetimes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateTimeMicrosecondsPrecision {
+    Truncate,
+    Round,
+    Error,
+}
+
+impl DateTimeMicrosecondsPrecision {
+    fn from_py(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let value: Option<Bound<'_, PyString>> =
+            schema_or_config_same(schema, config, intern!(schema.py(), "microseconds_precision"))?;
+        match value.as_ref().map(|v| v.to_str()).transpose()? {
+            None | Some("truncate") => Ok(Self::Truncate),
+            Some("round") => Ok(Self::Round),
+            Some("error") => Ok(Self::Error),
+            Some(_) => {
+                py_schema_err!("Invalid `microseconds_precision`, must be one of \"truncate\", \"round\" or \"error\"")
+            }
+        }
+    }
+
+    /// rounding is done after parsing, so the parser itself just truncates
+    fn overflow_behavior(self) -> speedate::MicrosecondsPrecisionOverflowBehavior {
+        match self {
+            Self::Truncate | Self::Round => speedate::MicrosecondsPrecisionOverflowBehavior::Truncate,
+            Self::Error => speedate::MicrosecondsPrecisionOverflowBehavior::Error,
+        }
+    }
+}
+
+/// The unit of int and float unix timestamps, when set this replaces speedate's inference of
+/// seconds vs. milliseconds from the size of the timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimestampUnit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+}
+
+// 1600-01-01T00:00:00 and 9999-12-31T23:59:59.999999, the range supported by speedate
+const MIN_TIMESTAMP_MICROSECONDS: i64 = -11_676_096_000_000_000;
+const MAX_TIMESTAMP_MICROSECONDS: i64 = 253_402_300_799_999_999;
+// speedate interprets timestamps larger than this as milliseconds
+const MS_WATERSHED: i64 = 20_000_000_000;
+
+impl TimestampUnit {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let value: Option<Bound<'_, PyString>> = schema.get_as(intern!(schema.py(), "timestamp_unit"))?;
+        match value.as_ref().map(|v| v.to_str()).transpose()? {
+            None => Ok(None),
+            Some("seconds") => Ok(Some(Self::Seconds)),
+            Some("milliseconds") => Ok(Some(Self::Milliseconds)),
+            Some("microseconds") => Ok(Some(Self::Microseconds)),
+            Some(_) => py_schema_err!(
+                "Invalid `timestamp_unit`, must be one of \"seconds\", \"milliseconds\" or \"microseconds\""
+            ),
+        }
+    }
+
+    fn microseconds(self) -> i64 {
+        match self {
+            Self::Seconds => 1_000_000,
+            Self::Milliseconds => 1_000,
+            Self::Microseconds => 1,
+        }
+    }
+
+    /// Convert an int, float or numeric string input to a datetime, `None` means the input isn't a number,
+    /// only called in lax mode
+    fn validate_timestamp<'py>(
+        self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+    ) -> ValResult<Option<EitherDateTime<'py>>> {
+        if input.is_bool() {
+            return Ok(None);
+        }
+        let total_microseconds = if let Ok(int) = input.validate_int(false) {
+            int.into_inner()
+                .into_i64(py)
+                .ok()
+                .and_then(|int| int.checked_mul(self.microseconds()))
+        } else if let Ok(float) = input.validate_float(false) {
+            let float = float.into_inner().as_f64();
+            // leave NaN to the usual timestamp validation which has a dedicated error
+            if float.is_nan() {
+                return Ok(None);
+            }
+            let microseconds = (float * self.microseconds() as f64).round();
+            (microseconds.abs() < i64::MAX as f64).then_some(microseconds as i64)
+        } else {
+            return Ok(None);
+        };
+
+        match total_microseconds.filter(|m| (MIN_TIMESTAMP_MICROSECONDS..=MAX_TIMESTAMP_MICROSECONDS).contains(m)) {
+            Some(total_microseconds) => {
+                let seconds = total_microseconds.div_euclid(1_000_000);
+                let microseconds = total_microseconds.rem_euclid(1_000_000) as u32;
+                if seconds > MS_WATERSHED {
+                    int_as_datetime(
+                        input,
+                        seconds * 1_000 + i64::from(microseconds / 1_000),
+                        microseconds % 1_000,
+                    )
+                } else {
+                    int_as_datetime(input, seconds, microseconds)
+                }
+                .map(Some)
+            }
+            None => Err(ValError::new(ErrorTypeDefaults::DatetimeFromTimestampOverflow, input)),
+        }
+    }
 }
 
 pub(crate) fn extract_microseconds_precision(
@@ -51,7 +168,8 @@ impl BuildValidator for DateTimeValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             constraints: DateTimeConstraints::from_py(schema)?,
-            microseconds_precision: extract_microseconds_precision(schema, config)?,
+            microseconds_precision: DateTimeMicrosecondsPrecision::from_py(schema, config)?,
+            timestamp_unit: TimestampUnit::from_py(schema)?,
         }
         .into())
     }
@@ -67,14 +185,34 @@ impl Validator for DateTimeValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
-        let datetime = match input.validate_datetime(strict, self.microseconds_precision) {
-            Ok(val_match) => val_match.unpack(state),
-            // if the error was a parsing error, in lax mode we allow dates and add the time 00:00:00
-            Err(line_errors @ ValError::LineErrors(..)) if !strict => {
-                state.floor_exactness(Exactness::Lax);
-                datetime_from_date(input)?.ok_or(line_errors)?
+        let timestamp = match self.timestamp_unit {
+            Some(timestamp_unit) if !strict => timestamp_unit.validate_timestamp(py, input)?,
+            _ => None,
+        };
+        let datetime = if let Some(datetime) = timestamp {
+            state.floor_exactness(Exactness::Lax);
+            datetime
+        } else {
+            match input.validate_datetime(strict, self.microseconds_precision.overflow_behavior()) {
+                Ok(val_match) if self.microseconds_precision == DateTimeMicrosecondsPrecision::Round => {
+                    round_sub_microseconds(py, input, val_match.unpack(state))?
+                }
+                Ok(val_match) => val_match.unpack(state),
+                // the input was a valid datetime with too much precision, parsing it as a date won't help
+                Err(ValError::LineErrors(line_errors))
+                    if line_errors
+                        .iter()
+                        .any(|e| matches!(e.error_type, ErrorType::DatetimeSubMicrosecond { .. })) =>
+                {
+                    return Err(ValError::LineErrors(line_errors))
+                }
+                // if the error was a parsing error, in lax mode we allow dates and add the time 00:00:00
+                Err(line_errors @ ValError::LineErrors(..)) if !strict => {
+                    state.floor_exactness(Exactness::Lax);
+                    datetime_from_date(input)?.ok_or(line_errors)?
+                }
+                Err(otherwise) => return Err(otherwise),
             }
-            Err(otherwise) => return Err(otherwise),
         };
         if let Some(constraints) = &self.constraints {
             // if we get an error from as_speedate, it's probably because the input datetime was invalid
@@ -140,6 +278,41 @@ impl Validator for DateTimeValidator {
     }
 }
 
+/// speedate truncates any fractional second digits beyond the sixth, to round instead we inspect
+/// the first discarded digit of string inputs and add a microsecond if it's 5 or more
+fn round_sub_microseconds<'py>(
+    py: Python<'py>,
+    input: &(impl Input<'py> + ?Sized),
+    datetime: EitherDateTime<'py>,
+) -> ValResult<EitherDateTime<'py>> {
+    let Ok(either_str) = input.validate_str(false, false) else {
+        return Ok(datetime);
+    };
+    let either_str = either_str.into_inner();
+    let cow = either_str.as_cow()?;
+    if !first_sub_microsecond_digit(&cow).is_some_and(|digit| digit >= b'5') {
+        return Ok(datetime);
+    }
+    // let python handle carrying the extra microsecond into seconds, minutes etc.
+    let one_microsecond = PyDelta::new_bound(py, 0, 0, 1, false)?;
+    let rounded = datetime
+        .try_into_py(py)?
+        .call_method1(py, intern!(py, "__add__"), (one_microsecond,))?;
+    Ok(rounded.downcast_bound::<PyDateTime>(py)?.clone().into())
+}
+
+/// Find the seventh digit of the second fraction in a datetime string, e.g. `7` in `2022-01-01T12:00:00.1234567`
+fn first_sub_microsecond_digit(s: &str) -> Option<u8> {
+    // the fraction always comes after the first `:` of the time, this also rules out unix timestamps
+    let after_colon = &s[s.find(':')?..];
+    let fraction_start = after_colon.find(['.', ','])? + 1;
+    after_colon.as_bytes()[fraction_start..]
+        .iter()
+        .take_while(|b| b.is_ascii_digit())
+        .nth(6)
+        .copied()
+}
+
 /// In lax mode, if the input is not a datetime, we try parsing the input as a date and add the "00:00:00" time.
 /// Ok(None) means that this is not relevant to datetimes (the input was not a date nor a string)
 fn datetime_from_date<'py>(input: &(impl Input<'py> + ?Sized)) -> Result<Option<EitherDateTime<'py>>, ValError> {
@@ -0,0 +1,119 @@
+use pyo3::exceptions::PyDeprecationWarning;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyString};
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{LocItem, ValResult, ValidationWarning};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{CombinedValidator, ValidationState, Validator};
+
+/// Wraps the validator of a schema with a `deprecated` key, emitting a `DeprecationWarning` whenever a value is
+/// validated, Python's warning filters take care of only showing the warning once per location
+#[derive(Debug)]
+pub struct DeprecatedValidator {
+    validator: Box<CombinedValidator>,
+    message: String,
+}
+
+impl DeprecatedValidator {
+    /// Wrap `validator` if the schema has a truthy `deprecated` key, otherwise return it unchanged
+    pub fn wrap(schema: &Bound<'_, PyDict>, validator: CombinedValidator) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let Some(deprecated) = schema.get_item(intern!(py, "deprecated"))? else {
+            return Ok(validator);
+        };
+        let mut message = if let Ok(py_str) = deprecated.downcast::<PyString>() {
+            py_str.to_str()?.to_string()
+        } else if let Ok(py_bool) = deprecated.downcast::<PyBool>() {
+            if !py_bool.is_true() {
+                return Ok(validator);
+            }
+            format!("`{}` is deprecated", validator.get_name())
+        } else {
+            return py_schema_err!("`deprecated` must be a bool or a str");
+        };
+        if let Some(since) = schema.get_as::<String>(intern!(py, "deprecated_since"))? {
+            message = format!("{message} (deprecated since {since})");
+        }
+        Ok(Self {
+            validator: Box::new(validator),
+            message,
+        }
+        .into())
+    }
+
+    pub fn inner(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
+    /// Collect the warning if `collect_warnings` is set, otherwise emit a `DeprecationWarning`
+    fn warn<'py>(
+        &self,
+        py: Python<'py>,
+        input: PyObject,
+        field_name: Option<&str>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> PyResult<()> {
+        match state.warnings {
+            Some(ref mut warnings) => {
+                warnings.push(ValidationWarning::new(
+                    py,
+                    "deprecated",
+                    field_name,
+                    &self.message,
+                    input,
+                ));
+                Ok(())
+            }
+            None => PyErr::warn_bound(py, &py.get_type_bound::<PyDeprecationWarning>(), &self.message, 1),
+        }
+    }
+}
+
+impl_py_gc_traverse!(DeprecatedValidator { validator });
+
+impl Validator for DeprecatedValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let field_name = state.extra().field_name.as_ref().map(ToString::to_string);
+        self.warn(py, input.to_object(py), field_name.as_deref(), state)?;
+        self.validator.validate(py, input, state)
+    }
+
+    fn default_value<'py>(
+        &self,
+        py: Python<'py>,
+        outer_loc: Option<impl Into<LocItem>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Option<PyObject>> {
+        self.validator.default_value(py, outer_loc, state)
+    }
+
+    fn validate_assignment<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        field_name: &str,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        self.warn(py, field_value.clone().unbind(), Some(field_name), state)?;
+        self.validator
+            .validate_assignment(py, obj, field_name, field_value, state)
+    }
+
+    fn field_names(&self) -> Option<Vec<&str>> {
+        self.validator.field_names()
+    }
+
+    fn get_name(&self) -> &str {
+        self.validator.get_name()
+    }
+}
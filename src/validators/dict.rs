@@ -1,9 +1,10 @@
+use ahash::AHashSet;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyTuple};
 
-use crate::build_tools::is_strict;
-use crate::errors::{LocItem, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, LocItem, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
 use crate::input::{Input, ValidatedDict};
@@ -14,6 +15,31 @@ use super::any::AnyValidator;
 use super::list::length_check;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
+/// What to do when a key occurs more than once, JSON objects can contain duplicate keys and different
+/// keys can also be validated to the same output key
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum DuplicateKeysBehavior {
+    #[default]
+    UseLast,
+    UseFirst,
+    Error,
+}
+
+impl DuplicateKeysBehavior {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let behavior: Option<Bound<'_, PyString>> = schema.get_as(intern!(schema.py(), "duplicate_keys_behavior"))?;
+        match behavior.as_ref().map(|s| s.to_str()).transpose()? {
+            None | Some("use_last") => Ok(Self::UseLast),
+            Some("use_first") => Ok(Self::UseFirst),
+            Some("error") => Ok(Self::Error),
+            Some(s) => py_schema_err!(
+                "Invalid duplicate_keys_behavior: `{}`, expected use_last, use_first or error",
+                s
+            ),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct DictValidator {
     strict: bool,
@@ -21,6 +47,12 @@ pub struct DictValidator {
     value_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    forbidden_keys: Option<AHashSet<String>>,
+    required_keys: Option<Vec<String>>,
+    allow_pairs_list: bool,
+    duplicate_keys_behavior: DuplicateKeysBehavior,
+    // reject keys which aren't strings before they reach `key_validator`
+    strict_keys: bool,
     name: String,
 }
 
@@ -53,12 +85,59 @@ impl BuildValidator for DictValidator {
             value_validator,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            forbidden_keys: schema
+                .get_as::<Vec<String>>(intern!(py, "forbidden_keys"))?
+                .map(|keys| keys.into_iter().collect()),
+            required_keys: schema.get_as(intern!(py, "required_keys"))?,
+            allow_pairs_list: schema.get_as(intern!(py, "allow_pairs_list"))?.unwrap_or(false),
+            duplicate_keys_behavior: DuplicateKeysBehavior::from_py(schema)?,
+            strict_keys: schema.get_as(intern!(py, "strict_keys"))?.unwrap_or(false),
             name,
         }
         .into())
     }
 }
 
+impl DictValidator {
+    /// Convert a list or tuple of 2-item sequences (or a JSON array of 2-item arrays) into a dict,
+    /// returns `None` if the input isn't a sequence so normal dict validation can report the error
+    fn pairs_as_dict<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+    ) -> ValResult<Option<Bound<'py, PyDict>>> {
+        let sequence = match input.as_python() {
+            Some(obj) if obj.is_instance_of::<PyTuple>() || obj.is_instance_of::<PyList>() => obj.clone(),
+            Some(_) => return Ok(None),
+            None if input.validate_list(true).is_ok() => input.to_object(py).into_bound(py),
+            None => return Ok(None),
+        };
+        let dict = PyDict::new_bound(py);
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, item) in sequence.iter()?.enumerate() {
+            let item = item?;
+            let pair = if item.is_instance_of::<PyTuple>() || item.is_instance_of::<PyList>() {
+                item.iter()?.collect::<PyResult<Vec<_>>>()?
+            } else {
+                Vec::new()
+            };
+            match <[_; 2]>::try_from(pair) {
+                Ok([key, value]) => dict.set_item(key, value)?,
+                Err(_) => errors.push(ValLineError::new_with_loc(
+                    ErrorType::DictPairsInvalid { index, context: None },
+                    &item,
+                    index,
+                )),
+            }
+        }
+        if errors.is_empty() {
+            Ok(Some(dict))
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+}
+
 impl_py_gc_traverse!(DictValidator {
     key_validator,
     value_validator
@@ -72,12 +151,21 @@ impl Validator for DictValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
+        if self.allow_pairs_list && !strict {
+            if let Some(dict) = self.pairs_as_dict(py, input)? {
+                return self.validate(py, dict.as_any(), state);
+            }
+        }
         let dict = input.validate_dict(strict)?;
         dict.iterate(ValidateToDict {
             py,
             input,
             min_length: self.min_length,
             max_length: self.max_length,
+            forbidden_keys: self.forbidden_keys.as_ref(),
+            required_keys: self.required_keys.as_deref(),
+            duplicate_keys_behavior: self.duplicate_keys_behavior,
+            strict_keys: self.strict_keys,
             key_validator: &self.key_validator,
             value_validator: &self.value_validator,
             state,
@@ -94,6 +182,10 @@ struct ValidateToDict<'a, 's, 'py, I: Input<'py> + ?Sized> {
     input: &'a I,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    forbidden_keys: Option<&'a AHashSet<String>>,
+    required_keys: Option<&'a [String]>,
+    duplicate_keys_behavior: DuplicateKeysBehavior,
+    strict_keys: bool,
     key_validator: &'a CombinedValidator,
     value_validator: &'a CombinedValidator,
     state: &'a mut ValidationState<'s, 'py>,
@@ -109,9 +201,29 @@ where
     fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> ValResult<PyObject> {
         let output = PyDict::new_bound(self.py);
         let mut errors: Vec<ValLineError> = Vec::new();
+        let mut seen_keys: AHashSet<String> = AHashSet::new();
+        // keys are tracked separately from `output` since a key whose value fails validation is still a duplicate
+        let seen_output_keys = match self.duplicate_keys_behavior {
+            DuplicateKeysBehavior::UseLast => None,
+            _ => Some(PySet::empty_bound(self.py)?),
+        };
 
         for item_result in iterator {
             let (key, value) = item_result?;
+            // JSON keys are always strings, so only python keys need checking
+            if self.strict_keys
+                && key
+                    .borrow_input()
+                    .as_python()
+                    .is_some_and(|py_key| !py_key.is_instance_of::<PyString>())
+            {
+                errors.push(
+                    ValLineError::new(ErrorTypeDefaults::InvalidKey, key.borrow_input())
+                        .with_outer_location("[key]")
+                        .with_outer_location(key.clone()),
+                );
+                continue;
+            }
             let output_key = match self.key_validator.validate(self.py, key.borrow_input(), self.state) {
                 Ok(value) => Some(value),
                 Err(ValError::LineErrors(line_errors)) => {
@@ -124,6 +236,48 @@ where
                 Err(ValError::Omit) => continue,
                 Err(err) => return Err(err),
             };
+            // string keys are checked against `forbidden_keys` and `required_keys`
+            if let Some(key_str) = output_key
+                .as_ref()
+                .filter(|_| self.forbidden_keys.is_some() || self.required_keys.is_some())
+                .and_then(|output_key| output_key.downcast_bound::<PyString>(self.py).ok())
+            {
+                let key_name = key_str.to_str()?;
+                if self.forbidden_keys.is_some_and(|keys| keys.contains(key_name)) {
+                    errors.push(
+                        ValLineError::new(
+                            ErrorType::DictForbiddenKey {
+                                key_name: key_name.to_string(),
+                                context: None,
+                            },
+                            key.borrow_input(),
+                        )
+                        .with_outer_location(key.clone()),
+                    );
+                    continue;
+                }
+                if self.required_keys.is_some() {
+                    seen_keys.insert(key_name.to_string());
+                }
+            }
+            if let (Some(output_key), Some(seen_output_keys)) = (&output_key, &seen_output_keys) {
+                if seen_output_keys.contains(output_key)? {
+                    if self.duplicate_keys_behavior == DuplicateKeysBehavior::Error {
+                        errors.push(
+                            ValLineError::new(
+                                ErrorType::DictDuplicateKey {
+                                    key_name: output_key.bind(self.py).str()?.to_string(),
+                                    context: None,
+                                },
+                                key.borrow_input(),
+                            )
+                            .with_outer_location(key.clone()),
+                        );
+                    }
+                    continue;
+                }
+                seen_output_keys.add(output_key)?;
+            }
             let output_value = match self.value_validator.validate(self.py, value.borrow_input(), self.state) {
                 Ok(value) => Some(value),
                 Err(ValError::LineErrors(line_errors)) => {
@@ -140,6 +294,21 @@ where
             }
         }
 
+        if let Some(required_keys) = self.required_keys {
+            for key_name in required_keys {
+                if !seen_keys.contains(key_name.as_str()) {
+                    errors.push(ValLineError::new_with_loc(
+                        ErrorType::DictMissingKey {
+                            key_name: key_name.clone(),
+                            context: None,
+                        },
+                        self.input,
+                        key_name.clone(),
+                    ));
+                }
+            }
+        }
+
         if errors.is_empty() {
             let input = self.input;
             length_check!(input, "Dictionary", self.min_length, self.max_length, output);
@@ -0,0 +1,79 @@
+use pyo3::exceptions::PyKeyError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, SchemaValidator, ValidationState, Validator};
+
+/// Validator which delegates to a separate `SchemaValidator`, either to its root schema or, if `path` is set,
+/// to one of its definitions, this allows sharing schemas between validators without rebuilding them
+#[derive(Debug)]
+pub struct ExternalRefValidator {
+    schema_validator: Py<SchemaValidator>,
+    path: Option<String>,
+    name: String,
+}
+
+impl BuildValidator for ExternalRefValidator {
+    const EXPECTED_TYPE: &'static str = "external-ref";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let schema_validator: Bound<'_, SchemaValidator> = schema.get_as_req(intern!(py, "schema_ref"))?;
+        let path: Option<String> = schema.get_as(intern!(py, "path"))?;
+
+        let referenced = schema_validator.try_borrow()?;
+        let validator = match &path {
+            Some(path) => match referenced.definitions.get(path) {
+                Some(validator) => validator,
+                None => return py_schema_err!("Definition `{}` not found in the referenced validator", path),
+            },
+            None => &referenced.validator,
+        };
+        let name = format!("{}:{}", Self::EXPECTED_TYPE, validator.get_name());
+
+        Ok(Self {
+            schema_validator: schema_validator.unbind(),
+            path,
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(ExternalRefValidator { schema_validator });
+
+impl Validator for ExternalRefValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        // fails if the referenced validator is being rebuilt
+        let referenced = self.schema_validator.try_borrow(py).map_err(PyErr::from)?;
+        match &self.path {
+            // the definition was checked when building, but the referenced validator may have been rebuilt since
+            Some(path) => match referenced.definitions.get(path) {
+                Some(validator) => validator.validate(py, input, state),
+                None => Err(ValError::InternalErr(PyKeyError::new_err(format!(
+                    "Definition `{path}` not found in the referenced validator"
+                )))),
+            },
+            None => referenced.validator.validate(py, input, state),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
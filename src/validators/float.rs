@@ -2,17 +2,107 @@ use std::cmp::Ordering;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 
-use crate::build_tools::{is_strict, schema_or_config_same};
+use crate::build_tools::{is_strict, py_schema_err, schema_or_config_same};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherFloat, Input, ValidationMatch};
 use crate::tools::SchemaDict;
 
+use super::decimal::get_decimal_type;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 pub struct FloatBuilder;
 
+/// Converting a `Decimal` to a float can silently lose precision, so this can be disallowed
+fn check_not_decimal<'py>(input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+    if let Some(py_input) = input.as_python() {
+        if py_input.is_instance(get_decimal_type(py_input.py()))? {
+            return Err(ValError::new(ErrorTypeDefaults::FloatFromDecimalNotAllowed, input));
+        }
+    }
+    Ok(())
+}
+
+/// Locales whose number formatting is understood when parsing strings in lax mode, e.g. `1.234,5` in German
+#[derive(Debug, Clone, Copy)]
+enum NumericLocale {
+    German,
+    French,
+}
+
+impl NumericLocale {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        match schema.get_as::<Bound<'_, PyString>>(intern!(py, "numeric_string_locale"))? {
+            Some(locale) => match locale.to_str()? {
+                "de" => Ok(Some(Self::German)),
+                "fr" => Ok(Some(Self::French)),
+                other => py_schema_err!("Invalid numeric string locale: {}", other),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn is_thousands_separator(self, c: char) -> bool {
+        match self {
+            Self::German => c == '.',
+            // thousands are separated by spaces, often non-breaking ones
+            Self::French => matches!(c, ' ' | '\u{a0}' | '\u{202f}'),
+        }
+    }
+
+    /// Remove thousands separators and use `.` as the decimal separator, `None` if the separators don't split
+    /// the integer part into groups of three digits, e.g. `1.5` in German
+    fn normalize(self, s: &str) -> Option<String> {
+        let s = s.trim();
+        let (int_part, frac_part) = match s.split_once(',') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (s, None),
+        };
+        let mut groups = int_part.split(|c| self.is_thousands_separator(c));
+        let mut normalized = groups.next().unwrap_or_default().to_string();
+        let leading_digits = normalized.trim_start_matches(['+', '-']).len();
+        let mut grouped = false;
+        for group in groups {
+            if group.len() != 3 || !group.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            normalized.push_str(group);
+            grouped = true;
+        }
+        if grouped && !(1..=3).contains(&leading_digits) {
+            return None;
+        }
+        if let Some(frac_part) = frac_part {
+            if frac_part.contains(|c| self.is_thousands_separator(c)) {
+                return None;
+            }
+            normalized.push('.');
+            normalized.push_str(frac_part);
+        }
+        Some(normalized)
+    }
+}
+
+/// Like `input.validate_float(strict)`, but in lax mode strings are parsed according to `locale` if it's set
+fn validate_float_locale<'a, 'py>(
+    input: &'a (impl Input<'py> + ?Sized),
+    strict: bool,
+    locale: Option<NumericLocale>,
+) -> ValResult<ValidationMatch<EitherFloat<'a>>> {
+    if let (Some(locale), false) = (locale, strict) {
+        if let Ok(either_str) = input.validate_str(true, false) {
+            let normalized = locale.normalize(either_str.into_inner().as_cow()?.as_ref());
+            return match normalized.map(|s| s.parse()) {
+                Some(Ok(float)) => Ok(ValidationMatch::lax(EitherFloat::F64(float))),
+                _ => Err(ValError::new(ErrorTypeDefaults::FloatParsing, input)),
+            };
+        }
+    }
+    input.validate_float(strict)
+}
+
 impl BuildValidator for FloatBuilder {
     const EXPECTED_TYPE: &'static str = "float";
     fn build(
@@ -25,13 +115,16 @@ impl BuildValidator for FloatBuilder {
             || schema.get_item(intern!(py, "le"))?.is_some()
             || schema.get_item(intern!(py, "lt"))?.is_some()
             || schema.get_item(intern!(py, "ge"))?.is_some()
-            || schema.get_item(intern!(py, "gt"))?.is_some();
+            || schema.get_item(intern!(py, "gt"))?.is_some()
+            || schema.get_item(intern!(py, "decimal_places"))?.is_some();
         if use_constrained {
             ConstrainedFloatValidator::build(schema, config, definitions)
         } else {
             Ok(FloatValidator {
                 strict: is_strict(schema, config)?,
                 allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                allow_decimal_coercion: schema.get_as(intern!(py, "allow_decimal_coercion"))?.unwrap_or(true),
+                numeric_string_locale: NumericLocale::from_schema(schema)?,
             }
             .into())
         }
@@ -42,6 +135,8 @@ impl BuildValidator for FloatBuilder {
 pub struct FloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    allow_decimal_coercion: bool,
+    numeric_string_locale: Option<NumericLocale>,
 }
 
 impl BuildValidator for FloatValidator {
@@ -56,6 +151,8 @@ impl BuildValidator for FloatValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            allow_decimal_coercion: schema.get_as(intern!(py, "allow_decimal_coercion"))?.unwrap_or(true),
+            numeric_string_locale: NumericLocale::from_schema(schema)?,
         }
         .into())
     }
@@ -70,7 +167,11 @@ impl Validator for FloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        if !self.allow_decimal_coercion {
+            check_not_decimal(input)?;
+        }
+        let either_float =
+            validate_float_locale(input, state.strict_or(self.strict), self.numeric_string_locale)?.unpack(state);
         if !self.allow_inf_nan && !either_float.as_f64().is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
@@ -86,11 +187,14 @@ impl Validator for FloatValidator {
 pub struct ConstrainedFloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    allow_decimal_coercion: bool,
+    numeric_string_locale: Option<NumericLocale>,
     multiple_of: Option<f64>,
     le: Option<f64>,
     lt: Option<f64>,
     ge: Option<f64>,
     gt: Option<f64>,
+    decimal_places: Option<u32>,
 }
 
 impl_py_gc_traverse!(ConstrainedFloatValidator {});
@@ -102,7 +206,11 @@ impl Validator for ConstrainedFloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        if !self.allow_decimal_coercion {
+            check_not_decimal(input)?;
+        }
+        let either_float =
+            validate_float_locale(input, state.strict_or(self.strict), self.numeric_string_locale)?.unpack(state);
         let float: f64 = either_float.as_f64();
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
@@ -164,6 +272,9 @@ impl Validator for ConstrainedFloatValidator {
                 ));
             }
         }
+        if let Some(decimal_places) = self.decimal_places {
+            return Ok(round_half_even(float, decimal_places).into_py(py));
+        }
         Ok(either_float.into_py(py))
     }
 
@@ -183,12 +294,60 @@ impl BuildValidator for ConstrainedFloatValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            allow_decimal_coercion: schema.get_as(intern!(py, "allow_decimal_coercion"))?.unwrap_or(true),
+            numeric_string_locale: NumericLocale::from_schema(schema)?,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
             gt: schema.get_as(intern!(py, "gt"))?,
+            decimal_places: schema.get_as(intern!(py, "decimal_places"))?,
         }
         .into())
     }
 }
+
+/// Round `value` to `decimal_places` using round-half-even.
+///
+/// The rounding is done on the integer digits of the shortest decimal representation of `value`,
+/// so e.g. `2.675` is rounded as if it were exactly `2.675`, rather than the closest `f64` to it
+/// (which is slightly less), and without the double rounding of scaling by a power of ten.
+fn round_half_even(value: f64, decimal_places: u32) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+    // formats as e.g. `-1.005e0`, with the fewest digits which round-trip
+    let repr = format!("{value:e}");
+    let (mantissa, exponent) = repr.split_once('e').expect("float repr always contains an exponent");
+    let exponent: i64 = exponent.parse().expect("float exponent is always an integer");
+    let (negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => (true, mantissa),
+        None => (false, mantissa),
+    };
+    let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+    // `value == digits * 10 ** -scale`
+    let scale = digits.len() as i64 - 1 - exponent;
+    // the number of trailing digits which need to be removed
+    let excess = scale - i64::from(decimal_places);
+    if excess <= 0 {
+        return value;
+    }
+    let digits: u64 = digits.parse().expect("float mantissa digits always fit in a u64");
+    let rounded = match 10_u64.checked_pow(u32::try_from(excess).unwrap_or(u32::MAX)) {
+        Some(divisor) => {
+            let (quotient, remainder) = (digits / divisor, digits % divisor);
+            let half = divisor / 2;
+            if remainder > half || (remainder == half && quotient % 2 == 1) {
+                quotient + 1
+            } else {
+                quotient
+            }
+        }
+        // `digits` has at most 17 digits, so it's always less than half of the divisor
+        None => 0,
+    };
+    let sign = if negative { "-" } else { "" };
+    format!("{sign}{rounded}e-{decimal_places}")
+        .parse()
+        .expect("rounded float repr is always valid")
+}
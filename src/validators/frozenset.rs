@@ -5,7 +5,7 @@ use crate::errors::ValResult;
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
-use super::list::min_length_check;
+use super::list::{exact_length_check, min_length_check};
 use super::set::set_build;
 use super::validation_state::ValidationState;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
@@ -16,6 +16,7 @@ pub struct FrozenSetValidator {
     item_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    exact_length: Option<usize>,
     name: String,
 }
 
@@ -43,6 +44,7 @@ impl Validator for FrozenSetValidator {
             item_validator: &self.item_validator,
             state,
         })??;
+        exact_length_check!(input, "Frozenset", self.exact_length, f_set);
         min_length_check!(input, "Frozenset", self.min_length, f_set);
         Ok(f_set.into_py(py))
     }
@@ -2,9 +2,11 @@ use std::sync::Arc;
 
 use pyo3::exceptions::{PyAssertionError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyAny, PyDict, PyString};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
+use crate::build_tools::py_schema_err;
 use crate::errors::{
     ErrorType, PydanticCustomError, PydanticKnownError, PydanticOmit, ToErrorValue, ValError, ValResult,
     ValidationError,
@@ -12,7 +14,7 @@ use crate::errors::{
 use crate::input::Input;
 use crate::py_gc::PyGcTraverse;
 use crate::tools::{function_name, safe_repr, SchemaDict};
-use crate::PydanticUseDefault;
+use crate::{PydanticDropFieldType, PydanticUndefinedType, PydanticUseDefault};
 
 use super::generator::InternalValidator;
 use super::{
@@ -25,6 +27,8 @@ struct FunctionInfo {
     pub function: Py<PyAny>,
     pub field_name: Option<Py<PyString>>,
     pub info_arg: bool,
+    /// Read-only view of the function schema's `metadata` dict, exposed as `info.metadata`
+    pub metadata: Option<PyObject>,
 }
 
 fn destructure_function_schema(schema: &Bound<'_, PyDict>) -> PyResult<FunctionInfo> {
@@ -39,15 +43,58 @@ fn destructure_function_schema(schema: &Bound<'_, PyDict>) -> PyResult<FunctionI
     let field_name = func_dict
         .get_as::<&PyString>(intern!(schema.py(), "field_name"))?
         .map(Into::into);
+    let metadata = match func_dict.get_as::<Bound<'_, PyDict>>(intern!(schema.py(), "metadata"))? {
+        Some(metadata) => Some(
+            schema
+                .py()
+                .import_bound(intern!(schema.py(), "types"))?
+                .getattr(intern!(schema.py(), "MappingProxyType"))?
+                .call1((metadata.copy()?,))?
+                .unbind(),
+        ),
+        None => None,
+    };
     Ok(FunctionInfo {
         function,
         field_name,
         info_arg,
+        metadata,
     })
 }
 
+/// `co_flags` of generators and coroutines, whose bytecode can match a plain function returning its argument
+const CO_GENERATOR_OR_COROUTINE: u32 = 0x20 | 0x80 | 0x100 | 0x200;
+
+static IDENTITY_CO_CODE: GILOnceCell<PyObject> = GILOnceCell::new();
+
+/// Whether `function` is known to return its first argument unchanged, so calling it can be skipped:
+/// either it's marked with `__pydantic_noop__ = True`, or it's a plain function with the same bytecode
+/// as `lambda v: v`, e.g. `def f(value, info): return value`
+fn is_noop_function(function: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let py = function.py();
+    if let Ok(marker) = function.getattr(intern!(py, "__pydantic_noop__")) {
+        return marker.is_truthy();
+    }
+    let function_type = py
+        .import_bound(intern!(py, "types"))?
+        .getattr(intern!(py, "FunctionType"))?;
+    if !function.get_type().is(&function_type) {
+        return Ok(false);
+    }
+    let code = function.getattr(intern!(py, "__code__"))?;
+    if code.getattr(intern!(py, "co_argcount"))?.extract::<usize>()? == 0
+        || code.getattr(intern!(py, "co_flags"))?.extract::<u32>()? & CO_GENERATOR_OR_COROUTINE != 0
+    {
+        return Ok(false);
+    }
+    let identity_co_code = IDENTITY_CO_CODE.get_or_try_init(py, || {
+        Ok::<_, PyErr>(py.eval_bound("(lambda v: v).__code__.co_code", None, None)?.unbind())
+    })?;
+    code.getattr(intern!(py, "co_code"))?.eq(identity_co_code)
+}
+
 macro_rules! impl_build {
-    ($impl_name:ident, $name:literal) => {
+    ($impl_name:ident, $name:literal $(, $noop_field:ident)?) => {
         impl BuildValidator for $impl_name {
             const EXPECTED_TYPE: &'static str = $name;
             fn build(
@@ -64,6 +111,7 @@ macro_rules! impl_build {
                     function_name(func_info.function.bind(py))?,
                     validator.get_name()
                 );
+                $(let $noop_field = is_noop_function(func_info.function.bind(py))?;)?
                 Ok(Self {
                     validator: Box::new(validator),
                     func: func_info.function,
@@ -74,6 +122,11 @@ macro_rules! impl_build {
                     name,
                     field_name: func_info.field_name,
                     info_arg: func_info.info_arg,
+                    metadata: func_info.metadata,
+                    skip_on_missing_context: schema
+                        .get_as(intern!(py, "skip_on_missing_context"))?
+                        .unwrap_or(false),
+                    $($noop_field,)?
                 }
                 .into())
             }
@@ -89,6 +142,9 @@ pub struct FunctionBeforeValidator {
     name: String,
     field_name: Option<Py<PyString>>,
     info_arg: bool,
+    metadata: Option<PyObject>,
+    // when validating without a `context`, the function isn't called
+    skip_on_missing_context: bool,
 }
 
 impl_build!(FunctionBeforeValidator, "function-before");
@@ -102,7 +158,13 @@ impl FunctionBeforeValidator {
         state: &'s mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
+            let info = ValidationInfo::new(
+                py,
+                state.extra(),
+                &self.config,
+                self.field_name.clone(),
+                self.metadata.as_ref(),
+            );
             self.func.call1(py, (input.to_object(py), info))
         } else {
             self.func.call1(py, (input.to_object(py),))
@@ -115,7 +177,8 @@ impl FunctionBeforeValidator {
 impl_py_gc_traverse!(FunctionBeforeValidator {
     validator,
     func,
-    config
+    config,
+    metadata
 });
 
 impl Validator for FunctionBeforeValidator {
@@ -125,7 +188,16 @@ impl Validator for FunctionBeforeValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let validate = |v, s: &mut ValidationState<'_, 'py>| self.validator.validate(py, &v, s);
+        if self.skip_on_missing_context && state.extra().context.is_none() {
+            return self.validator.validate(py, input, state);
+        }
+        let validate = |v: Bound<'py, PyAny>, s: &mut ValidationState<'_, 'py>| {
+            // the function asked for the default value to be used, or a `missing` error if there isn't one
+            if v.is(&PydanticUndefinedType::new(py)) {
+                return Err(ValError::Undefined);
+            }
+            self.validator.validate(py, &v, s)
+        };
         self._validate(validate, py, input, state)
     }
     fn validate_assignment<'py>(
@@ -136,6 +208,11 @@ impl Validator for FunctionBeforeValidator {
         field_value: &Bound<'py, PyAny>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        if self.skip_on_missing_context && state.extra().context.is_none() {
+            return self
+                .validator
+                .validate_assignment(py, obj, field_name, field_value, state);
+        }
         let validate = move |v, s: &mut ValidationState<'_, 'py>| {
             self.validator.validate_assignment(py, &v, field_name, field_value, s)
         };
@@ -155,9 +232,14 @@ pub struct FunctionAfterValidator {
     name: String,
     field_name: Option<Py<PyString>>,
     info_arg: bool,
+    metadata: Option<PyObject>,
+    // when validating without a `context`, the function isn't called
+    skip_on_missing_context: bool,
+    // the function returns its input unchanged, so it isn't called, see `is_noop_function`
+    noop: bool,
 }
 
-impl_build!(FunctionAfterValidator, "function-after");
+impl_build!(FunctionAfterValidator, "function-after", noop);
 
 impl FunctionAfterValidator {
     fn _validate<'py, I: Input<'py> + ?Sized>(
@@ -168,8 +250,17 @@ impl FunctionAfterValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let v = call(input, state)?;
+        if self.noop || (self.skip_on_missing_context && state.extra().context.is_none()) {
+            return Ok(v);
+        }
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
+            let info = ValidationInfo::new(
+                py,
+                state.extra(),
+                &self.config,
+                self.field_name.clone(),
+                self.metadata.as_ref(),
+            );
             self.func.call1(py, (v.to_object(py), info))
         } else {
             self.func.call1(py, (v.to_object(py),))
@@ -181,7 +272,8 @@ impl FunctionAfterValidator {
 impl_py_gc_traverse!(FunctionAfterValidator {
     validator,
     func,
-    config
+    config,
+    metadata
 });
 
 impl Validator for FunctionAfterValidator {
@@ -192,7 +284,12 @@ impl Validator for FunctionAfterValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let validate = |v: &_, s: &mut ValidationState<'_, 'py>| self.validator.validate(py, v, s);
-        self._validate(validate, py, input, state)
+        let output = self._validate(validate, py, input, state)?;
+        // the function asked for this field to be dropped, fields treat it like a `PydanticOmit` error
+        if output.is(&PydanticDropFieldType::new(py)) {
+            return Err(ValError::DropField);
+        }
+        Ok(output)
     }
     fn validate_assignment<'py>(
         &self,
@@ -220,6 +317,7 @@ pub struct FunctionPlainValidator {
     name: String,
     field_name: Option<Py<PyString>>,
     info_arg: bool,
+    metadata: Option<PyObject>,
 }
 
 impl BuildValidator for FunctionPlainValidator {
@@ -231,6 +329,10 @@ impl BuildValidator for FunctionPlainValidator {
         _definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        // the function is the whole validation, so there's no inner schema to fall back on
+        if schema.contains(intern!(py, "skip_on_missing_context"))? {
+            return py_schema_err!("`skip_on_missing_context` is not supported by `function-plain` validators");
+        }
         let function_info = destructure_function_schema(schema)?;
         Ok(Self {
             func: function_info.function.clone(),
@@ -241,12 +343,13 @@ impl BuildValidator for FunctionPlainValidator {
             name: format!("function-plain[{}()]", function_name(function_info.function.bind(py))?),
             field_name: function_info.field_name.clone(),
             info_arg: function_info.info_arg,
+            metadata: function_info.metadata.clone(),
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(FunctionPlainValidator { func, config });
+impl_py_gc_traverse!(FunctionPlainValidator { func, config, metadata });
 
 impl Validator for FunctionPlainValidator {
     fn validate<'py>(
@@ -256,7 +359,13 @@ impl Validator for FunctionPlainValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
+            let info = ValidationInfo::new(
+                py,
+                state.extra(),
+                &self.config,
+                self.field_name.clone(),
+                self.metadata.as_ref(),
+            );
             self.func.call1(py, (input.to_object(py), info))
         } else {
             self.func.call1(py, (input.to_object(py),))
@@ -273,10 +382,14 @@ impl Validator for FunctionPlainValidator {
 pub struct FunctionWrapValidator {
     validator: Arc<CombinedValidator>,
     func: PyObject,
+    on_error: Option<PyObject>,
     config: PyObject,
     name: String,
     field_name: Option<Py<PyString>>,
     info_arg: bool,
+    metadata: Option<PyObject>,
+    // when validating without a `context`, the function isn't called and only the inner validator is used
+    skip_on_missing_context: bool,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
 }
@@ -297,6 +410,7 @@ impl BuildValidator for FunctionWrapValidator {
         Ok(Self {
             validator: Arc::new(validator),
             func: function_info.function.clone(),
+            on_error: schema.get_as(intern!(py, "on_error"))?,
             config: match config {
                 Some(c) => c.clone().into(),
                 None => py.None(),
@@ -304,6 +418,8 @@ impl BuildValidator for FunctionWrapValidator {
             name: format!("function-wrap[{}()]", function_name(function_info.function.bind(py))?),
             field_name: function_info.field_name.clone(),
             info_arg: function_info.info_arg,
+            metadata: function_info.metadata.clone(),
+            skip_on_missing_context: schema.get_as(intern!(py, "skip_on_missing_context"))?.unwrap_or(false),
             hide_input_in_errors,
             validation_error_cause,
         }
@@ -320,19 +436,42 @@ impl FunctionWrapValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
+            let info = ValidationInfo::new(
+                py,
+                state.extra(),
+                &self.config,
+                self.field_name.clone(),
+                self.metadata.as_ref(),
+            );
             self.func.call1(py, (input.to_object(py), handler, info))
         } else {
             self.func.call1(py, (input.to_object(py), handler))
         };
-        r.map_err(|e| convert_err(py, e, input))
+        match (r, &self.on_error) {
+            // a `ValidationError`, e.g. raised by the handler, is passed to `on_error` which provides a fallback
+            (Err(err), Some(on_error)) if err.is_instance_of::<ValidationError>(py) => {
+                let info = ValidationInfo::new(
+                    py,
+                    state.extra(),
+                    &self.config,
+                    self.field_name.clone(),
+                    self.metadata.as_ref(),
+                );
+                on_error
+                    .call1(py, (input.to_object(py), err.value_bound(py), info))
+                    .map_err(|e| convert_err(py, e, input))
+            }
+            (r, _) => r.map_err(|e| convert_err(py, e, input)),
+        }
     }
 }
 
 impl_py_gc_traverse!(FunctionWrapValidator {
     validator,
     func,
-    config
+    on_error,
+    config,
+    metadata
 });
 
 impl Validator for FunctionWrapValidator {
@@ -342,6 +481,9 @@ impl Validator for FunctionWrapValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        if self.skip_on_missing_context && state.extra().context.is_none() {
+            return self.validator.validate(py, input, state);
+        }
         let handler = ValidatorCallable {
             validator: InternalValidator::new(
                 py,
@@ -366,6 +508,11 @@ impl Validator for FunctionWrapValidator {
         field_value: &Bound<'py, PyAny>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        if self.skip_on_missing_context && state.extra().context.is_none() {
+            return self
+                .validator
+                .validate_assignment(py, obj, field_name, field_value, state);
+        }
         let handler = AssignmentValidatorCallable {
             validator: InternalValidator::new(
                 py,
@@ -501,16 +648,25 @@ pub struct ValidationInfo {
     context: Option<PyObject>,
     data: Option<Py<PyDict>>,
     field_name: Option<Py<PyString>>,
+    metadata: Option<PyObject>,
     mode: InputType,
 }
 
 impl ValidationInfo {
-    fn new(py: Python, extra: &Extra, config: &PyObject, field_name: Option<Py<PyString>>) -> Self {
+    /// `field_name` from the function's schema takes precedence over the field currently being validated
+    fn new(
+        py: Python,
+        extra: &Extra,
+        config: &PyObject,
+        field_name: Option<Py<PyString>>,
+        metadata: Option<&PyObject>,
+    ) -> Self {
         Self {
             config: config.clone_ref(py),
             context: extra.context.map(|ctx| ctx.clone().into()),
-            field_name,
+            field_name: field_name.or_else(|| extra.field_name.as_ref().map(|f| f.clone().unbind())),
             data: extra.data.as_ref().map(|data| data.clone().into()),
+            metadata: metadata.map(|m| m.clone_ref(py)),
             mode: extra.input_type,
         }
     }
@@ -520,6 +676,9 @@ impl ValidationInfo {
         if let Some(context) = &self.context {
             visit.call(context)?;
         }
+        if let Some(metadata) = &self.metadata {
+            visit.call(metadata)?;
+        }
         Ok(())
     }
 
@@ -3,8 +3,8 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ValError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{Input, Int};
 use crate::tools::SchemaDict;
 
@@ -13,6 +13,7 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationSta
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    strict_no_bool: bool,
 }
 
 impl BuildValidator for IntValidator {
@@ -28,12 +29,15 @@ impl BuildValidator for IntValidator {
             || schema.get_item(intern!(py, "le"))?.is_some()
             || schema.get_item(intern!(py, "lt"))?.is_some()
             || schema.get_item(intern!(py, "ge"))?.is_some()
-            || schema.get_item(intern!(py, "gt"))?.is_some();
+            || schema.get_item(intern!(py, "gt"))?.is_some()
+            || schema.get_item(intern!(py, "bit_width"))?.is_some()
+            || schema.get_item(intern!(py, "unsigned"))?.is_some();
         if use_constrained {
             ConstrainedIntValidator::build(schema, config)
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                strict_no_bool: schema.get_as(intern!(py, "strict_no_bool"))?.unwrap_or(false),
             }
             .into())
         }
@@ -49,6 +53,9 @@ impl Validator for IntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        if self.strict_no_bool {
+            check_not_bool(input)?;
+        }
         input
             .validate_int(state.strict_or(self.strict))
             .map(|val_match| val_match.unpack(state).into_py(py))
@@ -59,14 +66,61 @@ impl Validator for IntValidator {
     }
 }
 
+/// `bool` is a subclass of `int`, so is accepted as an integer unless this is used to disallow it
+fn check_not_bool<'py>(input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+    if input.is_bool() {
+        return Err(ValError::new(ErrorTypeDefaults::IntFromBoolNotAllowed, input));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
+    strict_no_bool: bool,
     multiple_of: Option<Int>,
     le: Option<Int>,
     lt: Option<Int>,
     ge: Option<Int>,
     gt: Option<Int>,
+    bit_width: Option<BitWidth>,
+}
+
+/// Range of integers representable in a fixed number of bits, from the `bit_width` and `unsigned` schema keys
+#[derive(Debug, Clone)]
+struct BitWidth {
+    bits: u8,
+    unsigned: bool,
+    min: Int,
+    max: Int,
+}
+
+impl BitWidth {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let unsigned: bool = schema.get_as(intern!(py, "unsigned"))?.unwrap_or(false);
+        let Some(bits) = schema.get_as::<u8>(intern!(py, "bit_width"))? else {
+            if unsigned {
+                return py_schema_err!("`unsigned` requires `bit_width` to be set");
+            }
+            return Ok(None);
+        };
+        if ![8, 16, 32, 64].contains(&bits) {
+            return py_schema_err!("Invalid `bit_width`: {}, expected 8, 16, 32 or 64", bits);
+        }
+        let (min, max) = if unsigned {
+            (BigInt::from(0), (BigInt::from(1) << bits) - 1)
+        } else {
+            let half = BigInt::from(1) << (bits - 1);
+            (-half.clone(), half - 1)
+        };
+        Ok(Some(Self {
+            bits,
+            unsigned,
+            min: Int::Big(min),
+            max: Int::Big(max),
+        }))
+    }
 }
 
 impl_py_gc_traverse!(ConstrainedIntValidator {});
@@ -78,6 +132,9 @@ impl Validator for ConstrainedIntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        if self.strict_no_bool {
+            check_not_bool(input)?;
+        }
         let either_int = input.validate_int(state.strict_or(self.strict))?.unpack(state);
         let int_value = either_int.as_int()?;
 
@@ -136,6 +193,18 @@ impl Validator for ConstrainedIntValidator {
                 ));
             }
         }
+        if let Some(ref bit_width) = self.bit_width {
+            if int_value < bit_width.min || int_value > bit_width.max {
+                return Err(ValError::new(
+                    ErrorType::IntOverflow {
+                        bit_width: bit_width.bits,
+                        unsigned: bit_width.unsigned,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
         Ok(either_int.into_py(py))
     }
 
@@ -149,11 +218,13 @@ impl ConstrainedIntValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            strict_no_bool: schema.get_as(intern!(py, "strict_no_bool"))?.unwrap_or(false),
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
             gt: schema.get_as(intern!(py, "gt"))?,
+            bit_width: BitWidth::from_schema(schema)?,
         }
         .into())
     }
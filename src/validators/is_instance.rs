@@ -1,7 +1,7 @@
 use pyo3::exceptions::PyNotImplementedError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyTuple, PyType};
 
 use crate::build_tools::py_schema_err;
 use crate::errors::{ErrorType, ValError, ValResult};
@@ -81,9 +81,18 @@ impl Validator for IsInstanceValidator {
 pub fn class_repr(schema: &Bound<'_, PyDict>, class: &Bound<'_, PyAny>) -> PyResult<String> {
     match schema.get_as(intern!(schema.py(), "cls_repr"))? {
         Some(s) => Ok(s),
-        None => match class.extract::<&PyType>() {
-            Ok(t) => Ok(t.qualname()?.to_string()),
-            Err(_) => Ok(class.repr()?.extract()?),
-        },
+        None => type_repr(class),
+    }
+}
+
+/// Repr of a class, or of a tuple of classes as accepted by `isinstance`, e.g. `int | str`
+fn type_repr(class: &Bound<'_, PyAny>) -> PyResult<String> {
+    if let Ok(t) = class.downcast::<PyType>() {
+        Ok(t.qualname()?.to_string())
+    } else if let Ok(tuple) = class.downcast::<PyTuple>() {
+        let reprs = tuple.iter().map(|c| type_repr(&c)).collect::<PyResult<Vec<_>>>()?;
+        Ok(reprs.join(" | "))
+    } else {
+        Ok(class.repr()?.extract()?)
     }
 }
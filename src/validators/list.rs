@@ -1,8 +1,10 @@
 use std::sync::OnceLock;
 
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 
+use crate::build_tools::py_schema_err;
 use crate::errors::ValResult;
 use crate::input::{
     no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxLengthCheck, ValidatedList,
@@ -17,9 +19,38 @@ pub struct ListValidator {
     item_validator: Option<Box<CombinedValidator>>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    exact_length: Option<usize>,
+    output_type: ListOutputType,
+    mutate_in_place: bool,
     name: OnceLock<String>,
 }
 
+/// The Python type a list schema returns once its items are validated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum ListOutputType {
+    #[default]
+    List,
+    Tuple,
+}
+
+impl ListOutputType {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let output_type: Option<Bound<'_, PyString>> = schema.get_as(intern!(schema.py(), "output_type"))?;
+        match output_type.as_ref().map(|s| s.to_str()).transpose()? {
+            None | Some("list") => Ok(Self::List),
+            Some("tuple") => Ok(Self::Tuple),
+            Some(s) => py_schema_err!("Invalid output_type: `{}`, expected list or tuple", s),
+        }
+    }
+
+    fn output(self, py: Python<'_>, items: Vec<PyObject>) -> PyObject {
+        match self {
+            Self::List => items.into_py(py),
+            Self::Tuple => PyTuple::new_bound(py, items).into_py(py),
+        }
+    }
+}
+
 pub fn get_items_schema(
     schema: &Bound<'_, PyDict>,
     config: Option<&Bound<'_, PyDict>>,
@@ -93,6 +124,26 @@ macro_rules! min_length_check {
 }
 pub(crate) use min_length_check;
 
+macro_rules! exact_length_check {
+    ($input:ident, $field_type:literal, $exact_length:expr, $obj:ident) => {{
+        if let Some(exact_length) = $exact_length {
+            let actual_length = $obj.len();
+            if actual_length != exact_length {
+                return Err(crate::errors::ValError::new(
+                    crate::errors::ErrorType::ExactLength {
+                        field_type: $field_type.to_string(),
+                        exact_length,
+                        actual_length,
+                        context: None,
+                    },
+                    $input,
+                ));
+            }
+        }
+    }};
+}
+pub(crate) use exact_length_check;
+
 impl BuildValidator for ListValidator {
     const EXPECTED_TYPE: &'static str = "list";
 
@@ -108,6 +159,9 @@ impl BuildValidator for ListValidator {
             item_validator,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
+            exact_length: schema.get_as(pyo3::intern!(py, "exact_length"))?,
+            output_type: ListOutputType::from_py(schema)?,
+            mutate_in_place: schema.get_as(intern!(py, "mutate_in_place"))?.unwrap_or(false),
             name: OnceLock::new(),
         }
         .into())
@@ -138,9 +192,13 @@ impl Validator for ListValidator {
             })??,
             None => {
                 if let Some(py_list) = seq.as_py_list() {
+                    exact_length_check!(input, "List", self.exact_length, py_list);
                     length_check!(input, "List", self.min_length, self.max_length, py_list);
-                    let list_copy = py_list.get_slice(0, usize::MAX);
-                    return Ok(list_copy.into_py(py));
+                    return Ok(match self.output_type {
+                        ListOutputType::List if self.mutate_in_place => py_list.into_py(py),
+                        ListOutputType::List => py_list.get_slice(0, usize::MAX).into_py(py),
+                        ListOutputType::Tuple => py_list.to_tuple().into_py(py),
+                    });
                 }
 
                 seq.iterate(ToVec {
@@ -152,8 +210,14 @@ impl Validator for ListValidator {
                 })??
             }
         };
+        exact_length_check!(input, "List", self.exact_length, output);
         min_length_check!(input, "List", self.min_length, output);
-        Ok(output.into_py(py))
+        if self.mutate_in_place && self.output_type == ListOutputType::List {
+            if let Some(py_list) = input.as_python().and_then(|obj| obj.downcast::<PyList>().ok()) {
+                return Ok(replace_items(py_list, output)?);
+            }
+        }
+        Ok(self.output_type.output(py, output))
     }
 
     fn get_name(&self) -> &str {
@@ -176,6 +240,18 @@ impl Validator for ListValidator {
     }
 }
 
+/// Replace the items of `py_list` with `items`, dropping any items beyond the end of `items`
+fn replace_items(py_list: &Bound<'_, PyList>, items: Vec<PyObject>) -> PyResult<PyObject> {
+    let length = items.len();
+    for (index, item) in items.into_iter().enumerate() {
+        py_list.set_item(index, item)?;
+    }
+    if py_list.len() > length {
+        py_list.del_slice(length, usize::MAX)?;
+    }
+    Ok(py_list.clone().into_py(py_list.py()))
+}
+
 struct ValidateToVec<'a, 's, 'py, I: Input<'py> + ?Sized> {
     py: Python<'py>,
     input: &'a I,
@@ -4,7 +4,7 @@ use core::fmt::Debug;
 use std::cmp::Ordering;
 
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyInt, PyList};
+use pyo3::types::{PyDict, PyFloat, PyInt, PyList};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use ahash::AHashMap;
@@ -32,6 +32,8 @@ pub struct LiteralLookup<T: Debug> {
     expected_bool: Option<BoolLiteral>,
     expected_int: Option<AHashMap<i64, usize>>,
     expected_str: Option<AHashMap<String, usize>>,
+    // Floats aren't hashable in Rust, literals rarely have many values so a linear search is fine
+    expected_float: Option<Vec<(f64, usize)>>,
     // Catch all for hashable types like Enum and bytes (the latter only because it is seldom used)
     expected_py_dict: Option<Py<PyDict>>,
     // Catch all for unhashable types like list
@@ -45,6 +47,7 @@ impl<T: Debug> LiteralLookup<T> {
         let mut expected_bool = BoolLiteral::default();
         let mut expected_int = AHashMap::new();
         let mut expected_str: AHashMap<String, usize> = AHashMap::new();
+        let mut expected_float: Vec<(f64, usize)> = Vec::new();
         let expected_py_dict = PyDict::new_bound(py);
         let expected_py_list = PyList::empty_bound(py);
         let mut values = Vec::new();
@@ -70,6 +73,8 @@ impl<T: Debug> LiteralLookup<T> {
                     .as_cow()
                     .map_err(|_| py_schema_error_type!("error extracting str {:?}", k))?;
                 expected_str.insert(str.to_string(), id);
+            } else if k.is_exact_instance_of::<PyFloat>() {
+                expected_float.push((k.extract()?, id));
             } else if expected_py_dict.set_item(&k, id).is_err() {
                 expected_py_list.append((&k, id))?;
             }
@@ -88,6 +93,10 @@ impl<T: Debug> LiteralLookup<T> {
                 true => None,
                 false => Some(expected_str),
             },
+            expected_float: match expected_float.is_empty() {
+                true => None,
+                false => Some(expected_float),
+            },
             expected_py_dict: match expected_py_dict.is_empty() {
                 true => None,
                 false => Some(expected_py_dict.into()),
@@ -143,6 +152,13 @@ impl<T: Debug> LiteralLookup<T> {
                 }
             }
         }
+        if let Some(expected_floats) = &self.expected_float {
+            if let Ok(either_float) = input.validate_float(true) {
+                if let Some(id) = find_float(expected_floats, either_float.into_inner().as_f64()) {
+                    return Ok(Some((input, &self.values[id])));
+                }
+            }
+        }
         if let Some(expected_py_dict) = &self.expected_py_dict {
             // We don't use ? to unpack the result of `get_item` in the next line because unhashable
             // inputs will produce a TypeError, which in this case we just want to treat equivalently
@@ -204,20 +220,34 @@ impl<T: Debug> LiteralLookup<T> {
         input: &'a I,
         strict: bool,
     ) -> ValResult<Option<&T>> {
+        let Ok(either_float) = input.validate_float(strict) else {
+            return Ok(None);
+        };
+        let f = either_float.into_inner().as_f64();
+        if let Some(expected_floats) = &self.expected_float {
+            if let Some(id) = find_float(expected_floats, f) {
+                return Ok(Some(&self.values[id]));
+            }
+        }
         if let Some(expected_py) = &self.expected_py_dict {
-            if let Ok(either_float) = input.validate_float(strict) {
-                let f = either_float.into_inner().as_f64();
-                let py_float = f.to_object(py);
-                if let Ok(Some(v)) = expected_py.bind(py).get_item(py_float.bind(py)) {
-                    let id: usize = v.extract().unwrap();
-                    return Ok(Some(&self.values[id]));
-                }
+            let py_float = f.to_object(py);
+            if let Ok(Some(v)) = expected_py.bind(py).get_item(py_float.bind(py)) {
+                let id: usize = v.extract().unwrap();
+                return Ok(Some(&self.values[id]));
             }
         }
         Ok(None)
     }
 }
 
+/// NaN literals match any NaN input, unlike IEEE-754 comparison
+fn find_float(expected: &[(f64, usize)], value: f64) -> Option<usize> {
+    expected
+        .iter()
+        .find(|(f, _)| *f == value || (f.is_nan() && value.is_nan()))
+        .map(|(_, id)| *id)
+}
+
 impl<T: PyGcTraverse + Debug> PyGcTraverse for LiteralLookup<T> {
     fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
         self.expected_py_dict.py_gc_traverse(visit)?;
@@ -1,17 +1,20 @@
+use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::sync::Mutex;
 
+use ahash::AHashMap;
 use enum_dispatch::enum_dispatch;
 use jiter::StringCacheMode;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyKeyError, PyTypeError};
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyAny, PyDict, PyString, PyTuple, PyType};
+use pyo3::types::{PyAny, PyDict, PyFrozenSet, PyList, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::{py_schema_err, py_schema_error_type, SchemaError};
 use crate::definitions::{Definitions, DefinitionsBuilder};
-use crate::errors::{LocItem, ValError, ValResult, ValidationError};
+use crate::errors::{ErrorTypeDefaults, LocItem, ValError, ValResult, ValidationError, ValidationWarning};
 use crate::input::{Input, InputType, StringMapping};
 use crate::py_gc::PyGcTraverse;
 use crate::recursion_guard::RecursionState;
@@ -30,8 +33,10 @@ mod date;
 mod datetime;
 pub(crate) mod decimal;
 mod definitions;
+mod deprecated;
 mod dict;
 mod enum_;
+mod external_ref;
 mod float;
 mod frozenset;
 mod function;
@@ -61,6 +66,7 @@ mod validation_state;
 mod with_default;
 
 pub use self::validation_state::{Exactness, ValidationState};
+pub use typed_dict::FieldInfo;
 pub use with_default::DefaultType;
 
 #[pyclass(module = "pydantic_core._pydantic_core", name = "Some")]
@@ -98,7 +104,8 @@ impl PySome {
     }
 }
 
-#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+// not frozen so that `rebuild` can replace the validator in place
+#[pyclass(module = "pydantic_core._pydantic_core")]
 #[derive(Debug)]
 pub struct SchemaValidator {
     validator: CombinedValidator,
@@ -112,8 +119,15 @@ pub struct SchemaValidator {
     hide_input_in_errors: bool,
     validation_error_cause: bool,
     cache_str: StringCacheMode,
+    strip_json_bom: bool,
+    collect_warnings: bool,
+    // validators returned by `from_schema_cached` are shared between callers, so can't be rebuilt
+    cached: bool,
 }
 
+/// The UTF-8 encoding of the byte order mark, `U+FEFF`
+const UTF8_BOM: &[u8] = b"\xef\xbb\xbf";
+
 #[pymethods]
 impl SchemaValidator {
     #[new]
@@ -131,15 +145,23 @@ impl SchemaValidator {
             Some(c) => c.get_item("title")?,
             None => None,
         };
-        let title = match config_title {
-            Some(t) => t.into_py(py),
-            None => validator.get_name().into_py(py),
+        // the title from config takes precedence, then the schema's own `title` key, then the validator's name
+        let schema_title = match schema.downcast::<PyDict>() {
+            Ok(schema_dict) => schema_dict.get_as::<Bound<'_, PyString>>(intern!(py, "title"))?,
+            Err(_) => None,
+        };
+        let title = match (config_title, schema_title) {
+            (Some(t), _) => t.into_py(py),
+            (None, Some(t)) => t.into_py(py),
+            (None, None) => validator.get_name().into_py(py),
         };
         let hide_input_in_errors: bool = config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false);
         let validation_error_cause: bool = config.get_as(intern!(py, "validation_error_cause"))?.unwrap_or(false);
         let cache_str: StringCacheMode = config
             .get_as(intern!(py, "cache_strings"))?
             .unwrap_or(StringCacheMode::All);
+        let strip_json_bom: bool = config.get_as(intern!(py, "strip_json_bom"))?.unwrap_or(false);
+        let collect_warnings: bool = config.get_as(intern!(py, "collect_warnings"))?.unwrap_or(false);
         Ok(Self {
             validator,
             definitions,
@@ -149,18 +171,80 @@ impl SchemaValidator {
             hide_input_in_errors,
             validation_error_cause,
             cache_str,
+            strip_json_bom,
+            collect_warnings,
+            cached: false,
         })
     }
 
+    /// Like `SchemaValidator(schema, config)`, but reuses the validator built by a previous call with the same
+    /// `schema` and `config` objects, compared by identity, as long as it's still in the cache
+    #[classmethod]
+    #[pyo3(signature = (schema, config=None))]
+    pub fn from_schema_cached(
+        _cls: &Bound<'_, PyType>,
+        py: Python,
+        schema: &Bound<'_, PyAny>,
+        config: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Py<Self>> {
+        if let Some(validator) = SCHEMA_CACHE.lock().unwrap().get(schema, config) {
+            return Ok(validator);
+        }
+        // the lock isn't held while building, since building may call arbitrary python code
+        let mut schema_validator = Self::py_new(py, schema, config)?;
+        schema_validator.cached = true;
+        let validator = Py::new(py, schema_validator)?;
+        let evicted = SCHEMA_CACHE.lock().unwrap().insert(CachedSchemaValidator {
+            schema: schema.clone().unbind(),
+            config: config.map(|c| c.clone().unbind()),
+            validator: validator.clone_ref(py),
+        });
+        drop(evicted);
+        Ok(validator)
+    }
+
+    /// Rebuild the validator from `schema`, or from the original schema if it's omitted, e.g. once forward
+    /// references used by the schema can be resolved, the config is kept
+    #[pyo3(signature = (schema=None))]
+    pub fn rebuild(&mut self, py: Python, schema: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        if self.cached {
+            return Err(PyTypeError::new_err(
+                "Validators created by `from_schema_cached` are shared, so can't be rebuilt",
+            ));
+        }
+        let schema = match schema {
+            Some(schema) => schema.clone(),
+            None => self.py_schema.bind(py).clone(),
+        };
+        let config = self.py_config.as_ref().map(|c| c.bind(py).clone());
+        *self = Self::py_new(py, &schema, config.as_ref())?;
+        Ok(())
+    }
+
+    /// The `ref`s of the definitions used by the schema, for debugging and introspection
+    pub fn definition_refs(&self) -> Vec<&str> {
+        self.definitions.names()
+    }
+
+    /// A debug representation of the validator built for the definition with the given `ref`
+    pub fn get_definition(&self, reference: &str) -> PyResult<String> {
+        match self.definitions.get(reference) {
+            Some(validator) => Ok(format!("{validator:#?}")),
+            None => Err(PyKeyError::new_err(reference.to_string())),
+        }
+    }
+
     pub fn __reduce__(slf: &Bound<Self>) -> PyResult<(PyObject, (PyObject, PyObject))> {
         // Enables support for `pickle` serialization.
         let py = slf.py();
         let cls = slf.get_type().into();
-        let init_args = (slf.get().py_schema.to_object(py), slf.get().py_config.to_object(py));
+        let this = slf.borrow();
+        let init_args = (this.py_schema.to_object(py), this.py_config.to_object(py));
         Ok((cls, init_args))
     }
 
-    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, include=None, exclude=None))]
     pub fn validate_python(
         &self,
         py: Python,
@@ -169,7 +253,11 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
+        let include = include.map(FieldFilter::from_py).transpose()?;
+        let exclude = exclude.map(FieldFilter::from_py).transpose()?;
         self._validate(
             py,
             input,
@@ -178,6 +266,8 @@ impl SchemaValidator {
             from_attributes,
             context,
             self_instance,
+            include.as_ref(),
+            exclude.as_ref(),
         )
         .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
     }
@@ -200,12 +290,15 @@ impl SchemaValidator {
             from_attributes,
             context,
             self_instance,
+            None,
+            None,
         ) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
             Err(ValError::Omit) => Err(ValidationError::omit_error()),
+            Err(ValError::DropField) => Err(ValidationError::drop_field_error()),
             Err(ValError::UseDefault) => Err(ValidationError::use_default_error()),
-            Err(ValError::LineErrors(_)) => Ok(false),
+            Err(ValError::LineErrors(_) | ValError::Undefined) => Ok(false),
         }
     }
 
@@ -219,14 +312,15 @@ impl SchemaValidator {
         self_instance: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
         let r = match json::validate_json_bytes(input) {
-            Ok(v_match) => self._validate_json(
-                py,
-                input,
-                v_match.into_inner().as_slice(),
-                strict,
-                context,
-                self_instance,
-            ),
+            Ok(v_match) => {
+                let json_bytes = v_match.into_inner();
+                let mut json_data = json_bytes.as_slice();
+                if self.strip_json_bom {
+                    // only a single leading BOM is removed, for `str` input this is the `\u{feff}` character
+                    json_data = json_data.strip_prefix(UTF8_BOM).unwrap_or(json_data);
+                }
+                self._validate_json(py, input, json_data, strict, context, self_instance)
+            }
             Err(err) => Err(err),
         };
         r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
@@ -243,14 +337,14 @@ impl SchemaValidator {
         let t = InputType::String;
         let string_mapping = StringMapping::new_value(input).map_err(|e| self.prepare_validation_err(py, e, t))?;
 
-        match self._validate(py, &string_mapping, t, strict, None, context, None) {
+        match self._validate(py, &string_mapping, t, strict, None, context, None, None, None) {
             Ok(r) => Ok(r),
             Err(e) => Err(self.prepare_validation_err(py, e, t)),
         }
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (obj, field_name, field_value, *, strict=None, from_attributes=None, context=None))]
+    #[pyo3(signature = (obj, field_name, field_value, *, strict=None, from_attributes=None, context=None, fields_set=None))]
     pub fn validate_assignment(
         &self,
         py: Python,
@@ -260,6 +354,7 @@ impl SchemaValidator {
         strict: Option<bool>,
         from_attributes: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
+        fields_set: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
         let extra = Extra {
             input_type: InputType::Python,
@@ -269,13 +364,29 @@ impl SchemaValidator {
             context,
             self_instance: None,
             cache_str: self.cache_str,
+            include: None,
+            exclude: None,
+            field_name: None,
         };
 
         let guard = &mut RecursionState::default();
         let mut state = ValidationState::new(extra, guard);
-        self.validator
+        let output = self
+            .validator
             .validate_assignment(py, &obj, field_name, &field_value, &mut state)
-            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))?;
+
+        match fields_set {
+            // when the caller passes the current fields set, we return it with the assigned field added
+            // so they can update `__fields_set__` in one step
+            Some(fields_set) => {
+                let mut new_fields_set: Vec<Bound<'_, PyAny>> = fields_set.iter()?.collect::<PyResult<_>>()?;
+                new_fields_set.push(PyString::new_bound(py, field_name).into_any());
+                let new_fields_set = PyFrozenSet::new_bound(py, &new_fields_set)?;
+                Ok((output, new_fields_set).to_object(py))
+            }
+            None => Ok(output),
+        }
     }
 
     #[pyo3(signature = (*, strict=None, context=None))]
@@ -293,6 +404,9 @@ impl SchemaValidator {
             context,
             self_instance: None,
             cache_str: self.cache_str,
+            include: None,
+            exclude: None,
+            field_name: None,
         };
         let recursion_guard = &mut RecursionState::default();
         let mut state = ValidationState::new(extra, recursion_guard);
@@ -306,6 +420,21 @@ impl SchemaValidator {
         }
     }
 
+    pub fn get_name(&self, py: Python) -> PyResult<String> {
+        self.title.extract(py)
+    }
+
+    pub fn get_fields(&self) -> Option<Vec<&str>> {
+        self.validator.field_names()
+    }
+
+    pub fn get_model_fields<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        match self.validator.unwrap_deprecated() {
+            CombinedValidator::TypedDict(validator) => validator.field_infos(py).map(Some),
+            _ => Ok(None),
+        }
+    }
+
     pub fn __repr__(&self, py: Python) -> String {
         format!(
             "SchemaValidator(title={:?}, validator={:#?}, definitions={:#?}, cache_strings={})",
@@ -341,20 +470,32 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&Bound<'py, PyAny>>,
         self_instance: Option<&Bound<'py, PyAny>>,
+        include: Option<&FieldFilter>,
+        exclude: Option<&FieldFilter>,
     ) -> ValResult<PyObject> {
         let mut recursion_guard = RecursionState::default();
-        let mut state = ValidationState::new(
-            Extra::new(
-                strict,
-                from_attributes,
-                context,
-                self_instance,
-                input_type,
-                self.cache_str,
-            ),
-            &mut recursion_guard,
+        let mut extra = Extra::new(
+            strict,
+            from_attributes,
+            context,
+            self_instance,
+            input_type,
+            self.cache_str,
         );
-        self.validator.validate(py, input, &mut state)
+        extra.include = include;
+        extra.exclude = exclude;
+        let mut state = ValidationState::new(extra, &mut recursion_guard);
+        if self.collect_warnings {
+            state.warnings = Some(Vec::new());
+        }
+        let output = match self.validator.validate(py, input, &mut state) {
+            Err(ValError::Undefined) => Err(ValError::new(ErrorTypeDefaults::Missing, input)),
+            result => result,
+        }?;
+        match state.warnings {
+            Some(warnings) => Ok(self.prepare_warnings(py, output, warnings)?),
+            None => Ok(output),
+        }
     }
 
     fn _validate_json(
@@ -368,7 +509,17 @@ impl SchemaValidator {
     ) -> ValResult<PyObject> {
         let json_value =
             jiter::JsonValue::parse(json_data, true).map_err(|e| json::map_json_err(input, e, json_data))?;
-        self._validate(py, &json_value, InputType::Json, strict, None, context, self_instance)
+        self._validate(
+            py,
+            &json_value,
+            InputType::Json,
+            strict,
+            None,
+            context,
+            self_instance,
+            None,
+            None,
+        )
     }
 
     fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
@@ -382,6 +533,80 @@ impl SchemaValidator {
             self.validation_error_cause,
         )
     }
+
+    /// Pair the validated value with the warnings collected while validating it, as `(value, warnings)`
+    fn prepare_warnings(&self, py: Python, output: PyObject, warnings: Vec<ValidationWarning>) -> PyResult<PyObject> {
+        let warnings = warnings
+            .into_iter()
+            .map(|warning| Py::new(py, warning))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok((output, PyList::new_bound(py, warnings)).into_py(py))
+    }
+}
+
+#[derive(Debug)]
+struct CachedSchemaValidator {
+    schema: PyObject,
+    config: Option<Py<PyDict>>,
+    validator: Py<SchemaValidator>,
+}
+
+/// Least recently used cache of validators built by `SchemaValidator.from_schema_cached`, entries hold
+/// references to their schema and config so the objects' identities can't be reused while cached
+#[derive(Debug)]
+struct SchemaValidatorCache {
+    max_size: usize,
+    // most recently used first
+    entries: VecDeque<CachedSchemaValidator>,
+}
+
+impl SchemaValidatorCache {
+    const DEFAULT_MAX_SIZE: usize = 128;
+
+    fn get(&mut self, schema: &Bound<'_, PyAny>, config: Option<&Bound<'_, PyDict>>) -> Option<Py<SchemaValidator>> {
+        let py = schema.py();
+        let index = self.entries.iter().position(|entry| {
+            entry.schema.is(schema)
+                && match (&entry.config, config) {
+                    (Some(cached), Some(config)) => cached.is(config),
+                    (None, None) => true,
+                    _ => false,
+                }
+        })?;
+        let entry = self.entries.remove(index)?;
+        let validator = entry.validator.clone_ref(py);
+        self.entries.push_front(entry);
+        Some(validator)
+    }
+
+    /// Add an entry, returning any evicted entries so they can be dropped after the lock is released
+    fn insert(&mut self, entry: CachedSchemaValidator) -> Vec<CachedSchemaValidator> {
+        if self.max_size > 0 {
+            self.entries.push_front(entry);
+        }
+        self.shrink()
+    }
+
+    fn shrink(&mut self) -> Vec<CachedSchemaValidator> {
+        let keep = self.entries.len().min(self.max_size);
+        self.entries.split_off(keep).into()
+    }
+}
+
+static SCHEMA_CACHE: Mutex<SchemaValidatorCache> = Mutex::new(SchemaValidatorCache {
+    max_size: SchemaValidatorCache::DEFAULT_MAX_SIZE,
+    entries: VecDeque::new(),
+});
+
+/// Set the maximum number of validators kept by `SchemaValidator.from_schema_cached`, 0 disables the cache
+#[pyfunction]
+pub fn set_schema_cache_size(size: usize) {
+    let evicted = {
+        let mut cache = SCHEMA_CACHE.lock().unwrap();
+        cache.max_size = size;
+        cache.shrink()
+    };
+    drop(evicted);
 }
 
 static SCHEMA_DEFINITION: GILOnceCell<SchemaValidator> = GILOnceCell::new();
@@ -435,6 +660,9 @@ impl<'py> SelfValidator<'py> {
             hide_input_in_errors: false,
             validation_error_cause: false,
             cache_str: true.into(),
+            strip_json_bom: false,
+            collect_warnings: false,
+            cached: false,
         })
     }
 }
@@ -488,7 +716,13 @@ pub fn build_validator(
     let dict = schema.downcast::<PyDict>()?;
     let type_: Bound<'_, PyString> = dict.get_as_req(intern!(schema.py(), "type"))?;
     let type_ = type_.to_str()?;
-    validator_match!(
+    // an `any` schema with a `schema` key is just a wrapper, e.g. to attach metadata, validation is delegated
+    if type_ == "any" {
+        if let Some(inner_schema) = dict.get_as::<Bound<'_, PyAny>>(intern!(schema.py(), "schema"))? {
+            return build_validator(&inner_schema, config, definitions);
+        }
+    }
+    let validator = validator_match!(
         type_,
         dict,
         config,
@@ -579,7 +813,10 @@ pub fn build_validator(
         // recursive (self-referencing) models
         definitions::DefinitionRefValidator,
         definitions::DefinitionsValidatorBuilder,
-    )
+        // references to other schema validators
+        external_ref::ExternalRefValidator,
+    )?;
+    deprecated::DeprecatedValidator::wrap(dict, validator)
 }
 
 /// More (mostly immutable) data to pass between validators, should probably be class `Context`,
@@ -600,6 +837,12 @@ pub struct Extra<'a, 'py> {
     self_instance: Option<&'a Bound<'py, PyAny>>,
     /// Whether to use a cache of short strings to accelerate python string construction
     cache_str: StringCacheMode,
+    /// Fields to validate for partial validation, other fields are skipped
+    pub include: Option<&'a FieldFilter>,
+    /// Fields to skip for partial validation
+    pub exclude: Option<&'a FieldFilter>,
+    /// Name of the typed dict field being validated, used as `info.field_name` in validator functions
+    pub field_name: Option<Bound<'py, PyString>>,
 }
 
 impl<'a, 'py> Extra<'a, 'py> {
@@ -619,6 +862,9 @@ impl<'a, 'py> Extra<'a, 'py> {
             context,
             self_instance,
             cache_str,
+            include: None,
+            exclude: None,
+            field_name: None,
         }
     }
 }
@@ -633,8 +879,79 @@ impl Extra<'_, '_> {
             context: self.context,
             self_instance: self.self_instance,
             cache_str: self.cache_str,
+            include: self.include,
+            exclude: self.exclude,
+            field_name: self.field_name.clone(),
+        }
+    }
+
+    /// Whether partial validation with `include` or `exclude` is in use
+    pub fn has_field_filter(&self) -> bool {
+        self.include.is_some() || self.exclude.is_some()
+    }
+
+    /// Whether partial validation should skip this field entirely
+    pub fn skips_field(&self, field: &str) -> bool {
+        self.include.is_some_and(|include| !include.contains(field))
+            || self.exclude.is_some_and(|exclude| exclude.contains_whole(field))
+    }
+
+    /// Narrow `include` and `exclude` to the nested fields of `field`, for validating its value
+    pub fn enter_field(&mut self, field: &str) {
+        self.include = self.include.and_then(|include| include.nested(field));
+        self.exclude = self.exclude.and_then(|exclude| exclude.nested(field));
+    }
+}
+
+/// A set of field names used for partial validation, nested fields are given as dotted paths,
+/// e.g. `{'a', 'b.c'}` selects all of `a`, but only `c` within `b`
+#[derive(Debug, Default)]
+pub struct FieldFilter {
+    // an empty filter for a field means the whole field is selected
+    fields: AHashMap<String, FieldFilter>,
+}
+
+impl FieldFilter {
+    fn from_py(fields: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let mut filter = Self::default();
+        for field in fields.iter()? {
+            let field = field?;
+            let path = field.downcast::<PyString>()?.to_str()?;
+            filter.insert(path);
+        }
+        Ok(filter)
+    }
+
+    fn insert(&mut self, path: &str) {
+        match path.split_once('.') {
+            Some((field, rest)) => {
+                // if the whole field is already selected, selecting part of it changes nothing
+                let is_new = !self.fields.contains_key(field);
+                let nested = self.fields.entry(field.to_string()).or_default();
+                if is_new || !nested.fields.is_empty() {
+                    nested.insert(rest);
+                }
+            }
+            None => {
+                self.fields.insert(path.to_string(), Self::default());
+            }
         }
     }
+
+    /// Whether any part of the field is selected
+    pub fn contains(&self, field: &str) -> bool {
+        self.fields.contains_key(field)
+    }
+
+    /// Whether the field is selected as a whole, rather than just some of its nested fields
+    pub fn contains_whole(&self, field: &str) -> bool {
+        self.fields.get(field).is_some_and(|nested| nested.fields.is_empty())
+    }
+
+    /// The filter to apply to the field's value, `None` if the whole field is selected or it's not selected at all
+    pub fn nested(&self, field: &str) -> Option<&Self> {
+        self.fields.get(field).filter(|nested| !nested.fields.is_empty())
+    }
 }
 
 #[derive(Debug)]
@@ -730,10 +1047,25 @@ pub enum CombinedValidator {
     Uuid(uuid::UuidValidator),
     // reference to definition, useful for recursive (self-referencing) models
     DefinitionRef(definitions::DefinitionRefValidator),
+    // reference to another schema validator
+    ExternalRef(external_ref::ExternalRefValidator),
+    // any schema with a `deprecated` key
+    Deprecated(deprecated::DeprecatedValidator),
     // input dependent
     JsonOrPython(json_or_python::JsonOrPython),
 }
 
+impl CombinedValidator {
+    /// The validator wrapped by a `deprecated` schema, otherwise `self`, for code which needs to know what kind of
+    /// validator a schema built, e.g. whether it's a `WithDefault`
+    pub fn unwrap_deprecated(&self) -> &CombinedValidator {
+        match self {
+            Self::Deprecated(validator) => validator.inner(),
+            _ => self,
+        }
+    }
+}
+
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
 /// validators defined in `build_validator` also need `EXPECTED_TYPE` as a const, but that can't be part of the trait
 #[enum_dispatch(CombinedValidator)]
@@ -770,6 +1102,12 @@ pub trait Validator: Send + Sync + Debug {
         Err(py_err.into())
     }
 
+    /// Names of the fields this validator produces, used for reflection via `SchemaValidator.get_fields`,
+    /// `None` for validators which don't have fields
+    fn field_names(&self) -> Option<Vec<&str>> {
+        None
+    }
+
     /// `get_name` generally returns `Self::EXPECTED_TYPE` or some other clear identifier of the validator
     /// this is used in the error location in unions, and in the top level message in `ValidationError`
     fn get_name(&self) -> &str;
@@ -1,9 +1,10 @@
 use std::ptr::null_mut;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use pyo3::exceptions::PyTypeError;
 use pyo3::ffi;
-use pyo3::types::{PyDict, PySet, PyString, PyTuple, PyType};
-use pyo3::{intern, prelude::*};
+use pyo3::types::{PyDict, PyFrozenSet, PyList, PySequence, PySet, PyString, PyTuple, PyType};
+use pyo3::{intern, prelude::*, PyTraverseError, PyVisit};
 
 use super::function::convert_err;
 use super::validation_state::Exactness;
@@ -22,6 +23,8 @@ const DUNDER_DICT: &str = "__dict__";
 const DUNDER_FIELDS_SET_KEY: &str = "__pydantic_fields_set__";
 const DUNDER_MODEL_EXTRA_KEY: &str = "__pydantic_extra__";
 const DUNDER_MODEL_PRIVATE_KEY: &str = "__pydantic_private__";
+const DUNDER_VALIDATOR_KEY: &str = "__pydantic_validator__";
+const DUNDER_INIT_SUBCLASS_CALLED_KEY: &str = "__pydantic_init_subclass_called__";
 
 #[derive(Debug, Clone)]
 pub(super) enum Revalidate {
@@ -50,15 +53,39 @@ impl Revalidate {
     }
 }
 
+/// `copy.copy` or `copy.deepcopy` from `copy_on_model_validation`, `None` means instances are returned as-is
+fn get_copy_function(copy_on_model_validation: Option<&str>, py: Python) -> PyResult<Option<PyObject>> {
+    let function_name = match copy_on_model_validation {
+        None | Some("none") => return Ok(None),
+        Some("shallow") => "copy",
+        Some("deep") => "deepcopy",
+        Some(s) => return py_schema_err!("Invalid copy_on_model_validation value: {}", s),
+    };
+    Ok(Some(
+        py.import_bound(intern!(py, "copy"))?.getattr(function_name)?.unbind(),
+    ))
+}
+
 #[derive(Debug)]
 pub struct ModelValidator {
     revalidate: Revalidate,
+    // copies instances which aren't revalidated, from `copy_on_model_validation`
+    copy_instance: Option<PyObject>,
     validator: Box<CombinedValidator>,
     class: Py<PyType>,
+    // arguments passed to the class's `__new__` when creating an instance, e.g. for subclasses of `str`
+    class_new_args: Option<Py<PyTuple>>,
     post_init: Option<Py<PyString>>,
+    // keyword arguments from the class definition, passed to the class's `__pydantic_init_subclass__` hook
+    // once the first instance of the class has been set up
+    class_kwargs: Option<Py<PyDict>>,
+    // avoids checking the class for `DUNDER_INIT_SUBCLASS_CALLED_KEY` on every validation
+    init_subclass_called: AtomicBool,
     frozen: bool,
     custom_init: bool,
     root_model: bool,
+    // from the model's config, errors raised while validating the model don't include their input
+    hide_input_in_errors: bool,
     undefined: PyObject,
     name: String,
 }
@@ -80,7 +107,7 @@ impl BuildValidator for ModelValidator {
         let validator = build_validator(&sub_schema, config.as_ref(), definitions)?;
         let name = class.getattr(intern!(py, "__name__"))?.extract()?;
 
-        Ok(Self {
+        let model_validator = Self {
             revalidate: Revalidate::from_str(
                 schema_or_config_same::<Bound<'_, PyString>>(
                     schema,
@@ -91,21 +118,76 @@ impl BuildValidator for ModelValidator {
                 .map(|s| s.to_str())
                 .transpose()?,
             )?,
+            copy_instance: get_copy_function(
+                schema_or_config_same::<Bound<'_, PyString>>(
+                    schema,
+                    config.as_ref(),
+                    intern!(py, "copy_on_model_validation"),
+                )?
+                .as_ref()
+                .map(|s| s.to_str())
+                .transpose()?,
+                py,
+            )?,
             validator: Box::new(validator),
-            class: class.into(),
+            class: class.clone().unbind(),
+            class_new_args: schema
+                .get_as::<Bound<'_, PySequence>>(intern!(py, "class_new_args"))?
+                .map(|args| args.to_tuple().map(Bound::unbind))
+                .transpose()?,
             post_init: schema.get_as(intern!(py, "post_init"))?,
+            class_kwargs: schema.get_as(intern!(py, "class_kwargs"))?,
+            init_subclass_called: AtomicBool::new(false),
             frozen: schema.get_as(intern!(py, "frozen"))?.unwrap_or(false),
             custom_init: schema.get_as(intern!(py, "custom_init"))?.unwrap_or(false),
             root_model: schema.get_as(intern!(py, "root_model"))?.unwrap_or(false),
+            hide_input_in_errors: config
+                .as_ref()
+                .get_as(intern!(py, "hide_input_in_errors"))?
+                .unwrap_or(false),
             undefined: PydanticUndefinedType::new(py).to_object(py),
             // Get the class's `__name__`, not using `class.qualname()`
             name,
+        };
+
+        // a `__setattr__` defined on the class itself is left alone
+        if config
+            .as_ref()
+            .get_as(intern!(py, "validate_assignment"))?
+            .unwrap_or(false)
+            && !class
+                .getattr(intern!(py, DUNDER_DICT))?
+                .contains(intern!(py, "__setattr__"))?
+        {
+            let fields = model_validator
+                .validator
+                .field_names()
+                .map(|names| PyFrozenSet::new_bound(py, &names).map(Bound::unbind))
+                .transpose()?;
+            class.setattr(
+                intern!(py, "__setattr__"),
+                ValidateAssignmentSetattr { fields, instance: None }.into_py(py),
+            )?;
         }
-        .into())
+
+        if config.as_ref().get_as(intern!(py, "get_validators"))?.unwrap_or(false) {
+            class.setattr(
+                intern!(py, "__get_validators__"),
+                GetValidatorsClassmethod { class: None }.into_py(py),
+            )?;
+        }
+
+        Ok(model_validator.into())
     }
 }
 
-impl_py_gc_traverse!(ModelValidator { class, validator });
+impl_py_gc_traverse!(ModelValidator {
+    copy_instance,
+    class,
+    class_new_args,
+    class_kwargs,
+    validator
+});
 
 impl Validator for ModelValidator {
     fn validate<'py>(
@@ -113,6 +195,34 @@ impl Validator for ModelValidator {
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let result = self.validate_model(py, input, state);
+        self.hide_input(py, result)
+    }
+
+    fn validate_assignment<'py>(
+        &self,
+        py: Python<'py>,
+        model: &Bound<'py, PyAny>,
+        field_name: &str,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let result = self.validate_model_assignment(py, model, field_name, field_value, state);
+        self.hide_input(py, result)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl ModelValidator {
+    fn validate_model<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         if let Some(self_instance) = state.extra().self_instance {
             // in the case that self_instance is Some, we're calling validation from within `BaseModel.__init__`
@@ -144,6 +254,8 @@ impl Validator for ModelValidator {
                     };
                     self.validate_construct(py, &inner_input, Some(&fields_set), state)
                 }
+            } else if let Some(ref copy_instance) = self.copy_instance {
+                Ok(copy_instance.call1(py, (py_input,))?)
             } else {
                 Ok(input.to_object(py))
             }
@@ -154,7 +266,7 @@ impl Validator for ModelValidator {
         }
     }
 
-    fn validate_assignment<'py>(
+    fn validate_model_assignment<'py>(
         &self,
         py: Python<'py>,
         model: &Bound<'py, PyAny>,
@@ -216,12 +328,19 @@ impl Validator for ModelValidator {
         Ok(model.into_py(py))
     }
 
-    fn get_name(&self) -> &str {
-        &self.name
+    /// with `hide_input_in_errors` set in the model's config, the input is dropped from all errors
+    fn hide_input(&self, py: Python, result: ValResult<PyObject>) -> ValResult<PyObject> {
+        match result {
+            Err(ValError::LineErrors(line_errors)) if self.hide_input_in_errors => Err(ValError::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|line_error| line_error.with_hidden_input(py))
+                    .collect(),
+            )),
+            result => result,
+        }
     }
-}
 
-impl ModelValidator {
     /// here we just call the inner validator, then set attributes on `self_instance`
     fn validate_init<'py>(
         &self,
@@ -247,6 +366,7 @@ impl ModelValidator {
             let (model_dict, model_extra, fields_set) = output.extract(py)?;
             set_model_attrs(self_instance, &model_dict, &model_extra, &fields_set)?;
         }
+        self.call_init_subclass(py)?;
         self.call_post_init(py, self_instance.clone(), input, state.extra())
     }
 
@@ -273,7 +393,10 @@ impl ModelValidator {
 
         let output = self.validator.validate(py, input, state)?;
 
-        let instance = create_class(self.class.bind(py))?;
+        let instance = match self.class_new_args {
+            Some(ref args) => create_class_with_args(self.class.bind(py), args.bind(py))?,
+            None => create_class(self.class.bind(py))?,
+        };
 
         if self.root_model {
             let fields_set = if input.to_object(py).is(&self.undefined) {
@@ -288,9 +411,33 @@ impl ModelValidator {
             let fields_set = existing_fields_set.unwrap_or(&val_fields_set);
             set_model_attrs(&instance, &model_dict, &model_extra, fields_set)?;
         }
+        self.call_init_subclass(py)?;
         self.call_post_init(py, instance, input, state.extra())
     }
 
+    /// Pass `class_kwargs` to the class's `__pydantic_init_subclass__` hook, if it has one, the first time an
+    /// instance of the class is constructed by any validator; if the hook raises, it's called again next time
+    fn call_init_subclass(&self, py: Python) -> PyResult<()> {
+        let Some(ref class_kwargs) = self.class_kwargs else {
+            return Ok(());
+        };
+        if self.init_subclass_called.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let class = self.class.bind(py);
+        let marker = intern!(py, DUNDER_INIT_SUBCLASS_CALLED_KEY);
+        // the class's own `__dict__` is checked, since the marker is inherited but subclasses need their own call
+        if !class.getattr(intern!(py, DUNDER_DICT))?.contains(marker)? {
+            let hook = intern!(py, "__pydantic_init_subclass__");
+            if class.hasattr(hook)? {
+                class.call_method(hook, (), Some(class_kwargs.bind(py)))?;
+            }
+            class.setattr(marker, true)?;
+        }
+        self.init_subclass_called.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
     fn call_post_init<'py>(
         &self,
         py: Python<'py>,
@@ -307,11 +454,120 @@ impl ModelValidator {
     }
 }
 
+/// `__setattr__` set on model classes with `validate_assignment` in their config, assignments to fields are validated
+/// by the class's `__pydantic_validator__` which is only created once the class's validator has been built,
+/// private (`_`-prefixed) and other non-field attributes are set as usual
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+struct ValidateAssignmentSetattr {
+    // `None` if the field names aren't known, in which case all public attributes are validated
+    fields: Option<Py<PyFrozenSet>>,
+    instance: Option<PyObject>,
+}
+
+#[pymethods]
+impl ValidateAssignmentSetattr {
+    /// Bind to the instance when accessed as an attribute, like a function defined on the class would
+    fn __get__(
+        slf: &Bound<'_, Self>,
+        instance: Option<&Bound<'_, PyAny>>,
+        _owner: Option<&Bound<'_, PyAny>>,
+    ) -> PyObject {
+        let py = slf.py();
+        match instance {
+            Some(instance) => Self {
+                fields: slf.get().fields.as_ref().map(|fields| fields.clone_ref(py)),
+                instance: Some(instance.clone().unbind()),
+            }
+            .into_py(py),
+            None => slf.clone().into_py(py),
+        }
+    }
+
+    fn __call__(&self, py: Python, name: &Bound<'_, PyString>, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let Some(ref instance) = self.instance else {
+            return py_err!(PyTypeError; "validate_assignment `__setattr__` must be called on an instance");
+        };
+        let instance = instance.bind(py);
+        let is_field = match self.fields {
+            Some(ref fields) => fields.bind(py).contains(name)?,
+            None => true,
+        };
+        if !is_field || name.to_str()?.starts_with('_') {
+            return force_setattr(py, instance, name, value);
+        }
+        instance
+            .get_type()
+            .getattr(intern!(py, DUNDER_VALIDATOR_KEY))?
+            .call_method1(intern!(py, "validate_assignment"), (instance, name, value))?;
+        Ok(())
+    }
+
+    fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
+        if let Some(ref fields) = self.fields {
+            visit.call(fields)?;
+        }
+        if let Some(ref instance) = self.instance {
+            visit.call(instance)?;
+        }
+        Ok(())
+    }
+}
+
+/// Pydantic V1 style `__get_validators__` classmethod set on model classes with `get_validators` in their config,
+/// returns the `validate_python` method of the class's `__pydantic_validator__`
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+struct GetValidatorsClassmethod {
+    class: Option<PyObject>,
+}
+
+#[pymethods]
+impl GetValidatorsClassmethod {
+    /// Bind to the class, like `classmethod` does, whether accessed on the class or an instance
+    fn __get__(
+        slf: &Bound<'_, Self>,
+        instance: Option<&Bound<'_, PyAny>>,
+        owner: Option<&Bound<'_, PyAny>>,
+    ) -> PyObject {
+        let py = slf.py();
+        match owner.cloned().or_else(|| instance.map(|i| i.get_type().into_any())) {
+            Some(class) => Self {
+                class: Some(class.unbind()),
+            }
+            .into_py(py),
+            None => slf.clone().into_py(py),
+        }
+    }
+
+    fn __call__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let Some(ref class) = self.class else {
+            return py_err!(PyTypeError; "`__get_validators__` must be called on a class");
+        };
+        let validate_python = class
+            .bind(py)
+            .getattr(intern!(py, DUNDER_VALIDATOR_KEY))?
+            .getattr(intern!(py, "validate_python"))?;
+        Ok(PyList::new_bound(py, [validate_python]))
+    }
+
+    fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
+        if let Some(ref class) = self.class {
+            visit.call(class)?;
+        }
+        Ok(())
+    }
+}
+
 /// based on the following but with the second argument of new_func set to an empty tuple as required
 /// https://github.com/PyO3/pyo3/blob/d2caa056e9aacc46374139ef491d112cb8af1a25/src/pyclass_init.rs#L35-L77
 pub(super) fn create_class<'py>(class: &Bound<'py, PyType>) -> PyResult<Bound<'py, PyAny>> {
+    create_class_with_args(class, &PyTuple::empty_bound(class.py()))
+}
+
+/// as `create_class`, but passing `args` to `tp_new`, for classes whose `__new__` requires arguments
+fn create_class_with_args<'py>(class: &Bound<'py, PyType>, args: &Bound<'py, PyTuple>) -> PyResult<Bound<'py, PyAny>> {
     let py = class.py();
-    let args = PyTuple::empty_bound(py);
     let raw_type = class.as_type_ptr();
     unsafe {
         // Safety: raw_type is known to be a non-null type object pointer
@@ -35,6 +35,10 @@ pub struct ModelFieldsValidator {
     extras_validator: Option<Box<CombinedValidator>>,
     strict: bool,
     from_attributes: bool,
+    // whether fields which were set to their default are included in the returned fields set
+    fields_set_include_defaults: bool,
+    // whether the fields set of a field's value, e.g. a nested model, is included in dotted form, e.g. `'a.b'`
+    nested_fields_set: bool,
     loc_by_alias: bool,
 }
 
@@ -102,6 +106,10 @@ impl BuildValidator for ModelFieldsValidator {
             extras_validator,
             strict,
             from_attributes,
+            fields_set_include_defaults: schema
+                .get_as(intern!(py, "fields_set_include_defaults"))?
+                .unwrap_or(false),
+            nested_fields_set: schema.get_as(intern!(py, "nested_fields_set"))?.unwrap_or(false),
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
         }
         .into())
@@ -164,6 +172,7 @@ impl Validator for ModelFieldsValidator {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(model_dict.clone()));
 
             for field in &self.fields {
+                let skip_field = state.extra().skips_field(&field.name);
                 let op_key_value = match dict.get_item(&field.lookup_key) {
                     Ok(v) => v,
                     Err(ValError::LineErrors(line_errors)) => {
@@ -180,12 +189,33 @@ impl Validator for ModelFieldsValidator {
                         // extra logic either way
                         used_keys.insert(lookup_path.first_key());
                     }
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    if skip_field {
+                        continue;
+                    }
+                    let result = if state.extra().has_field_filter() {
+                        let state = &mut state.rebind_extra(|extra| extra.enter_field(&field.name));
+                        field.validator.validate(py, value.borrow_input(), state)
+                    } else {
+                        field.validator.validate(py, value.borrow_input(), state)
+                    };
+                    match result {
                         Ok(value) => {
+                            if self.nested_fields_set {
+                                push_nested_fields_set(&mut fields_set_vec, &field.name, value.bind(py))?;
+                            }
                             model_dict.set_item(&field.name_py, value)?;
                             fields_set_vec.push(field.name_py.clone_ref(py));
                         }
-                        Err(ValError::Omit) => continue,
+                        Err(ValError::Omit | ValError::DropField) => continue,
+                        // the default value was requested, but the field doesn't have one
+                        Err(ValError::UseDefault | ValError::Undefined) => {
+                            errors.push(field.lookup_key.error(
+                                ErrorTypeDefaults::Missing,
+                                input,
+                                self.loc_by_alias,
+                                &field.name,
+                            ));
+                        }
                         Err(ValError::LineErrors(line_errors)) => {
                             for err in line_errors {
                                 errors.push(lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name));
@@ -196,10 +226,18 @@ impl Validator for ModelFieldsValidator {
                     continue;
                 }
 
+                if skip_field {
+                    // partial validation, the field isn't required and its default isn't used
+                    continue;
+                }
+
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
                     Ok(Some(value)) => {
                         // Default value exists, and passed validation if required
                         model_dict.set_item(&field.name_py, value)?;
+                        if self.fields_set_include_defaults {
+                            fields_set_vec.push(field.name_py.clone_ref(py));
+                        }
                     }
                     Ok(None) => {
                         // This means there was no default value
@@ -210,7 +248,7 @@ impl Validator for ModelFieldsValidator {
                             &field.name,
                         ));
                     }
-                    Err(ValError::Omit) => continue,
+                    Err(ValError::Omit | ValError::DropField) => continue,
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
                             // Note: this will always use the field name even if there is an alias
@@ -432,7 +470,32 @@ impl Validator for ModelFieldsValidator {
         Ok((new_data.to_object(py), new_extra, fields_set.to_object(py)).to_object(py))
     }
 
+    fn field_names(&self) -> Option<Vec<&str>> {
+        Some(self.fields.iter().map(|field| field.name.as_str()).collect())
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 }
+
+/// Add the names in `value.__pydantic_fields_set__`, if it's set, to `fields_set_vec` prefixed with `field_name`,
+/// e.g. `'address.city'`, names which are already dotted are nested further
+fn push_nested_fields_set(
+    fields_set_vec: &mut Vec<Py<PyString>>,
+    field_name: &str,
+    value: &Bound<'_, PyAny>,
+) -> PyResult<()> {
+    let py = value.py();
+    let Ok(nested_fields_set) = value.getattr(intern!(py, "__pydantic_fields_set__")) else {
+        return Ok(());
+    };
+    let Ok(nested_fields_set) = nested_fields_set.downcast_into::<PySet>() else {
+        return Ok(());
+    };
+    for nested_field in nested_fields_set {
+        let nested_field = nested_field.downcast_into::<PyString>()?;
+        fields_set_vec.push(PyString::new_bound(py, &format!("{field_name}.{}", nested_field.to_str()?)).unbind());
+    }
+    Ok(())
+}
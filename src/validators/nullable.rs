@@ -1,6 +1,6 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyFloat, PyList};
 
 use crate::errors::ValResult;
 use crate::input::Input;
@@ -12,6 +12,10 @@ use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuild
 #[derive(Debug)]
 pub struct NullableValidator {
     validator: Box<CombinedValidator>,
+    // other objects, besides `None`, which are treated as null
+    none_types: Vec<PyObject>,
+    // returned for null inputs, `None` unless set
+    null_value: PyObject,
     name: String,
 }
 
@@ -23,14 +27,30 @@ impl BuildValidator for NullableValidator {
         config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        let py = schema.py();
+        let none_types = match schema.get_as::<Bound<'_, PyList>>(intern!(py, "none_types"))? {
+            Some(none_types) => none_types.iter().map(Into::into).collect(),
+            None => Vec::new(),
+        };
+        let null_value = schema.get_as(intern!(py, "null_value"))?.unwrap_or_else(|| py.None());
+        let schema = schema.get_as_req(intern!(py, "schema"))?;
         let validator = Box::new(build_validator(&schema, config, definitions)?);
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
-        Ok(Self { validator, name }.into())
+        Ok(Self {
+            validator,
+            none_types,
+            null_value,
+            name,
+        }
+        .into())
     }
 }
 
-impl_py_gc_traverse!(NullableValidator { validator });
+impl_py_gc_traverse!(NullableValidator {
+    validator,
+    none_types,
+    null_value
+});
 
 impl Validator for NullableValidator {
     fn validate<'py>(
@@ -39,8 +59,8 @@ impl Validator for NullableValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        match input.is_none() {
-            true => Ok(py.None()),
+        match input.is_none() || self.is_none_type(py, input)? {
+            true => Ok(self.null_value.clone_ref(py)),
             false => self.validator.validate(py, input, state),
         }
     }
@@ -49,3 +69,33 @@ impl Validator for NullableValidator {
         &self.name
     }
 }
+
+impl NullableValidator {
+    fn is_none_type<'py>(&self, py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> PyResult<bool> {
+        if self.none_types.is_empty() {
+            return Ok(false);
+        }
+        let input = input.to_object(py).into_bound(py);
+        for none_type in &self.none_types {
+            let none_type = none_type.bind(py);
+            if input.is(none_type) {
+                return Ok(true);
+            }
+            // the types must match too, so e.g. `False` isn't treated as null when `0` is
+            if !input.get_type().is(&none_type.get_type()) {
+                continue;
+            }
+            // NaN isn't equal to itself, so any NaN matches
+            if let (Ok(input_float), Ok(none_float)) = (input.downcast::<PyFloat>(), none_type.downcast::<PyFloat>()) {
+                if input_float.value().is_nan() && none_float.value().is_nan() {
+                    return Ok(true);
+                }
+            }
+            // an error from `__eq__` or `__bool__`, e.g. for numpy arrays, means it's not a match
+            if input.eq(none_type).unwrap_or(false) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
@@ -5,7 +5,7 @@ use crate::errors::ValResult;
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
-use super::list::min_length_check;
+use super::list::{exact_length_check, min_length_check};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -14,6 +14,7 @@ pub struct SetValidator {
     item_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    exact_length: Option<usize>,
     name: String,
 }
 
@@ -41,6 +42,7 @@ macro_rules! set_build {
                 item_validator,
                 min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
                 max_length,
+                exact_length: schema.get_as(pyo3::intern!(py, "exact_length"))?,
                 name,
             }
             .into())
@@ -73,6 +75,7 @@ impl Validator for SetValidator {
             item_validator: &self.item_validator,
             state,
         })??;
+        exact_length_check!(input, "Set", self.exact_length, set);
         min_length_check!(input, "Set", self.min_length, set);
         Ok(set.into_py(py))
     }
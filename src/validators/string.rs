@@ -1,11 +1,14 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use regex::Regex;
 
-use crate::build_tools::{is_strict, py_schema_error_type, schema_or_config, schema_or_config_same};
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::build_tools::{is_strict, py_schema_err, py_schema_error_type, schema_or_config, schema_or_config_same};
+use crate::errors::{ErrorType, ErrorTypeDefaults, StringOrList, ValError, ValResult};
+use crate::input::{Input, InputType};
 use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -61,13 +64,26 @@ impl Validator for StrValidator {
 #[derive(Debug, Clone, Default)]
 pub struct StrConstrainedValidator {
     strict: bool,
+    // in strict mode, reject subclasses of `str`
+    strict_subclass_check: bool,
     pattern: Option<Pattern>,
+    patterns: Vec<Pattern>,
+    not_pattern: Option<Pattern>,
+    not_patterns: Vec<Pattern>,
+    format: Option<StrFormat>,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    max_codepoints: Option<usize>,
+    json_max_length_bytes: Option<usize>,
+    // from `starts_with` or `starts_with_any`, the string must start with one of them
+    prefixes: Vec<String>,
+    suffix: Option<String>,
     strip_whitespace: bool,
     to_lower: bool,
     to_upper: bool,
     coerce_numbers_to_str: bool,
+    coerce_bool_to_str: bool,
+    reject_empty_after_strip: bool,
 }
 
 impl_py_gc_traverse!(StrConstrainedValidator {});
@@ -79,20 +95,44 @@ impl Validator for StrConstrainedValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_str = input
-            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)?
-            .unpack(state);
+        let strict = state.strict_or(self.strict);
+        let either_str = match input.validate_str(strict, self.coerce_numbers_to_str) {
+            Ok(val_match) => val_match.unpack(state),
+            // `bool` is never coerced to a string by `validate_str`, since `str(True)` is `'True'`
+            Err(err) if self.coerce_bool_to_str && !strict => match input.validate_bool(true) {
+                Ok(val_match) => if val_match.into_inner() { "true" } else { "false" }.into(),
+                Err(_) => return Err(err),
+            },
+            Err(err) => return Err(err),
+        };
+        if strict && self.strict_subclass_check {
+            if let Some(py_input) = input.as_python() {
+                if !py_input.is_exact_instance_of::<PyString>() {
+                    return Err(ValError::new(ErrorTypeDefaults::StringTypeExact, input));
+                }
+            }
+        }
         let cow = either_str.as_cow()?;
         let mut str = cow.as_ref();
-        if self.strip_whitespace {
+        if self.strip_whitespace || self.reject_empty_after_strip {
             str = str.trim();
         }
+        if self.reject_empty_after_strip && str.is_empty() {
+            return Err(ValError::new(
+                ErrorType::StringTooShort {
+                    min_length: 1,
+                    context: None,
+                },
+                input,
+            ));
+        }
 
-        let str_len: Option<usize> = if self.min_length.is_some() | self.max_length.is_some() {
-            Some(str.chars().count())
-        } else {
-            None
-        };
+        let str_len: Option<usize> =
+            if self.min_length.is_some() | self.max_length.is_some() | self.max_codepoints.is_some() {
+                Some(str.chars().count())
+            } else {
+                None
+            };
         if let Some(min_length) = self.min_length {
             if str_len.unwrap() < min_length {
                 return Err(ValError::new(
@@ -116,7 +156,32 @@ impl Validator for StrConstrainedValidator {
             }
         }
 
-        if let Some(pattern) = &self.pattern {
+        if let Some(max_length) = self.max_codepoints {
+            if str_len.unwrap() > max_length {
+                return Err(ValError::new(
+                    ErrorType::StringTooLongCodepoints {
+                        max_length,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+
+        if let Some(max_length) = self.json_max_length_bytes {
+            // `str.len()` is the length of the UTF-8 encoding, so no Python string is needed
+            if state.extra().input_type == InputType::Json && str.len() > max_length {
+                return Err(ValError::new(
+                    ErrorType::StringTooLongBytes {
+                        max_length,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+
+        for pattern in self.pattern.iter().chain(&self.patterns) {
             if !pattern.is_match(py, str)? {
                 return Err(ValError::new(
                     ErrorType::StringPatternMismatch {
@@ -128,11 +193,52 @@ impl Validator for StrConstrainedValidator {
             }
         }
 
+        if let Some(format) = self.format {
+            if let Some(error_type) = format.check(str) {
+                return Err(ValError::new(error_type, input));
+            }
+        }
+
+        for pattern in self.not_pattern.iter().chain(&self.not_patterns) {
+            if pattern.is_match(py, str)? {
+                return Err(ValError::new(
+                    ErrorType::StringPatternMatch {
+                        pattern: pattern.pattern.clone(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+
+        if !self.prefixes.is_empty() && !self.prefixes.iter().any(|prefix| str.starts_with(prefix.as_str())) {
+            let prefix = match self.prefixes.as_slice() {
+                [prefix] => StringOrList::String(prefix.clone()),
+                prefixes => StringOrList::List(prefixes.to_vec()),
+            };
+            return Err(ValError::new(
+                ErrorType::StringStartsWith { prefix, context: None },
+                input,
+            ));
+        }
+
+        if let Some(ref suffix) = self.suffix {
+            if !str.ends_with(suffix.as_str()) {
+                return Err(ValError::new(
+                    ErrorType::StringEndsWith {
+                        suffix: suffix.clone(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+
         let py_string = if self.to_lower {
             state.maybe_cached_str(py, &str.to_lowercase())
         } else if self.to_upper {
             state.maybe_cached_str(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
+        } else if self.strip_whitespace || self.reject_empty_after_strip {
             state.maybe_cached_str(py, str)
         } else {
             // we haven't modified the string, return the original as it might be a PyString
@@ -150,27 +256,62 @@ impl StrConstrainedValidator {
     fn build(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
         let py = schema.py();
 
+        let regex_engine = schema_or_config::<Bound<'_, PyString>>(
+            schema,
+            config,
+            intern!(py, "regex_engine"),
+            intern!(py, "regex_engine"),
+        )?;
+        let regex_engine = regex_engine
+            .as_ref()
+            .map(|s| s.to_str())
+            .transpose()?
+            .unwrap_or(RegexEngine::RUST_REGEX);
         let pattern = schema
             .get_as(intern!(py, "pattern"))?
-            .map(|s| {
-                let regex_engine = schema_or_config::<Bound<'_, PyString>>(
-                    schema,
-                    config,
-                    intern!(py, "regex_engine"),
-                    intern!(py, "regex_engine"),
-                )?;
-                let regex_engine = regex_engine
-                    .as_ref()
-                    .map(|s| s.to_str())
-                    .transpose()?
-                    .unwrap_or(RegexEngine::RUST_REGEX);
-                Pattern::compile(py, s, regex_engine)
-            })
+            .map(|s| Pattern::compile(py, s, regex_engine))
+            .transpose()?;
+        let patterns = schema
+            .get_as::<Vec<String>>(intern!(py, "patterns"))?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|s| Pattern::compile(py, s, regex_engine))
+            .collect::<PyResult<Vec<_>>>()?;
+        if pattern.is_some() && !patterns.is_empty() {
+            return py_schema_err!("`pattern` and `patterns` cannot be used together");
+        }
+        let not_pattern = schema
+            .get_as(intern!(py, "not_pattern"))?
+            .map(|s| Pattern::compile(py, s, regex_engine))
+            .transpose()?;
+        let not_patterns = schema
+            .get_as::<Vec<String>>(intern!(py, "not_patterns"))?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|s| Pattern::compile(py, s, regex_engine))
+            .collect::<PyResult<Vec<_>>>()?;
+        if not_pattern.is_some() && !not_patterns.is_empty() {
+            return py_schema_err!("`not_pattern` and `not_patterns` cannot be used together");
+        }
+        let prefixes = match (
+            schema.get_as::<String>(intern!(py, "starts_with"))?,
+            schema.get_as::<Vec<String>>(intern!(py, "starts_with_any"))?,
+        ) {
+            (Some(_), Some(_)) => return py_schema_err!("`starts_with` and `starts_with_any` cannot be used together"),
+            (Some(prefix), None) => vec![prefix],
+            (None, Some(prefixes)) => prefixes,
+            (None, None) => Vec::new(),
+        };
+        let format = schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "format"))?
+            .map(|s| StrFormat::from_str(s.to_str()?))
             .transpose()?;
         let min_length: Option<usize> =
             schema_or_config(schema, config, intern!(py, "min_length"), intern!(py, "str_min_length"))?;
         let max_length: Option<usize> =
             schema_or_config(schema, config, intern!(py, "max_length"), intern!(py, "str_max_length"))?;
+        let max_codepoints: Option<usize> = schema.get_as(intern!(py, "max_codepoints"))?;
+        let json_max_length_bytes: Option<usize> = schema.get_as(intern!(py, "json_max_length_bytes"))?;
 
         let strip_whitespace: bool = schema_or_config(
             schema,
@@ -186,32 +327,189 @@ impl StrConstrainedValidator {
 
         let coerce_numbers_to_str: bool =
             schema_or_config_same(schema, config, intern!(py, "coerce_numbers_to_str"))?.unwrap_or(false);
+        let coerce_bool_to_str: bool = schema.get_as(intern!(py, "coerce_bool_to_str"))?.unwrap_or(false);
+        let reject_empty_after_strip: bool = schema.get_as(intern!(py, "reject_empty_after_strip"))?.unwrap_or(false);
 
         Ok(Self {
             strict: is_strict(schema, config)?,
+            strict_subclass_check: schema.get_as(intern!(py, "strict_subclass_check"))?.unwrap_or(false),
             pattern,
+            patterns,
+            not_pattern,
+            not_patterns,
+            format,
             min_length,
             max_length,
+            max_codepoints,
+            json_max_length_bytes,
+            prefixes,
+            suffix: schema.get_as(intern!(py, "ends_with"))?,
             strip_whitespace,
             to_lower,
             to_upper,
             coerce_numbers_to_str,
+            coerce_bool_to_str,
+            reject_empty_after_strip,
         })
     }
 
     // whether any of the constraints/customisations are actually enabled
     // except strict which can be set on StrValidator
     fn has_constraints_set(&self) -> bool {
-        self.pattern.is_some()
+        self.strict_subclass_check
+            || self.pattern.is_some()
+            || !self.patterns.is_empty()
+            || self.not_pattern.is_some()
+            || !self.not_patterns.is_empty()
+            || self.format.is_some()
             || self.max_length.is_some()
             || self.min_length.is_some()
+            || self.max_codepoints.is_some()
+            || self.json_max_length_bytes.is_some()
+            || !self.prefixes.is_empty()
+            || self.suffix.is_some()
             || self.strip_whitespace
             || self.to_lower
             || self.to_upper
             || self.coerce_numbers_to_str
+            || self.coerce_bool_to_str
+            || self.reject_empty_after_strip
+    }
+}
+
+/// Lightweight checks of common string formats, the validated value is still returned as a string
+#[derive(Debug, Clone, Copy)]
+enum StrFormat {
+    Ipv4,
+    Ipv6,
+    Ip,
+    Email,
+    Uri,
+    Hostname,
+    // a hostname which may contain non-ASCII labels, checked by encoding it per IDNA
+    Idna,
+}
+
+impl FromStr for StrFormat {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ipv4" => Ok(Self::Ipv4),
+            "ipv6" => Ok(Self::Ipv6),
+            "ip" => Ok(Self::Ip),
+            "email" => Ok(Self::Email),
+            "uri" => Ok(Self::Uri),
+            "hostname" => Ok(Self::Hostname),
+            "idna" => Ok(Self::Idna),
+            s => py_schema_err!("Invalid string format: {}", s),
+        }
+    }
+}
+
+impl std::fmt::Display for StrFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Ipv4 => "ipv4",
+            Self::Ipv6 => "ipv6",
+            Self::Ip => "ip",
+            Self::Email => "email",
+            Self::Uri => "uri",
+            Self::Hostname => "hostname",
+            Self::Idna => "idna",
+        };
+        write!(f, "{s}")
     }
 }
 
+impl StrFormat {
+    /// The error for `s` if it doesn't match this format, hostname formats report the invalid label
+    fn check(self, s: &str) -> Option<ErrorType> {
+        let is_valid = match self {
+            Self::Ipv4 => Ipv4Addr::from_str(s).is_ok(),
+            Self::Ipv6 => Ipv6Addr::from_str(s).is_ok(),
+            Self::Ip => IpAddr::from_str(s).is_ok(),
+            Self::Email => is_email(s),
+            Self::Uri => url::Url::parse(s).is_ok(),
+            Self::Hostname => {
+                return invalid_hostname_label(s, ascii_label_len)
+                    .map(|label| ErrorType::StringNotHostname { label, context: None })
+            }
+            Self::Idna => {
+                return invalid_hostname_label(s, idna_label_len)
+                    .map(|label| ErrorType::StringNotIdna { label, context: None })
+            }
+        };
+        match is_valid {
+            true => None,
+            false => Some(ErrorType::StringFormatMismatch {
+                format: self.to_string(),
+                context: None,
+            }),
+        }
+    }
+}
+
+/// Hostnames are at most 253 characters, optionally followed by a trailing dot
+const MAX_HOSTNAME_LENGTH: usize = 253;
+
+/// The first invalid label of hostname `s`, or the whole of `s` if it's too long. `ascii_label_len` returns the
+/// length of a label in ASCII form, or `None` if it's invalid
+fn invalid_hostname_label(s: &str, ascii_label_len: impl Fn(&str) -> Option<usize>) -> Option<String> {
+    let name = s.strip_suffix('.').unwrap_or(s);
+    let mut length = 0;
+    for label in name.split('.') {
+        match ascii_label_len(label) {
+            Some(label_len) => length += label_len + 1,
+            None => return Some(label.to_string()),
+        }
+    }
+    // `length` includes a dot after the last label
+    match length - 1 > MAX_HOSTNAME_LENGTH {
+        true => Some(s.to_string()),
+        false => None,
+    }
+}
+
+fn ascii_label_len(label: &str) -> Option<usize> {
+    is_hostname_label(label).then_some(label.len())
+}
+
+/// The length of `label` once encoded per IDNA, e.g. `bücher` is `xn--bcher-kva`
+fn idna_label_len(label: &str) -> Option<usize> {
+    // the hyphen rules apply to the unicode label too, its encoded form could hide them
+    if label.starts_with('-') || label.ends_with('-') {
+        return None;
+    }
+    idna::domain_to_ascii_strict(label)
+        .ok()
+        .filter(|ascii_label| is_hostname_label(ascii_label))
+        .map(|ascii_label| ascii_label.len())
+}
+
+/// A DNS label: 1 to 63 ASCII letters, digits and hyphens, not starting or ending with a hyphen
+fn is_hostname_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.len() <= 63
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// A subset of RFC 5322 addresses: a dot-atom local part, and a domain of at least two dot separated labels
+fn is_email(s: &str) -> bool {
+    let Some((local, domain)) = s.rsplit_once('@') else {
+        return false;
+    };
+    let is_atext = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c);
+    let local_ok = local.len() <= 64
+        && local
+            .split('.')
+            .all(|atom| !atom.is_empty() && atom.chars().all(is_atext));
+    let domain_ok = domain.len() <= 253 && domain.contains('.') && domain.split('.').all(is_hostname_label);
+    local_ok && domain_ok
+}
+
 #[derive(Debug, Clone)]
 struct Pattern {
     pattern: String,
@@ -1,13 +1,13 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyString, PyTime};
 
 use speedate::Time;
+use strum::EnumMessage;
 
-use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ValError, ValResult};
+use crate::build_tools::{is_strict, py_schema_error_type};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{EitherTime, Input};
-use crate::tools::SchemaDict;
 
 use super::datetime::extract_microseconds_precision;
 use super::datetime::TZConstraint;
@@ -51,6 +51,7 @@ impl Validator for TimeValidator {
             .unpack(state);
         if let Some(constraints) = &self.constraints {
             let raw_time = time.as_raw()?;
+            constraints.check_tz_awareness(&raw_time, input)?;
 
             macro_rules! check_constraint {
                 ($constraint:ident, $error:ident) => {
@@ -85,9 +86,21 @@ impl Validator for TimeValidator {
     }
 }
 
+/// Constraints may be given either as a `time` or as an ISO 8601 time string
 fn convert_pytime(schema: &Bound<'_, PyDict>, field: &Bound<'_, PyString>) -> PyResult<Option<Time>> {
-    match schema.get_as(field)? {
-        Some(date) => Ok(Some(EitherTime::Py(date).as_raw()?)),
+    match schema.get_item(field)? {
+        Some(value) => {
+            if let Ok(py_str) = value.downcast::<PyString>() {
+                return Time::parse_str(py_str.to_str()?).map(Some).map_err(|err| {
+                    py_schema_error_type!(
+                        "Invalid `{}` constraint, {}",
+                        field,
+                        err.get_documentation().unwrap_or_default()
+                    )
+                });
+            }
+            Ok(Some(EitherTime::Py(value.downcast_into::<PyTime>()?).as_raw()?))
+        }
         None => Ok(None),
     }
 }
@@ -117,4 +130,17 @@ impl TimeConstraints {
             Ok(None)
         }
     }
+
+    /// Comparing a timezone aware time with a naive one is ambiguous, so require the input to match the bounds
+    fn check_tz_awareness<'py>(&self, time: &Time, input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+        let bounds = [&self.le, &self.lt, &self.ge, &self.gt];
+        for bound in bounds.into_iter().flatten() {
+            match (bound.tz_offset.is_some(), time.tz_offset.is_some()) {
+                (true, false) => return Err(ValError::new(ErrorTypeDefaults::TimezoneAware, input)),
+                (false, true) => return Err(ValError::new(ErrorTypeDefaults::TimezoneNaive, input)),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
 }
@@ -1,8 +1,9 @@
 use pyo3::prelude::*;
-use pyo3::types::{PyDelta, PyDeltaAccess, PyDict};
+use pyo3::types::{PyDelta, PyDeltaAccess, PyDict, PyString};
 use speedate::Duration;
+use strum::EnumMessage;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{duration_as_pytimedelta, EitherTimedelta, Input};
 
@@ -24,9 +25,19 @@ struct TimedeltaConstraints {
     gt: Option<Duration>,
 }
 
-fn get_constraint(schema: &Bound<'_, PyDict>, key: &str) -> PyResult<Option<Duration>> {
+/// Constraints may be given either as a `timedelta` or as an ISO 8601 duration string
+fn parse_duration_constraint(schema: &Bound<'_, PyDict>, key: &str) -> PyResult<Option<Duration>> {
     match schema.get_item(key)? {
         Some(value) => {
+            if let Ok(py_str) = value.downcast::<PyString>() {
+                return Duration::parse_str(py_str.to_str()?).map(Some).map_err(|err| {
+                    py_schema_error_type!(
+                        "Invalid `{}` constraint, {}",
+                        key,
+                        err.get_documentation().unwrap_or_default()
+                    )
+                });
+            }
             let either_timedelta = EitherTimedelta::try_from(&value)?;
             Ok(Some(either_timedelta.to_duration()?))
         }
@@ -43,10 +54,10 @@ impl BuildValidator for TimeDeltaValidator {
         _definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let constraints = TimedeltaConstraints {
-            le: get_constraint(schema, "le")?,
-            lt: get_constraint(schema, "lt")?,
-            ge: get_constraint(schema, "ge")?,
-            gt: get_constraint(schema, "gt")?,
+            le: parse_duration_constraint(schema, "le")?,
+            lt: parse_duration_constraint(schema, "lt")?,
+            ge: parse_duration_constraint(schema, "ge")?,
+            gt: parse_duration_constraint(schema, "gt")?,
         };
 
         Ok(Self {
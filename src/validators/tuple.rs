@@ -18,6 +18,7 @@ pub struct TupleValidator {
     variadic_item_index: Option<usize>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    exact_length: Option<usize>,
     name: String,
 }
 
@@ -49,6 +50,7 @@ impl BuildValidator for TupleValidator {
             variadic_item_index,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            exact_length: schema.get_as(intern!(py, "exact_length"))?,
             name,
         }
         .into())
@@ -78,6 +80,11 @@ impl TupleValidator {
                     Err(ValError::LineErrors(line_errors)) => {
                         errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
                     }
+                    Err(ValError::Undefined) => errors.push(ValLineError::new_with_loc(
+                        ErrorTypeDefaults::Missing,
+                        input_item.borrow_input(),
+                        index,
+                    )),
                     Err(ValError::Omit) => (),
                     Err(err) => return Err(err),
                 },
@@ -138,6 +145,11 @@ impl TupleValidator {
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
                         }
+                        Err(ValError::Undefined) => errors.push(ValLineError::new_with_loc(
+                            ErrorTypeDefaults::Missing,
+                            input_item.borrow_input(),
+                            index,
+                        )),
                         Err(ValError::Omit) => (),
                         Err(err) => return Err(err),
                     }
@@ -169,6 +181,11 @@ impl TupleValidator {
                                     .map(|err| err.with_outer_location(buffer_item_index)),
                             );
                         }
+                        Err(ValError::Undefined) => errors.push(ValLineError::new_with_loc(
+                            ErrorTypeDefaults::Missing,
+                            buffered_item.borrow_input(),
+                            buffer_item_index,
+                        )),
                         Err(ValError::Omit) => (),
                         Err(err) => return Err(err),
                     }
@@ -261,7 +278,20 @@ impl Validator for TupleValidator {
             state,
         })??;
 
-        if let Some(min_length) = self.min_length {
+        if let Some(exact_length) = self.exact_length {
+            let actual_length = output.len();
+            if actual_length != exact_length {
+                errors.push(ValLineError::new(
+                    ErrorType::ExactLength {
+                        field_type: "Tuple".to_string(),
+                        exact_length,
+                        actual_length,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        } else if let Some(min_length) = self.min_length {
             let actual_length = output.len();
             if actual_length < min_length {
                 errors.push(ValLineError::new(
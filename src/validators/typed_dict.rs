@@ -1,20 +1,26 @@
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyKeyError, PyTypeError};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyType};
+use pyo3::{PyTraverseError, PyVisit};
 
 use ahash::AHashSet;
+use regex::Regex;
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{is_strict, schema_or_config, schema_or_config_same, ExtraBehavior};
 use crate::errors::LocItem;
-use crate::errors::{ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
 use crate::input::ValidationMatch;
 use crate::input::{Input, ValidatedDict};
 use crate::lookup_key::LookupKey;
-use crate::tools::SchemaDict;
+use crate::py_gc::PyGcTraverse;
+use crate::tools::{safe_repr, SchemaDict};
 
+use super::function::convert_err;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -22,19 +28,248 @@ struct TypedDictField {
     name: String,
     lookup_key: LookupKey,
     name_py: Py<PyString>,
+    alias: Option<String>,
     required: bool,
+    required_if: Option<RequiredIf>,
+    // former name of the field from `field_aliases`, used when the field itself is absent
+    old_name_key: Option<LookupKey>,
     validator: CombinedValidator,
 }
 
-impl_py_gc_traverse!(TypedDictField { validator });
+impl_py_gc_traverse!(TypedDictField { validator, required_if });
+
+/// Condition under which an otherwise optional field is required: another field was validated to `value`
+#[derive(Debug)]
+struct RequiredIf {
+    field: Py<PyString>,
+    value: PyObject,
+}
+
+impl_py_gc_traverse!(RequiredIf { field, value });
+
+/// How many of the fields of a `FieldGroup` (exactly, at least or at most one) may be present in the output
+#[derive(Debug, Clone, Copy)]
+enum FieldGroupRule {
+    Exactly,
+    AtLeast,
+    AtMost,
+}
+
+/// Fields from `require_exactly_one_of`, `require_at_least_one_of` or `require_at_most_one_of`,
+/// checked against the output once all fields have been validated
+#[derive(Debug)]
+struct FieldGroup {
+    rule: FieldGroupRule,
+    fields: Vec<String>,
+    // e.g. `'a', 'b'`, used in the error
+    fields_repr: String,
+}
+
+impl FieldGroup {
+    fn from_schema(
+        schema: &Bound<'_, PyDict>,
+        key: &Bound<'_, PyString>,
+        rule: FieldGroupRule,
+        fields: &[TypedDictField],
+    ) -> PyResult<Option<Self>> {
+        let Some(names) = schema.get_as::<Vec<Bound<'_, PyString>>>(key)? else {
+            return Ok(None);
+        };
+        if names.is_empty() {
+            return py_schema_err!("`{}` must not be empty", key);
+        }
+        for name in &names {
+            let name = name.to_str()?;
+            if !fields.iter().any(|field| field.name == name) {
+                return py_schema_err!("`{}` refers to unknown field '{}'", key, name);
+            }
+        }
+        let fields_repr = names
+            .iter()
+            .map(|name| format!("'{name}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Ok(Some(Self {
+            rule,
+            fields: names.iter().map(ToString::to_string).collect(),
+            fields_repr,
+        }))
+    }
+
+    /// `fields_set` are the fields which were provided in the input, fields set from their default don't count
+    fn check(&self, fields_set: &AHashSet<&str>) -> Option<ErrorType> {
+        let count = self
+            .fields
+            .iter()
+            .filter(|field| fields_set.contains(field.as_str()))
+            .count();
+        let fields = self.fields_repr.clone();
+        match self.rule {
+            FieldGroupRule::Exactly if count != 1 => Some(ErrorType::ExactlyOneOf { fields, context: None }),
+            FieldGroupRule::AtLeast if count < 1 => Some(ErrorType::AtLeastOneOf { fields, context: None }),
+            FieldGroupRule::AtMost if count > 1 => Some(ErrorType::AtMostOneOf { fields, context: None }),
+            _ => None,
+        }
+    }
+}
+
+impl TypedDictField {
+    fn field_info(&self, py: Python) -> FieldInfo {
+        let (has_default, default) = match self.validator.unwrap_deprecated() {
+            CombinedValidator::WithDefault(v) => (v.has_default(), v.static_default(py)),
+            _ => (false, None),
+        };
+        FieldInfo {
+            required: self.required,
+            alias: self.alias.clone(),
+            has_default,
+            default,
+        }
+    }
+}
+
+/// Information about a typed dict field, as returned by `SchemaValidator.get_model_fields`
+#[pyclass(module = "pydantic_core._pydantic_core", get_all, frozen)]
+#[derive(Debug)]
+pub struct FieldInfo {
+    required: bool,
+    alias: Option<String>,
+    has_default: bool,
+    default: Option<PyObject>,
+}
+
+#[pymethods]
+impl FieldInfo {
+    pub fn __repr__(&self, py: Python) -> String {
+        format!(
+            "FieldInfo(required={}, alias={}, has_default={}, default={})",
+            safe_repr(self.required.to_object(py).bind(py)),
+            safe_repr(self.alias.to_object(py).bind(py)),
+            safe_repr(self.has_default.to_object(py).bind(py)),
+            safe_repr(self.default.to_object(py).bind(py)),
+        )
+    }
+}
+
+/// Hook called once all fields of a typed dict have been validated
+#[derive(Debug)]
+enum PostInit {
+    /// `post_init` method looked up on `cls`, called with the validated dict and the validation context
+    Method(PyObject),
+    /// `post_init_function`, called with the validated dict, its return value becomes the output
+    Function(PyObject),
+}
+
+impl PyGcTraverse for PostInit {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Self::Method(obj) | Self::Function(obj) => visit.call(obj)?,
+        }
+        Ok(())
+    }
+}
+
+/// Entry of `field_ordering`: the value of the field at `field_index` must compare to `other_field`'s value
+/// with `op`, e.g. `end_date >= start_date`
+#[derive(Debug)]
+struct FieldOrdering {
+    field_index: usize,
+    other_field: Py<PyString>,
+    op: CompareOp,
+    operator: &'static str,
+}
+
+impl_py_gc_traverse!(FieldOrdering { other_field });
+
+impl FieldOrdering {
+    fn from_py(ordering: &Bound<'_, PyDict>, fields: &[TypedDictField]) -> PyResult<Self> {
+        let py = ordering.py();
+        let field: Bound<'_, PyString> = ordering.get_as_req(intern!(py, "field"))?;
+        let field_name = field.to_str()?;
+        let Some(field_index) = fields.iter().position(|f| f.name == field_name) else {
+            return py_schema_err!("`field_ordering` refers to unknown field '{}'", field);
+        };
+        let mut comparison = None;
+        for (key, op, operator) in [
+            (intern!(py, "gt_field"), CompareOp::Gt, ">"),
+            (intern!(py, "ge_field"), CompareOp::Ge, ">="),
+            (intern!(py, "lt_field"), CompareOp::Lt, "<"),
+            (intern!(py, "le_field"), CompareOp::Le, "<="),
+        ] {
+            if let Some(other_field) = ordering.get_as::<Bound<'_, PyString>>(key)? {
+                if comparison.is_some() {
+                    return py_schema_err!("`field_ordering` entry for '{}' must have exactly one operator", field);
+                }
+                let other_field_name = other_field.to_str()?;
+                if !fields.iter().any(|f| f.name == other_field_name) {
+                    return py_schema_err!("`field_ordering` refers to unknown field '{}'", other_field);
+                }
+                comparison = Some((other_field, op, operator));
+            }
+        }
+        let Some((other_field, op, operator)) = comparison else {
+            return py_schema_err!(
+                "`field_ordering` entry for '{}' must have one of `gt_field`, `ge_field`, `lt_field` or `le_field`",
+                field
+            );
+        };
+        Ok(Self {
+            field_index,
+            other_field: other_field.unbind(),
+            op,
+            operator,
+        })
+    }
+}
+
+/// Entry of `pattern_properties`: keys which aren't fields but match `pattern` are validated with `validator`
+#[derive(Debug)]
+struct PatternProperty {
+    pattern: Regex,
+    validator: CombinedValidator,
+}
+
+impl_py_gc_traverse!(PatternProperty { validator });
+
+impl PatternProperty {
+    fn from_py(
+        pattern_property: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<Self> {
+        let py = pattern_property.py();
+        let pattern: String = pattern_property.get_as_req(intern!(py, "pattern"))?;
+        let pattern = match Regex::new(&pattern) {
+            Ok(pattern) => pattern,
+            Err(err) => return py_schema_err!("`pattern_properties` has an invalid pattern '{}': {}", pattern, err),
+        };
+        let validator = build_validator(
+            &pattern_property.get_as_req(intern!(py, "schema"))?,
+            config,
+            definitions,
+        )?;
+        Ok(Self { pattern, validator })
+    }
+}
 
 #[derive(Debug)]
 pub struct TypedDictValidator {
     fields: Vec<TypedDictField>,
+    post_init: Option<PostInit>,
+    output_class: Option<PyObject>,
+    // keys used to look up the fields named by `from_sequence`, in order
+    from_sequence: Option<Vec<Py<PyString>>>,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
+    // checked in order for keys which aren't fields, before `extra_behavior` applies
+    pattern_properties: Vec<PatternProperty>,
+    field_groups: Vec<FieldGroup>,
+    field_ordering: Vec<FieldOrdering>,
     strict: bool,
+    // in strict mode, reject subclasses of `dict`
+    strict_dict_only: bool,
     loc_by_alias: bool,
+    name: String,
 }
 
 impl BuildValidator for TypedDictValidator {
@@ -68,7 +303,15 @@ impl BuildValidator for TypedDictValidator {
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<TypedDictField> = Vec::with_capacity(fields_dict.len());
 
-        for (key, value) in fields_dict {
+        let name = match schema.get_as::<String>(intern!(py, "discriminator"))? {
+            Some(discriminator) => {
+                let discriminator_value = get_discriminator_value(&fields_dict, &discriminator)?;
+                format!("{discriminator}:{discriminator_value}")
+            }
+            None => Self::EXPECTED_TYPE.to_string(),
+        };
+
+        for (key, value) in &fields_dict {
             let field_info = value.downcast::<PyDict>()?;
             let field_name_py = key.downcast_into::<PyString>()?;
             let field_name = field_name_py.to_str()?;
@@ -83,7 +326,7 @@ impl BuildValidator for TypedDictValidator {
             let required = match field_info.get_as::<bool>(intern!(py, "required"))? {
                 Some(required) => {
                     if required {
-                        if let CombinedValidator::WithDefault(ref val) = validator {
+                        if let CombinedValidator::WithDefault(val) = validator.unwrap_deprecated() {
                             if val.has_default() {
                                 return py_schema_err!(
                                     "Field '{}': a required field cannot have a default value",
@@ -97,8 +340,31 @@ impl BuildValidator for TypedDictValidator {
                 None => total,
             };
 
+            // fields with `required_if` are optional unless the condition is met
+            let required_if = match field_info.get_as::<Bound<'_, PyDict>>(intern!(py, "required_if"))? {
+                Some(_) if field_info.get_as(intern!(py, "required"))? == Some(true) => {
+                    return py_schema_err!("Field '{}': a required field cannot have `required_if`", field_name)
+                }
+                Some(condition) => {
+                    let field: Bound<'_, PyString> = condition.get_as_req(intern!(py, "field"))?;
+                    if !fields_dict.contains(&field)? {
+                        return py_schema_err!(
+                            "Field '{}': `required_if` refers to unknown field '{}'",
+                            field_name,
+                            field
+                        );
+                    }
+                    Some(RequiredIf {
+                        field: field.unbind(),
+                        value: condition.get_as_req(intern!(py, "value"))?,
+                    })
+                }
+                None => None,
+            };
+            let required = required && required_if.is_none();
+
             if required {
-                if let CombinedValidator::WithDefault(ref val) = validator {
+                if let CombinedValidator::WithDefault(val) = validator.unwrap_deprecated() {
                     if val.omit_on_error() {
                         return py_schema_err!(
                             "Field '{}': 'on_error = omit' cannot be set for required fields",
@@ -108,7 +374,13 @@ impl BuildValidator for TypedDictValidator {
                 }
             }
 
-            let lookup_key = match field_info.get_item(intern!(py, "validation_alias"))? {
+            // `alias` applies to both validation and serialization, unless overridden
+            let validation_alias = match field_info.get_item(intern!(py, "validation_alias"))? {
+                Some(alias) => Some(alias),
+                None => field_info.get_item(intern!(py, "alias"))?,
+            };
+            let alias = validation_alias.as_ref().and_then(|alias| alias.extract().ok());
+            let lookup_key = match validation_alias {
                 Some(alias) => {
                     let alt_alias = if populate_by_name { Some(field_name) } else { None };
                     LookupKey::from_py(py, &alias, alt_alias)?
@@ -120,27 +392,183 @@ impl BuildValidator for TypedDictValidator {
                 name: field_name.to_string(),
                 lookup_key,
                 name_py: field_name_py.into(),
+                alias,
                 validator,
                 required,
+                required_if,
+                old_name_key: None,
             });
         }
 
+        if let Some(field_aliases) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "field_aliases"))? {
+            for (new_name, old_name) in field_aliases.iter() {
+                let new_name: &str = new_name.extract()?;
+                let old_name: &str = old_name.extract()?;
+                match fields.iter_mut().find(|field| field.name == new_name) {
+                    Some(field) => field.old_name_key = Some(LookupKey::from_string(py, old_name)),
+                    None => return py_schema_err!("`field_aliases` refers to unknown field '{}'", new_name),
+                }
+            }
+        }
+
+        let post_init = match (
+            schema.get_as::<Bound<'_, PyString>>(intern!(py, "post_init"))?,
+            schema.get_item(intern!(py, "post_init_function"))?,
+        ) {
+            (Some(_), Some(_)) => {
+                return py_schema_err!("`post_init` and `post_init_function` cannot be used together")
+            }
+            (Some(method_name), None) => {
+                let Some(cls) = schema.get_as::<Bound<'_, PyType>>(intern!(py, "cls"))? else {
+                    return py_schema_err!("`post_init` requires `cls` to be set");
+                };
+                Some(PostInit::Method(cls.getattr(method_name)?.unbind()))
+            }
+            (None, Some(function)) => Some(PostInit::Function(function.unbind())),
+            (None, None) => None,
+        };
+
+        let output_class: Option<PyObject> = schema.get_as(intern!(py, "output_class"))?;
+        if output_class.is_some() && matches!(post_init, Some(PostInit::Function(_))) {
+            return py_schema_err!("`output_class` and `post_init_function` cannot be used together");
+        }
+
+        let from_sequence = match schema.get_as::<Vec<Bound<'_, PyString>>>(intern!(py, "from_sequence"))? {
+            Some(names) => Some(
+                names
+                    .iter()
+                    .map(|name| {
+                        let name = name.to_str()?;
+                        match fields.iter().find(|field| field.name == name).map(|f| &f.lookup_key) {
+                            Some(LookupKey::Simple { py_key, .. } | LookupKey::Choice { py_key1: py_key, .. }) => {
+                                Ok(py_key.clone_ref(py))
+                            }
+                            Some(LookupKey::PathChoices(_)) => {
+                                py_schema_err!("`from_sequence` cannot include field '{}' with a path alias", name)
+                            }
+                            None => py_schema_err!("`from_sequence` refers to unknown field '{}'", name),
+                        }
+                    })
+                    .collect::<PyResult<_>>()?,
+            ),
+            None => None,
+        };
+
+        let pattern_properties = schema
+            .get_as::<Vec<Bound<'_, PyDict>>>(intern!(py, "pattern_properties"))?
+            .unwrap_or_default()
+            .iter()
+            .map(|pattern_property| PatternProperty::from_py(pattern_property, config, definitions))
+            .collect::<PyResult<_>>()?;
+
+        let field_groups = [
+            (intern!(py, "require_exactly_one_of"), FieldGroupRule::Exactly),
+            (intern!(py, "require_at_least_one_of"), FieldGroupRule::AtLeast),
+            (intern!(py, "require_at_most_one_of"), FieldGroupRule::AtMost),
+        ]
+        .into_iter()
+        .filter_map(|(key, rule)| FieldGroup::from_schema(schema, key, rule, &fields).transpose())
+        .collect::<PyResult<_>>()?;
+
+        let field_ordering = schema
+            .get_as::<Vec<Bound<'_, PyDict>>>(intern!(py, "field_ordering"))?
+            .unwrap_or_default()
+            .iter()
+            .map(|ordering| FieldOrdering::from_py(ordering, &fields))
+            .collect::<PyResult<_>>()?;
+
         Ok(Self {
             fields,
+            post_init,
+            output_class,
+            from_sequence,
             extra_behavior,
             extras_validator,
+            pattern_properties,
+            field_groups,
+            field_ordering,
             strict,
+            strict_dict_only: schema.get_as(intern!(py, "strict_dict_only"))?.unwrap_or(false),
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
+            name,
         }
         .into())
     }
 }
 
+/// Get the value of the discriminator field, which must be a literal with a single expected value,
+/// this is used in the validator's name so union errors show which tagged choice failed
+fn get_discriminator_value(fields_dict: &Bound<'_, PyDict>, discriminator: &str) -> PyResult<String> {
+    let py = fields_dict.py();
+    let Some(field_info) = fields_dict.get_item(discriminator)? else {
+        return py_schema_err!("Discriminator field \"{}\" is not a field", discriminator);
+    };
+    let field_schema: Bound<'_, PyDict> = field_info.downcast::<PyDict>()?.get_as_req(intern!(py, "schema"))?;
+    if field_schema.get_as::<String>(intern!(py, "type"))?.as_deref() == Some("literal") {
+        let expected: Bound<'_, PyList> = field_schema.get_as_req(intern!(py, "expected"))?;
+        if expected.len() == 1 {
+            return Ok(expected.get_item(0)?.str()?.to_string());
+        }
+    }
+    py_schema_err!(
+        "Discriminator field \"{}\" must be a literal with a single expected value",
+        discriminator
+    )
+}
+
 impl_py_gc_traverse!(TypedDictValidator {
     fields,
-    extras_validator
+    post_init,
+    output_class,
+    from_sequence,
+    extras_validator,
+    pattern_properties,
+    field_ordering
 });
 
+impl TypedDictValidator {
+    /// A dict of field names to `FieldInfo`s, in definition order
+    pub fn field_infos<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        for field in &self.fields {
+            dict.set_item(field.name_py.bind(py), Py::new(py, field.field_info(py))?)?;
+        }
+        Ok(dict)
+    }
+
+    /// Map the items of a tuple, list or JSON array onto the fields named by `from_sequence` by position,
+    /// missing trailing fields are left to their defaults, surplus items are an error if extra is forbidden
+    /// and are otherwise dropped since they have no name
+    fn sequence_as_dict<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        keys: &[Py<PyString>],
+    ) -> ValResult<Option<Bound<'py, PyDict>>> {
+        let sequence = match input.as_python() {
+            Some(obj) if obj.is_instance_of::<PyTuple>() || obj.is_instance_of::<PyList>() => obj.clone(),
+            Some(_) => return Ok(None),
+            None if input.validate_list(true).is_ok() => input.to_object(py).into_bound(py),
+            None => return Ok(None),
+        };
+        let items = sequence.iter()?.collect::<PyResult<Vec<_>>>()?;
+        if items.len() > keys.len() && self.extra_behavior == ExtraBehavior::Forbid {
+            let errors = items
+                .iter()
+                .enumerate()
+                .skip(keys.len())
+                .map(|(index, item)| ValLineError::new_with_loc(ErrorTypeDefaults::ExtraForbidden, item, index))
+                .collect();
+            return Err(ValError::LineErrors(errors));
+        }
+        let dict = PyDict::new_bound(py);
+        for (key, item) in keys.iter().zip(items) {
+            dict.set_item(key, item)?;
+        }
+        Ok(Some(dict))
+    }
+}
+
 impl Validator for TypedDictValidator {
     fn validate<'py>(
         &self,
@@ -149,24 +577,43 @@ impl Validator for TypedDictValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
+        if let (Some(keys), false) = (&self.from_sequence, strict) {
+            if let Some(dict) = self.sequence_as_dict(py, input, keys)? {
+                return self.validate(py, dict.as_any(), state);
+            }
+        }
         let dict = input.validate_dict(strict)?;
+        if strict && self.strict_dict_only {
+            if let Some(py_input) = input.as_python() {
+                if !py_input.is_exact_instance_of::<PyDict>() {
+                    return Err(ValError::new(ErrorTypeDefaults::DictTypeExact, input));
+                }
+            }
+        }
 
         let output_dict = PyDict::new_bound(py);
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
+        // missing fields with a `required_if` condition, checked once all other fields have been validated
+        let mut conditional_fields: Vec<&TypedDictField> = Vec::new();
+        // fields provided in the input, used by field groups
+        let mut fields_set: AHashSet<&str> = AHashSet::with_capacity(self.fields.len());
 
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
-        let mut used_keys: Option<AHashSet<&str>> =
-            if self.extra_behavior == ExtraBehavior::Ignore || dict.is_py_get_attr() {
-                None
-            } else {
-                Some(AHashSet::with_capacity(self.fields.len()))
-            };
+        let mut used_keys: Option<AHashSet<&str>> = if (self.extra_behavior == ExtraBehavior::Ignore
+            && self.pattern_properties.is_empty())
+            || dict.is_py_get_attr()
+        {
+            None
+        } else {
+            Some(AHashSet::with_capacity(self.fields.len()))
+        };
 
         {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(output_dict.clone()));
 
             for field in &self.fields {
+                let skip_field = state.extra().skips_field(&field.name);
                 let op_key_value = match dict.get_item(&field.lookup_key) {
                     Ok(v) => v,
                     Err(ValError::LineErrors(line_errors)) => {
@@ -177,17 +624,68 @@ impl Validator for TypedDictValidator {
                     }
                     Err(err) => return Err(err),
                 };
+                let op_key_value = match (op_key_value, &field.old_name_key) {
+                    (None, Some(old_name_key)) => dict.get_item(old_name_key)?,
+                    (Some((lookup_path, value)), Some(old_name_key)) => {
+                        // both names are present, they're only allowed if they agree
+                        if let Some((old_path, old_value)) = dict.get_item(old_name_key)? {
+                            if let Some(ref mut used_keys) = used_keys {
+                                used_keys.insert(lookup_path.first_key());
+                                used_keys.insert(old_path.first_key());
+                            }
+                            let value_py = value.borrow_input().to_object(py);
+                            if !value_py.bind(py).eq(old_value.borrow_input().to_object(py))? {
+                                errors.push(ValLineError::new_with_loc(
+                                    ErrorType::AmbiguousField {
+                                        field_name: field.name.clone(),
+                                        old_name: old_path.first_key().to_string(),
+                                        context: None,
+                                    },
+                                    input,
+                                    field.name.clone(),
+                                ));
+                                continue;
+                            }
+                        }
+                        Some((lookup_path, value))
+                    }
+                    (op_key_value, None) => op_key_value,
+                };
                 if let Some((lookup_path, value)) = op_key_value {
                     if let Some(ref mut used_keys) = used_keys {
                         // key is "used" whether or not validation passes, since we want to skip this key in
                         // extra logic either way
                         used_keys.insert(lookup_path.first_key());
                     }
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    if skip_field {
+                        continue;
+                    }
+                    let result = {
+                        let state = &mut state.rebind_extra(|extra| {
+                            extra.field_name = Some(field.name_py.bind(py).clone());
+                            if extra.has_field_filter() {
+                                extra.enter_field(&field.name);
+                            }
+                        });
+                        field.validator.validate(py, value.borrow_input(), state)
+                    };
+                    match result {
                         Ok(value) => {
                             output_dict.set_item(&field.name_py, value)?;
+                            fields_set.insert(&field.name);
+                        }
+                        Err(ValError::Omit | ValError::DropField) => continue,
+                        // the default value was requested, but the field doesn't have one
+                        Err(ValError::UseDefault | ValError::Undefined) => {
+                            if field.required {
+                                errors.push(field.lookup_key.error(
+                                    ErrorTypeDefaults::Missing,
+                                    input,
+                                    self.loc_by_alias,
+                                    &field.name,
+                                ));
+                            }
                         }
-                        Err(ValError::Omit) => continue,
                         Err(ValError::LineErrors(line_errors)) => {
                             for err in line_errors {
                                 errors.push(lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name));
@@ -198,6 +696,11 @@ impl Validator for TypedDictValidator {
                     continue;
                 }
 
+                if skip_field {
+                    // partial validation, the field isn't required and its default isn't used
+                    continue;
+                }
+
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
                     Ok(Some(value)) => {
                         // Default value exists, and passed validation if required
@@ -212,9 +715,11 @@ impl Validator for TypedDictValidator {
                                 self.loc_by_alias,
                                 &field.name,
                             ));
+                        } else if field.required_if.is_some() {
+                            conditional_fields.push(field);
                         }
                     }
-                    Err(ValError::Omit) => continue,
+                    Err(ValError::Omit | ValError::DropField) => continue,
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
                             // Note: this will always use the field name even if there is an alias
@@ -229,12 +734,34 @@ impl Validator for TypedDictValidator {
             }
         }
 
+        for field in conditional_fields {
+            let Some(ref required_if) = field.required_if else {
+                continue;
+            };
+            let condition_field = required_if.field.bind(py);
+            if let Some(other_value) = output_dict.get_item(condition_field)? {
+                if other_value.eq(&required_if.value)? {
+                    errors.push(field.lookup_key.error(
+                        ErrorType::MissingConditionalField {
+                            condition_field: condition_field.to_string(),
+                            condition_value: safe_repr(required_if.value.bind(py)).to_string(),
+                            context: None,
+                        },
+                        input,
+                        self.loc_by_alias,
+                        &field.name,
+                    ));
+                }
+            }
+        }
+
         if let Some(used_keys) = used_keys {
             struct ValidateExtras<'a, 's, 'py> {
                 py: Python<'py>,
                 used_keys: AHashSet<&'a str>,
                 errors: &'a mut Vec<ValLineError>,
                 extras_validator: Option<&'a CombinedValidator>,
+                pattern_properties: &'a [PatternProperty],
                 output_dict: &'a Bound<'py, PyDict>,
                 state: &'a mut ValidationState<'s, 'py>,
                 extra_behavior: ExtraBehavior,
@@ -272,6 +799,26 @@ impl Validator for TypedDictValidator {
                         }
 
                         let value = value.borrow_input();
+                        if let Some(pattern_property) = self
+                            .pattern_properties
+                            .iter()
+                            .find(|pattern_property| pattern_property.pattern.is_match(cow.as_ref()))
+                        {
+                            match pattern_property.validator.validate(self.py, value, self.state) {
+                                Ok(value) => {
+                                    let py_key = either_str.as_py_string(self.py, self.state.cache_str());
+                                    self.output_dict.set_item(py_key, value)?;
+                                }
+                                Err(ValError::LineErrors(line_errors)) => {
+                                    for err in line_errors {
+                                        self.errors.push(err.with_outer_location(raw_key.clone()));
+                                    }
+                                }
+                                Err(err) => return Err(err),
+                            }
+                            continue;
+                        }
+
                         // Unknown / extra field
                         match self.extra_behavior {
                             ExtraBehavior::Forbid => {
@@ -312,20 +859,157 @@ impl Validator for TypedDictValidator {
                 py,
                 errors: &mut errors,
                 extras_validator: self.extras_validator.as_deref(),
+                pattern_properties: &self.pattern_properties,
                 output_dict: &output_dict,
                 state,
                 extra_behavior: self.extra_behavior,
             })??;
         }
 
+        // field groups and orderings are only checked if all fields are valid, an invalid field is missing from
+        // the output
+        if errors.is_empty() {
+            for group in &self.field_groups {
+                if let Some(error_type) = group.check(&fields_set) {
+                    errors.push(ValLineError::new(error_type, input));
+                }
+            }
+            for ordering in &self.field_ordering {
+                let field = &self.fields[ordering.field_index];
+                let other_field = ordering.other_field.bind(py);
+                // orderings involving a field which wasn't provided, or is `None`, are skipped
+                let (Some(value), Some(other_value)) = (
+                    output_dict.get_item(&field.name_py)?,
+                    output_dict.get_item(other_field)?,
+                ) else {
+                    continue;
+                };
+                if PyAnyMethods::is_none(&value) || PyAnyMethods::is_none(&other_value) {
+                    continue;
+                }
+                // values which can't be compared violate the ordering
+                let ordered = match value.rich_compare(&other_value, ordering.op) {
+                    Ok(result) => result.is_truthy()?,
+                    Err(err) if err.is_instance_of::<PyTypeError>(py) => false,
+                    Err(err) => return Err(err.into()),
+                };
+                if !ordered {
+                    errors.push(field.lookup_key.error(
+                        ErrorType::FieldOrderingViolation {
+                            field: field.name.clone(),
+                            operator: ordering.operator.to_string(),
+                            other_field: other_field.to_string(),
+                            context: None,
+                        },
+                        &value,
+                        self.loc_by_alias,
+                        &field.name,
+                    ));
+                }
+            }
+        }
+
         if !errors.is_empty() {
-            Err(ValError::LineErrors(errors))
+            return Err(ValError::LineErrors(errors));
+        }
+        match self.post_init {
+            Some(PostInit::Method(ref method)) => {
+                method
+                    .call1(py, (&output_dict, state.extra().context))
+                    .map_err(|e| convert_err(py, e, input))?;
+            }
+            Some(PostInit::Function(ref function)) => {
+                return function
+                    .call1(py, (&output_dict,))
+                    .map_err(|e| convert_err(py, e, input));
+            }
+            None => (),
+        }
+        match self.output_class {
+            // `ValueError`s from the constructor are validation errors, anything else, e.g. a `TypeError` from
+            // unexpected keyword arguments, is raised as is
+            Some(ref output_class) => output_class
+                .call_bound(py, (), Some(&output_dict))
+                .map_err(|e| convert_err(py, e, input)),
+            None => Ok(output_dict.to_object(py)),
+        }
+    }
+
+    fn validate_assignment<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        field_name: &str,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let dict = obj.downcast::<PyDict>()?;
+
+        let ok = |output: PyObject| {
+            dict.set_item(field_name, output)?;
+            Ok(dict.to_object(py))
+        };
+
+        let validator = if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
+            Some(&field.validator)
+        } else if let Some(pattern_property) = self
+            .pattern_properties
+            .iter()
+            .find(|pattern_property| pattern_property.pattern.is_match(field_name))
+        {
+            Some(&pattern_property.validator)
         } else {
-            Ok(output_dict.to_object(py))
+            match self.extra_behavior {
+                // For typed dicts we forbid assigning extra keys unless the user explicitly set
+                // extra_behavior to 'allow', matching model fields
+                ExtraBehavior::Allow => self.extras_validator.as_deref(),
+                ExtraBehavior::Forbid | ExtraBehavior::Ignore => {
+                    return Err(ValError::new_with_loc(
+                        ErrorType::NoSuchAttribute {
+                            attribute: field_name.to_string(),
+                            context: None,
+                        },
+                        field_value,
+                        field_name.to_string(),
+                    ))
+                }
+            }
+        };
+        let Some(validator) = validator else {
+            return ok(field_value.to_object(py));
+        };
+
+        // by using dict but removing the field in question, we match V1 behaviour
+        let data_dict = dict.copy()?;
+        if let Err(err) = data_dict.del_item(field_name) {
+            // KeyError is fine here as the field might not be in the dict
+            if !err.get_type_bound(py).is(&PyType::new_bound::<PyKeyError>(py)) {
+                return Err(err.into());
+            }
         }
+
+        match validator.validate(
+            py,
+            field_value,
+            &mut state.rebind_extra(|extra| extra.data = Some(data_dict)),
+        ) {
+            Ok(output) => ok(output),
+            Err(ValError::LineErrors(line_errors)) => {
+                let errors = line_errors
+                    .into_iter()
+                    .map(|e| e.with_outer_location(field_name))
+                    .collect();
+                Err(ValError::LineErrors(errors))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    fn field_names(&self) -> Option<Vec<&str>> {
+        Some(self.fields.iter().map(|field| field.name.as_str()).collect())
     }
 
     fn get_name(&self) -> &str {
-        Self::EXPECTED_TYPE
+        &self.name
     }
 }
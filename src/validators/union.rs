@@ -1,3 +1,4 @@
+use std::cmp::Reverse;
 use std::fmt::Write;
 use std::str::FromStr;
 
@@ -8,8 +9,8 @@ use smallvec::SmallVec;
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{is_strict, schema_or_config};
-use crate::errors::{ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
-use crate::input::{BorrowInput, Input, ValidatedDict};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ToErrorValue, ValError, ValLineError, ValResult};
+use crate::input::{BorrowInput, Input, JsonType, ValidatedDict};
 use crate::lookup_key::LookupKey;
 use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
@@ -24,6 +25,7 @@ use super::{
 enum UnionMode {
     Smart,
     LeftToRight,
+    BestMatch,
 }
 
 impl FromStr for UnionMode {
@@ -33,7 +35,11 @@ impl FromStr for UnionMode {
         match s {
             "smart" => Ok(Self::Smart),
             "left_to_right" => Ok(Self::LeftToRight),
-            s => py_schema_err!("Invalid union mode: `{}`, expected `smart` or `left_to_right`", s),
+            "best_match" => Ok(Self::BestMatch),
+            s => py_schema_err!(
+                "Invalid union mode: `{}`, expected `smart`, `left_to_right` or `best_match`",
+                s
+            ),
         }
     }
 }
@@ -44,9 +50,57 @@ pub struct UnionValidator {
     choices: Vec<(CombinedValidator, Option<String>)>,
     custom_error: Option<CustomError>,
     strict: bool,
+    // if set, JSON input is first matched against the choices whose schema type corresponds to its JSON type
+    exact_json_type: bool,
+    // the JSON type of each choice, `None` if its schema type doesn't correspond to a single JSON type
+    choice_json_types: Vec<Option<JsonType>>,
+    // `pydantic_core.union` logger, set if `union_debug` is enabled
+    debug_logger: Option<PyObject>,
     name: String,
 }
 
+/// `logging.DEBUG`
+const LOGGING_DEBUG: u8 = 10;
+
+/// Log a message built by `msg` if `logger` is set, i.e. `union_debug` is on and the logger is enabled for `DEBUG`
+fn debug_log(logger: Option<&Bound<'_, PyAny>>, msg: impl FnOnce() -> String) -> PyResult<()> {
+    if let Some(logger) = logger {
+        logger.call_method1(intern!(logger.py(), "debug"), (msg(),))?;
+    }
+    Ok(())
+}
+
+fn choice_name<'a>(choice: &'a CombinedValidator, label: Option<&'a str>) -> &'a str {
+    label.unwrap_or(choice.get_name())
+}
+
+/// A choice whose validator returned `PydanticUndefined` has failed, rather than the whole union
+fn undefined_as_missing<'py>(result: ValResult<PyObject>, input: &(impl Input<'py> + ?Sized)) -> ValResult<PyObject> {
+    match result {
+        Err(ValError::Undefined) => Err(ValError::new(ErrorTypeDefaults::Missing, input)),
+        otherwise => otherwise,
+    }
+}
+
+/// The JSON type a choice's schema accepts without coercion, used by `exact_json_type`
+fn schema_json_type(schema: &Bound<'_, PyAny>) -> PyResult<Option<JsonType>> {
+    let Ok(schema_dict) = schema.downcast::<PyDict>() else {
+        return Ok(None);
+    };
+    let Some(schema_type) = schema_dict.get_as::<Bound<'_, PyString>>(intern!(schema.py(), "type"))? else {
+        return Ok(None);
+    };
+    Ok(match schema_type.to_str()? {
+        "none" => Some(JsonType::Null),
+        "bool" => Some(JsonType::Bool),
+        "int" | "float" | "decimal" => Some(JsonType::Number),
+        "str" => Some(JsonType::String),
+        "list" | "tuple" | "set" | "frozenset" => Some(JsonType::Array),
+        "dict" | "typed-dict" | "model" | "dataclass" => Some(JsonType::Object),
+        _ => None,
+    })
+}
+
 impl BuildValidator for UnionValidator {
     const EXPECTED_TYPE: &'static str = "union";
 
@@ -56,7 +110,7 @@ impl BuildValidator for UnionValidator {
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        let choices: Vec<(CombinedValidator, Option<String>)> = schema
+        let mut choices: Vec<(i64, CombinedValidator, Option<String>, Option<JsonType>)> = schema
             .get_as_req::<Bound<'_, PyList>>(intern!(py, "choices"))?
             .iter()
             .map(|choice| {
@@ -69,9 +123,21 @@ impl BuildValidator for UnionValidator {
                     }
                     Err(_) => choice,
                 };
-                Ok((build_validator(&choice, config, definitions)?, label))
+                let priority = match choice.downcast::<PyDict>() {
+                    Ok(choice_dict) => choice_dict.get_as(intern!(py, "priority"))?.unwrap_or(0),
+                    Err(_) => 0,
+                };
+                let validator = build_validator(&choice, config, definitions)?;
+                Ok((priority, validator, label, schema_json_type(&choice)?))
             })
-            .collect::<PyResult<Vec<(CombinedValidator, Option<String>)>>>()?;
+            .collect::<PyResult<_>>()?;
+        // choices with a higher priority are tried first, the sort is stable so ties keep the schema's order
+        choices.sort_by_key(|(priority, _, _, _)| Reverse(*priority));
+        let choice_json_types: Vec<Option<JsonType>> = choices.iter().map(|(_, _, _, json_type)| *json_type).collect();
+        let choices: Vec<(CombinedValidator, Option<String>)> = choices
+            .into_iter()
+            .map(|(_, validator, label, _)| (validator, label))
+            .collect();
 
         let auto_collapse = || schema.get_as_req(intern!(py, "auto_collapse")).unwrap_or(true);
         let mode = schema
@@ -83,7 +149,7 @@ impl BuildValidator for UnionValidator {
             _ => {
                 let descr = choices
                     .iter()
-                    .map(|(choice, label)| label.as_deref().unwrap_or(choice.get_name()))
+                    .map(|(choice, label)| choice_name(choice, label.as_deref()))
                     .collect::<Vec<_>>()
                     .join(",");
 
@@ -92,6 +158,16 @@ impl BuildValidator for UnionValidator {
                     choices,
                     custom_error: CustomError::build(schema, config, definitions)?,
                     strict: is_strict(schema, config)?,
+                    exact_json_type: schema.get_as(intern!(py, "exact_json_type"))?.unwrap_or(false),
+                    choice_json_types,
+                    debug_logger: match schema.get_as(intern!(py, "union_debug"))?.unwrap_or(false) {
+                        true => Some(
+                            py.import_bound(intern!(py, "logging"))?
+                                .call_method1(intern!(py, "getLogger"), ("pydantic_core.union",))?
+                                .unbind(),
+                        ),
+                        false => None,
+                    },
                     name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
                 }
                 .into())
@@ -101,6 +177,21 @@ impl BuildValidator for UnionValidator {
 }
 
 impl UnionValidator {
+    /// The debug logger, only if `union_debug` is set and the logger is enabled for `DEBUG` messages
+    fn enabled_debug_logger<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let Some(ref logger) = self.debug_logger else {
+            return Ok(None);
+        };
+        let logger = logger.bind(py);
+        match logger
+            .call_method1(intern!(py, "isEnabledFor"), (LOGGING_DEBUG,))?
+            .is_truthy()?
+        {
+            true => Ok(Some(logger.clone())),
+            false => Ok(None),
+        }
+    }
+
     fn validate_smart<'py>(
         &self,
         py: Python<'py>,
@@ -110,6 +201,8 @@ impl UnionValidator {
         let old_exactness = state.exactness;
         let strict = state.strict_or(self.strict);
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
+        let logger = self.enabled_debug_logger(py)?;
+        let logger = logger.as_ref();
 
         let mut success = None;
 
@@ -120,11 +213,15 @@ impl UnionValidator {
                 }
             });
             state.exactness = Some(Exactness::Exact);
-            let result = choice.validate(py, input, state);
+            let result = undefined_as_missing(choice.validate(py, input, state), input);
+            self.log_result(logger, choice, label.as_deref(), &result, state.exactness)?;
             match result {
                 Ok(new_success) => match state.exactness {
                     // exact match, return
                     Some(Exactness::Exact) => {
+                        debug_log(logger, || {
+                            self.selected_msg(Some(choice_name(choice, label.as_deref())))
+                        })?;
                         return {
                             // exact match, return, restore any previous exactness
                             state.exactness = old_exactness;
@@ -138,12 +235,12 @@ impl UnionValidator {
                         // if the new result has higher exactness than the current success, replace it
                         if success
                             .as_ref()
-                            .map_or(true, |(_, current_exactness)| *current_exactness < new_exactness)
+                            .map_or(true, |(_, current_exactness, _)| *current_exactness < new_exactness)
                         {
                             // TODO: is there a possible optimization here, where once there has
                             // been one success, we turn on strict mode, to avoid unnecessary
                             // coercions for further validation?
-                            success = Some((new_success, new_exactness));
+                            success = Some((new_success, new_exactness, choice_name(choice, label.as_deref())));
                         }
                     }
                 },
@@ -157,8 +254,9 @@ impl UnionValidator {
             }
         }
         state.exactness = old_exactness;
+        debug_log(logger, || self.selected_msg(success.as_ref().map(|(_, _, name)| *name)))?;
 
-        if let Some((success, exactness)) = success {
+        if let Some((success, exactness, _)) = success {
             state.floor_exactness(exactness);
             return Ok(success);
         }
@@ -183,20 +281,183 @@ impl UnionValidator {
             state
         };
 
+        let logger = self.enabled_debug_logger(py)?;
+        let logger = logger.as_ref();
+
         for (validator, label) in &self.choices {
-            match validator.validate(py, input, state) {
+            let result = undefined_as_missing(validator.validate(py, input, state), input);
+            self.log_result(logger, validator, label.as_deref(), &result, None)?;
+            match result {
                 Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
-                otherwise => return otherwise,
+                otherwise => {
+                    if otherwise.is_ok() {
+                        debug_log(logger, || {
+                            self.selected_msg(Some(choice_name(validator, label.as_deref())))
+                        })?;
+                    }
+                    return otherwise;
+                }
             };
         }
 
+        debug_log(logger, || self.selected_msg(None))?;
+        Err(errors.into_val_error(input))
+    }
+
+    /// With `exact_json_type`, only the choices whose schema type matches the input's JSON type are tried, in
+    /// strict mode, as in smart mode an exact match is returned straight away, otherwise the leftmost of the
+    /// most exact successes is used; `None` means no such choice succeeded and the union's mode is used instead
+    fn validate_exact_json_type<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+        json_type: JsonType,
+    ) -> ValResult<Option<PyObject>> {
+        let old_exactness = state.exactness;
+        let logger = self.enabled_debug_logger(py)?;
+        let logger = logger.as_ref();
+        debug_log(logger, || format!("{}: matching JSON type `{json_type}`", self.name))?;
+
+        let mut success: Option<(PyObject, Exactness, &str)> = None;
+        let choices = self
+            .choices
+            .iter()
+            .zip(&self.choice_json_types)
+            .filter(|(_, choice_json_type)| **choice_json_type == Some(json_type));
+        for ((choice, label), _) in choices {
+            let state = &mut state.rebind_extra(|extra| extra.strict = Some(true));
+            state.exactness = Some(Exactness::Exact);
+            let result = undefined_as_missing(choice.validate(py, input, state), input);
+            self.log_result(logger, choice, label.as_deref(), &result, state.exactness)?;
+            match result {
+                Ok(new_success) => {
+                    let new_exactness = state.exactness.unwrap_or(Exactness::Lax);
+                    if success
+                        .as_ref()
+                        .map_or(true, |(_, current_exactness, _)| *current_exactness < new_exactness)
+                    {
+                        success = Some((new_success, new_exactness, choice_name(choice, label.as_deref())));
+                    }
+                    if new_exactness == Exactness::Exact {
+                        break;
+                    }
+                }
+                Err(ValError::LineErrors(_)) => {}
+                Err(err) => {
+                    state.exactness = old_exactness;
+                    return Err(err);
+                }
+            }
+        }
+        state.exactness = old_exactness;
+
+        match success {
+            Some((success, exactness, name)) => {
+                debug_log(logger, || self.selected_msg(Some(name)))?;
+                state.floor_exactness(exactness);
+                Ok(Some(success))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Like `validate_smart`, but any choice which would succeed in strict mode is equally good,
+    /// so the leftmost of those is used, falling back to the leftmost lax success
+    fn validate_best_match<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let old_exactness = state.exactness;
+        let strict = state.strict_or(self.strict);
+        let mut errors = MaybeErrors::new(self.custom_error.as_ref());
+
+        let logger = self.enabled_debug_logger(py)?;
+        let logger = logger.as_ref();
+
+        let mut successes: Vec<(PyObject, Exactness, &str)> = Vec::new();
+
+        for (choice, label) in &self.choices {
+            let state = &mut state.rebind_extra(|extra| {
+                if strict {
+                    extra.strict = Some(strict);
+                }
+            });
+            state.exactness = Some(Exactness::Exact);
+            let result = undefined_as_missing(choice.validate(py, input, state), input);
+            self.log_result(logger, choice, label.as_deref(), &result, state.exactness)?;
+            let name = choice_name(choice, label.as_deref());
+            match result {
+                Ok(success) => {
+                    // success should always have an exactness
+                    debug_assert_ne!(state.exactness, None);
+                    let exactness = state.exactness.unwrap_or(Exactness::Lax);
+                    if exactness >= Exactness::Strict {
+                        // no later choice can be a better match
+                        successes.clear();
+                        successes.push((success, exactness, name));
+                        break;
+                    }
+                    successes.push((success, exactness, name));
+                }
+                Err(ValError::LineErrors(lines)) => {
+                    if successes.is_empty() {
+                        errors.push(choice, label.as_deref(), lines);
+                    }
+                }
+                otherwise => return otherwise,
+            }
+        }
+        state.exactness = old_exactness;
+        debug_log(logger, || {
+            self.selected_msg(successes.first().map(|(_, _, name)| *name))
+        })?;
+
+        if let Some((success, exactness, _)) = successes.into_iter().next() {
+            state.floor_exactness(exactness);
+            return Ok(success);
+        }
+
         Err(errors.into_val_error(input))
     }
 }
 
+impl UnionValidator {
+    fn log_result(
+        &self,
+        logger: Option<&Bound<'_, PyAny>>,
+        choice: &CombinedValidator,
+        label: Option<&str>,
+        result: &ValResult<PyObject>,
+        exactness: Option<Exactness>,
+    ) -> PyResult<()> {
+        debug_log(logger, || {
+            let name = choice_name(choice, label);
+            match (result, exactness) {
+                (Ok(_), Some(exactness)) => format!("{}: `{name}` succeeded ({exactness:?})", self.name),
+                (Ok(_), None) => format!("{}: `{name}` succeeded", self.name),
+                (Err(ValError::LineErrors(lines)), _) => {
+                    format!("{}: `{name}` failed with {} error(s)", self.name, lines.len())
+                }
+                (Err(_), _) => format!("{}: `{name}` raised an error", self.name),
+            }
+        })
+    }
+
+    fn selected_msg(&self, selected: Option<&str>) -> String {
+        match selected {
+            Some(name) => format!("{}: selected `{name}`", self.name),
+            None => format!("{}: no choice succeeded", self.name),
+        }
+    }
+}
+
 impl PyGcTraverse for UnionValidator {
     fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
         self.choices.iter().try_for_each(|(v, _)| v.py_gc_traverse(visit))?;
+        self.debug_logger.py_gc_traverse(visit)?;
         Ok(())
     }
 }
@@ -208,9 +469,15 @@ impl Validator for UnionValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        if let Some(json_type) = input.json_type().filter(|_| self.exact_json_type) {
+            if let Some(success) = self.validate_exact_json_type(py, input, state, json_type)? {
+                return Ok(success);
+            }
+        }
         match self.mode {
             UnionMode::Smart => self.validate_smart(py, input, state),
             UnionMode::LeftToRight => self.validate_left_to_right(py, input, state),
+            UnionMode::BestMatch => self.validate_best_match(py, input, state),
         }
     }
 
@@ -3,6 +3,7 @@ use pyo3::types::PyString;
 
 use jiter::StringCacheMode;
 
+use crate::errors::ValidationWarning;
 use crate::recursion_guard::{ContainsRecursionState, RecursionState};
 use crate::tools::new_py_string;
 
@@ -18,6 +19,8 @@ pub enum Exactness {
 pub struct ValidationState<'a, 'py> {
     pub recursion_guard: &'a mut RecursionState,
     pub exactness: Option<Exactness>,
+    // warnings are collected here rather than emitted if `collect_warnings` is set
+    pub warnings: Option<Vec<ValidationWarning>>,
     // deliberately make Extra readonly
     extra: Extra<'a, 'py>,
 }
@@ -27,6 +30,7 @@ impl<'a, 'py> ValidationState<'a, 'py> {
         Self {
             recursion_guard, // Don't care about exactness unless doing union validation
             exactness: None,
+            warnings: None,
             extra,
         }
     }
@@ -146,7 +146,9 @@ impl Validator for WithDefaultValidator {
             match self.validator.validate(py, input, state) {
                 Ok(v) => Ok(v),
                 Err(e) => match e {
-                    ValError::UseDefault => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
+                    ValError::UseDefault | ValError::Undefined => {
+                        Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?)
+                    }
                     e => match self.on_error {
                         OnError::Raise => Err(e),
                         OnError::Default => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
@@ -200,6 +202,14 @@ impl WithDefaultValidator {
         !matches!(self.default, DefaultType::None)
     }
 
+    /// The default value, `None` if there's no default or it comes from a `default_factory`
+    pub fn static_default(&self, py: Python) -> Option<PyObject> {
+        match self.default {
+            DefaultType::Default(ref default) => Some(default.clone_ref(py)),
+            _ => None,
+        }
+    }
+
     pub fn omit_on_error(&self) -> bool {
         matches!(self.on_error, OnError::Omit)
     }
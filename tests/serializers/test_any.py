@@ -644,3 +644,10 @@ def test_ser_json_inf_nan_with_list_of_any() -> None:
     assert isnan(s.to_python([nan])[0])
     assert s.to_python([nan], mode='json')[0] is None
     assert s.to_json([nan]) == b'[null]'
+
+
+def test_any_wrapper_schema():
+    s = SchemaSerializer(core_schema.any_schema(schema=core_schema.float_schema(), metadata={'a': 1}))
+    assert s.to_python(1) == 1
+    assert s.to_json(1) == b'1.0'
+    assert 'FloatSerializer' in plain_repr(s)
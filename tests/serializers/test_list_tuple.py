@@ -1,10 +1,18 @@
 import json
 import re
+import warnings
 from functools import partial
 
 import pytest
 
-from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, core_schema, validate_core_schema
+from pydantic_core import (
+    PydanticSerializationError,
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    core_schema,
+    validate_core_schema,
+)
 
 
 def test_list_any():
@@ -42,6 +50,25 @@ def test_list_fallback():
         assert v.to_python((1, 2, 3), mode='json') == [1, 2, 3]
 
 
+
+def test_list_output_type_tuple():
+    schema = core_schema.list_schema(core_schema.int_schema(), output_type='tuple')
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    value = v.validate_python(['1', 2])
+    assert value == (1, 2)
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert s.to_python(value) == (1, 2)
+        assert s.to_python(value, mode='json') == [1, 2]
+        assert s.to_json(value) == b'[1,2]'
+        assert s.to_python([1, 2]) == [1, 2]
+
+    # without `output_type='tuple'`, tuples are unexpected
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.warns(UserWarning, match=re.escape('Expected `list[int]` but got `tuple`')):
+        s.to_python((1, 2))
+
 def test_list_str_fallback():
     v = SchemaSerializer(core_schema.list_schema(core_schema.str_schema()))
     with pytest.warns(UserWarning) as warning_info:
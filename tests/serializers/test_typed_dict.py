@@ -127,6 +127,21 @@ def test_alias():
     assert json.loads(s.to_json(value, by_alias=False)) == IsStrictDict(cat=0, dog=1, bird=2)
 
 
+def test_alias_serialization_alias():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'cat': core_schema.typed_dict_field(core_schema.int_schema(), alias='Meow'),
+                'dog': core_schema.typed_dict_field(core_schema.int_schema(), alias='Bark', serialization_alias='Woof'),
+            }
+        )
+    )
+    value = {'cat': 0, 'dog': 1}
+    assert s.to_python(value) == IsStrictDict(Meow=0, Woof=1)
+    assert s.to_python(value, by_alias=False) == IsStrictDict(cat=0, dog=1)
+    assert json.loads(s.to_json(value)) == IsStrictDict(Meow=0, Woof=1)
+
+
 def test_exclude_none():
     v = SchemaSerializer(
         core_schema.typed_dict_schema(
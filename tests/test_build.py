@@ -2,7 +2,7 @@ import pickle
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, validate_core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, set_schema_cache_size, validate_core_schema
 from pydantic_core import core_schema as cs
 
 
@@ -139,3 +139,104 @@ def test_build_recursive_schema_from_defs() -> None:
     )
 
     SchemaValidator(s)
+
+
+def test_from_schema_cached():
+    schema = cs.int_schema()
+    config = cs.CoreConfig(strict=True)
+    v = SchemaValidator.from_schema_cached(schema, config)
+    assert v.validate_python(1) == 1
+    assert SchemaValidator.from_schema_cached(schema, config) is v
+    # lookups use the identity of the schema and config, not their contents
+    assert SchemaValidator.from_schema_cached(schema) is not v
+    assert SchemaValidator.from_schema_cached(cs.int_schema(), config) is not v
+
+
+def test_from_schema_cached_size():
+    schemas = [cs.int_schema(), cs.str_schema(), cs.bool_schema()]
+    try:
+        set_schema_cache_size(2)
+        validators = [SchemaValidator.from_schema_cached(s) for s in schemas]
+        # the least recently used validator was evicted
+        assert SchemaValidator.from_schema_cached(schemas[0]) is not validators[0]
+        assert SchemaValidator.from_schema_cached(schemas[2]) is validators[2]
+
+        set_schema_cache_size(0)
+        assert SchemaValidator.from_schema_cached(schemas[2]) is not validators[2]
+        v = SchemaValidator.from_schema_cached(schemas[2])
+        assert SchemaValidator.from_schema_cached(schemas[2]) is not v
+    finally:
+        set_schema_cache_size(128)
+
+
+def test_from_schema_cached_error():
+    schema = {'type': 'str', 'pattern': '('}
+    for _ in range(2):
+        with pytest.raises(SchemaError, match='regex parse error'):
+            SchemaValidator.from_schema_cached(schema)
+
+
+def test_rebuild():
+    v = SchemaValidator(cs.int_schema(), cs.CoreConfig(strict=True))
+    assert v.title == 'int'
+    v.rebuild(cs.str_schema())
+    assert v.title == 'str'
+    assert v.validate_python('foo') == 'foo'
+    # the config is kept
+    with pytest.raises(ValidationError, match=r'Input should be a valid string \[type=string_type'):
+        v.validate_python(b'foo')
+    # the new schema is used for pickling
+    assert pickle.loads(pickle.dumps(v)).validate_python('foo') == 'foo'
+
+
+def test_rebuild_original_schema():
+    schema = cs.typed_dict_schema({'x': cs.typed_dict_field(cs.any_schema())})
+    v = SchemaValidator(schema)
+    assert v.validate_python({'x': '1'}) == {'x': '1'}
+    # e.g. a forward reference has been resolved since the validator was built
+    schema['fields']['x']['schema'] = cs.int_schema()
+    v.rebuild()
+    assert v.validate_python({'x': '1'}) == {'x': 1}
+
+
+def test_rebuild_cached():
+    v = SchemaValidator.from_schema_cached(cs.int_schema())
+    with pytest.raises(TypeError, match="Validators created by `from_schema_cached` are shared, so can't be rebuilt"):
+        v.rebuild(cs.str_schema())
+    assert v.validate_python('1') == 1
+
+
+def test_rebuild_error():
+    v = SchemaValidator(cs.int_schema())
+    with pytest.raises(SchemaError, match='regex parse error'):
+        v.rebuild({'type': 'str', 'pattern': '('})
+    # the validator is unchanged
+    assert v.validate_python('1') == 1
+
+
+def test_definitions_introspection():
+    v = SchemaValidator(
+        cs.definitions_schema(
+            cs.list_schema(cs.definition_reference_schema('int-ref')),
+            [cs.int_schema(ref='int-ref'), cs.str_schema(ref='str-ref')],
+        )
+    )
+    assert v.definition_refs() == ['int-ref', 'str-ref']
+    assert 'IntValidator' in v.get_definition('int-ref')
+    with pytest.raises(KeyError, match='missing'):
+        v.get_definition('missing')
+
+    assert SchemaValidator(cs.int_schema()).definition_refs() == []
+
+
+def test_any_wrapper_schema():
+    schema = cs.any_schema(schema=cs.list_schema(cs.int_schema()), metadata={'source': 'annotation'})
+    v = SchemaValidator(schema)
+    assert v.validate_python(['1', 2]) == [1, 2]
+    assert v.validate_json('[1, "2"]') == [1, 2]
+    with pytest.raises(ValidationError, match='Input should be a valid list'):
+        v.validate_python('foo')
+    assert 'ListValidator' in repr(v)
+
+    nested = SchemaValidator(cs.any_schema(schema=cs.any_schema(schema=cs.int_schema())))
+    assert nested.validate_python('3') == 3
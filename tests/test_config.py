@@ -139,6 +139,35 @@ def test_hide_input_in_errors(config, input_str):
         assert v.validate_python({'f': 123})
 
 
+def test_hide_input_in_errors_model_config():
+    v = SchemaValidator(
+        {
+            'type': 'model',
+            'cls': MyModel,
+            'schema': {'type': 'model-fields', 'fields': {'f': {'type': 'model-field', 'schema': {'type': 'str'}}}},
+            'config': {'hide_input_in_errors': True},
+        }
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'f': 123})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': ('f',), 'msg': 'Input should be a valid string', 'input': None}
+    ]
+    assert '123' not in str(exc_info.value)
+    assert 'input_value' not in str(exc_info.value)
+    assert '"input":null' in exc_info.value.json()
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"f": 123}')
+    assert exc_info.value.errors(include_url=False)[0]['input'] is None
+
+    m = v.validate_python({'f': 'x'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'f', 123)
+    assert exc_info.value.errors(include_url=False)[0]['input'] is None
+
+
 def test_cache_strings():
     v = SchemaValidator({'type': 'str'})
     assert 'cache_strings=True' in plain_repr(v)
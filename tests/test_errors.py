@@ -257,12 +257,27 @@ all_errors = [
     ('no_such_attribute', "Object has no attribute 'wrong_name'", {'attribute': 'wrong_name'}),
     ('json_invalid', 'Invalid JSON: foobar', {'error': 'foobar'}),
     ('json_type', 'JSON input should be string, bytes or bytearray', None),
+    ('json_scalar_type', 'Input should be a JSON string, number, boolean or null', None),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
     ('model_type', 'Input should be a valid dictionary or instance of Foobar', {'class_name': 'Foobar'}),
     ('model_attributes_type', 'Input should be a valid dictionary or object to extract fields from', None),
     ('dataclass_exact_type', 'Input should be an instance of Foobar', {'class_name': 'Foobar'}),
     ('dataclass_type', 'Input should be a dictionary or an instance of Foobar', {'class_name': 'Foobar'}),
     ('missing', 'Field required', None),
+    (
+        'missing_conditional_field',
+        "Field required when 'type' is 'company'",
+        {'condition_field': 'type', 'condition_value': "'company'"},
+    ),
+    ('ambiguous_field', "Field 'b' and its old name 'a' have different values", {'field_name': 'b', 'old_name': 'a'}),
+    ('exactly_one_of', "Exactly one of the fields 'a', 'b' should be provided", {'fields': "'a', 'b'"}),
+    ('at_least_one_of', "At least one of the fields 'a', 'b' should be provided", {'fields': "'a', 'b'"}),
+    ('at_most_one_of', "At most one of the fields 'a', 'b' should be provided", {'fields': "'a', 'b'"}),
+    (
+        'field_ordering_violation',
+        "Field 'end' should be greater than or equal to field 'start'",
+        {'field': 'end', 'operator': '>=', 'other_field': 'start'},
+    ),
     ('frozen_field', 'Field is frozen', None),
     ('frozen_instance', 'Instance is frozen', None),
     ('extra_forbidden', 'Extra inputs are not permitted', None),
@@ -287,27 +302,50 @@ all_errors = [
         'Foobar should have at most 42 items after validation, not 50',
         {'field_type': 'Foobar', 'max_length': 42, 'actual_length': 50},
     ),
+    (
+        'exact_length',
+        'Foobar should have exactly 2 items after validation, not 3',
+        {'field_type': 'Foobar', 'exact_length': 2, 'actual_length': 3},
+    ),
     ('string_type', 'Input should be a valid string', None),
+    ('string_type_exact', 'Input should be an exact string, not a subclass of str', None),
     ('string_sub_type', 'Input should be a string, not an instance of a subclass of str', None),
     ('string_unicode', 'Input should be a valid string, unable to parse raw data as a unicode string', None),
     ('string_pattern_mismatch', "String should match pattern 'foo'", {'pattern': 'foo'}),
+    ('string_pattern_match', "String should not match pattern 'foo'", {'pattern': 'foo'}),
+    ('string_format_mismatch', "String should match the 'email' format", {'format': 'email'}),
+    ('string_not_hostname', "String should be a valid hostname, 'a_b' is invalid", {'label': 'a_b'}),
+    ('string_not_idna', "String should be a valid internationalized domain name, 'a_b' is invalid", {'label': 'a_b'}),
+    ('string_starts_with', "String should start with 'foo'", {'prefix': 'foo'}),
+    ('string_starts_with', "String should start with 'foo' or 'bar'", {'prefix': ['foo', 'bar']}),
+    ('string_ends_with', "String should end with 'foo'", {'suffix': 'foo'}),
     ('string_too_short', 'String should have at least 42 characters', {'min_length': 42}),
     ('string_too_short', 'String should have at least 1 character', {'min_length': 1}),
     ('string_too_long', 'String should have at most 42 characters', {'max_length': 42}),
     ('string_too_long', 'String should have at most 1 character', {'max_length': 1}),
+    ('string_too_long_bytes', 'String should have at most 42 bytes', {'max_length': 42}),
+    ('string_too_long_codepoints', 'String should have at most 42 codepoints', {'max_length': 42}),
     ('dict_type', 'Input should be a valid dictionary', None),
+    ('dict_type_exact', 'Input should be an exact dictionary, not a subclass of dict', None),
     ('mapping_type', 'Input should be a valid mapping, error: foobar', {'error': 'foobar'}),
+    ('dict_forbidden_key', "Dictionary should not contain the key 'foo'", {'key_name': 'foo'}),
+    ('dict_missing_key', "Dictionary should contain the key 'foo'", {'key_name': 'foo'}),
+    ('dict_pairs_invalid', 'Item 1 should be a key-value pair with exactly 2 items', {'index': 1}),
+    ('dict_duplicate_key', "Dictionary should not contain the key 'foo' more than once", {'key_name': 'foo'}),
     ('iterable_type', 'Input should be iterable', None),
     ('iteration_error', 'Error iterating over object, error: foobar', {'error': 'foobar'}),
     ('list_type', 'Input should be a valid list', None),
     ('tuple_type', 'Input should be a valid tuple', None),
     ('set_type', 'Input should be a valid set', None),
+    ('set_item_unhashable', 'Set items should be hashable, got an instance of list', {'type_name': 'list'}),
     ('bool_type', 'Input should be a valid boolean', None),
     ('bool_parsing', 'Input should be a valid boolean, unable to interpret input', None),
     ('int_type', 'Input should be a valid integer', None),
     ('int_parsing', 'Input should be a valid integer, unable to parse string as an integer', None),
     ('int_parsing_size', 'Unable to parse input string as an integer, exceeded maximum size', None),
     ('int_from_float', 'Input should be a valid integer, got a number with a fractional part', None),
+    ('int_from_bool_not_allowed', 'Input should be a valid integer, boolean values are not allowed', None),
+    ('int_overflow', 'Input should fit in an unsigned 32 bit integer', {'bit_width': 32, 'unsigned': True}),
     ('multiple_of', 'Input should be a multiple of 42.1', {'multiple_of': 42.1}),
     ('greater_than', 'Input should be greater than 42.1', {'gt': 42.1}),
     ('greater_than_equal', 'Input should be greater than or equal to 42.1', {'ge': 42.1}),
@@ -315,11 +353,23 @@ all_errors = [
     ('less_than_equal', 'Input should be less than or equal to 42.1', {'le': 42.1}),
     ('float_type', 'Input should be a valid number', None),
     ('float_parsing', 'Input should be a valid number, unable to parse string as a number', None),
+    (
+        'float_from_decimal_not_allowed',
+        'Input should be a valid number, Decimal values may not be coerced to float',
+        None,
+    ),
     ('bytes_type', 'Input should be a valid bytes', None),
     ('bytes_too_short', 'Data should have at least 42 bytes', {'min_length': 42}),
     ('bytes_too_short', 'Data should have at least 1 byte', {'min_length': 1}),
     ('bytes_too_long', 'Data should have at most 42 bytes', {'max_length': 42}),
     ('bytes_too_long', 'Data should have at most 1 byte', {'max_length': 1}),
+    ('bytes_too_short_chars', 'Data should have at least 2 characters', {'min_length': 2}),
+    ('bytes_too_long_chars', 'Data should have at most 1 character', {'max_length': 1}),
+    (
+        'bytes_invalid_encoding',
+        'Data should be valid base64: Invalid padding',
+        {'encoding': 'base64', 'encoding_error': 'Invalid padding'},
+    ),
     ('value_error', 'Value error, foobar', {'error': ValueError('foobar')}),
     ('assertion_error', 'Assertion failed, foobar', {'error': AssertionError('foobar')}),
     ('literal_error', 'Input should be foo', {'expected': 'foo'}),
@@ -338,6 +388,8 @@ all_errors = [
     ('datetime_object_invalid', 'Invalid datetime object, got foobar', {'error': 'foobar'}),
     ('datetime_past', 'Input should be in the past', None),
     ('datetime_future', 'Input should be in the future', None),
+    ('datetime_sub_microsecond', 'Input should not have sub-microsecond precision', None),
+    ('datetime_from_timestamp_overflow', 'Timestamp is out of range for a datetime', None),
     ('timezone_naive', 'Input should not have timezone info', None),
     ('timezone_aware', 'Input should have timezone info', None),
     ('timezone_offset', 'Timezone offset of 0 required, got 60', {'tz_expected': 0, 'tz_actual': 60}),
@@ -347,6 +399,11 @@ all_errors = [
     ('is_instance_of', 'Input should be an instance of Foo', {'class': 'Foo'}),
     ('is_subclass_of', 'Input should be a subclass of Foo', {'class': 'Foo'}),
     ('callable_type', 'Input should be callable', None),
+    (
+        'callable_arity_mismatch',
+        'Callable should accept 2 positional arguments, but it accepts 1',
+        {'expected_min': 2, 'expected_max': 2, 'got': '1'},
+    ),
     (
         'union_tag_invalid',
         "Input tag 'foo' found using bar does not match any of the expected tags: baz",
@@ -465,8 +522,8 @@ def test_all_errors():
     errors = list_all_errors()
     # print(f'{len(errors)=}')
     assert len(errors) == len({e['type'] for e in errors}), 'error types are not unique'
-    # insert_assert(errors[:4])
-    assert errors[:4] == [
+    # insert_assert(errors[:5])
+    assert errors[:5] == [
         {
             'type': 'no_such_attribute',
             'message_template_python': "Object has no attribute '{attribute}'",
@@ -485,6 +542,12 @@ def test_all_errors():
             'example_message_python': 'JSON input should be string, bytes or bytearray',
             'example_context': None,
         },
+        {
+            'type': 'json_scalar_type',
+            'message_template_python': 'Input should be a JSON string, number, boolean or null',
+            'example_message_python': 'Input should be a JSON string, number, boolean or null',
+            'example_context': None,
+        },
         {
             'type': 'recursion_loop',
             'message_template_python': 'Recursion error - cyclic reference detected',
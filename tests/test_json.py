@@ -176,6 +176,35 @@ def test_dict_any_value():
     assert v.validate_json('{"1": 1, "2": "a", "3": null}') == {'1': 1, '2': 'a', '3': None}
 
 
+def test_any_value():
+    v = SchemaValidator({'type': 'any'})
+    output = v.validate_json('{"a": [1, 2.5, "b", null, true, false], "c": {"d": []}}')
+    assert output == {'a': [1, 2.5, 'b', None, True, False], 'c': {'d': []}}
+    assert type(output) is dict
+    assert type(output['a']) is list
+    assert output['a'][3] is None
+    assert output['a'][4] is True
+
+
+def test_any_json_scalars_only():
+    v = SchemaValidator(core_schema.any_schema(json_scalars_only=True))
+    for value in ('"foo"', '1', '2.5', 'true', 'null'):
+        assert v.validate_json(value) == json.loads(value)
+    for value in ('[1, 2]', '{"a": 1}'):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_json(value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'json_scalar_type',
+                'loc': (),
+                'msg': 'Input should be a JSON string, number, boolean or null',
+                'input': json.loads(value),
+            }
+        ]
+    # python inputs aren't restricted
+    assert v.validate_python([1, 2]) == [1, 2]
+
+
 def test_json_invalid():
     v = SchemaValidator({'type': 'bool'})
 
@@ -203,6 +232,38 @@ def test_json_invalid():
     ]
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'\xef\xbb\xbf{"a": 1}', {'a': 1}),
+        ('\ufeff{"a": 1}', {'a': 1}),
+        (bytearray(b'\xef\xbb\xbf{"a": 1}'), {'a': 1}),
+        (b'{"a": 1}', {'a': 1}),
+        ('{"a": 1}', {'a': 1}),
+    ],
+)
+def test_strip_json_bom(input_value, expected):
+    v = SchemaValidator(core_schema.dict_schema(), core_schema.CoreConfig(strip_json_bom=True))
+    assert v.validate_json(input_value) == expected
+
+
+@pytest.mark.parametrize('input_value', [b'\xef\xbb\xbf', '\ufeff', b'\xef\xbb\xbf\xef\xbb\xbf{}', '\ufeff\ufeff{}'])
+def test_strip_json_bom_invalid(input_value):
+    v = SchemaValidator(core_schema.dict_schema(), core_schema.CoreConfig(strip_json_bom=True))
+    # a BOM on its own is stripped leaving no JSON, and only the first of multiple BOMs is stripped
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'json_invalid'
+
+
+@pytest.mark.parametrize('input_value', [b'\xef\xbb\xbf{"a": 1}', '\ufeff{"a": 1}'])
+def test_json_bom_not_stripped(input_value):
+    v = SchemaValidator(core_schema.dict_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'json_invalid'
+
+
 class Foobar:
     def __str__(self):
         return 'Foobar.__str__'
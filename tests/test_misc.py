@@ -5,7 +5,7 @@ import re
 import pytest
 from typing_extensions import get_args
 
-from pydantic_core import CoreSchema, CoreSchemaType, PydanticUndefined, core_schema
+from pydantic_core import CoreSchema, CoreSchemaType, PydanticDropField, PydanticUndefined, core_schema
 from pydantic_core._pydantic_core import (
     SchemaError,
     SchemaValidator,
@@ -190,6 +190,16 @@ def test_undefined():
     assert pickle.loads(pickle.dumps(PydanticUndefined)) is PydanticUndefined
 
 
+def test_drop_field():
+    with pytest.raises(NotImplementedError, match='DropFieldType'):
+        PydanticDropField.__class__()
+
+    assert repr(PydanticDropField) == 'PydanticDropField'
+    assert copy.copy(PydanticDropField) is PydanticDropField
+    assert copy.deepcopy(PydanticDropField) is PydanticDropField
+    assert pickle.loads(pickle.dumps(PydanticDropField)) is PydanticDropField
+
+
 def test_unicode_error_input_repr() -> None:
     """https://github.com/pydantic/pydantic/issues/6448"""
 
@@ -32,6 +32,9 @@ class MyEnum(int, Enum):
     b = 2
 
 
+int_validator = SchemaValidator(core_schema.int_schema())
+
+
 def ids_function(val):
     if callable(val):
         return val.__name__
@@ -268,6 +271,11 @@ all_schema_functions = [
         },
     ),
     (core_schema.definition_reference_schema, args('foo'), {'type': 'definition-ref', 'schema_ref': 'foo'}),
+    (
+        core_schema.external_reference_schema,
+        args(int_validator),
+        {'type': 'external-ref', 'schema_ref': int_validator},
+    ),
     (
         core_schema.dataclass_args_schema,
         args('Foo', [{'name': 'foo', 'type': 'dataclass-field', 'schema': {'type': 'int'}}]),
@@ -1,9 +1,17 @@
+import array
 import re
 from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import (
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    validate_core_schema,
+)
 
 from ..conftest import Err, PyAndJson
 
@@ -121,3 +129,136 @@ def test_length_ctx():
             'ctx': {'max_length': 3},
         }
     ]
+
+
+@pytest.mark.parametrize(
+    'opts,input,expected',
+    [
+        ({}, '"Zm9vYmFy"', b'Zm9vYmFy'),
+        ({'json_format': 'raw'}, '"Zm9vYmFy"', b'Zm9vYmFy'),
+        ({'json_format': 'base64'}, '"Zm9vYmFy"', b'foobar'),
+        ({'json_format': 'base64'}, '"Zm9vYg=="', b'foob'),
+        # both the standard and URL-safe alphabets are accepted
+        ({'json_format': 'base64'}, '"+/8="', b'\xfb\xff'),
+        ({'json_format': 'base64'}, '"-_8="', b'\xfb\xff'),
+        ({'json_format': 'base64'}, '"Zm9vYg"', Err('Data should be valid base64: ')),
+        ({'json_format': 'base64'}, '"Zm9v!mFy"', Err('Data should be valid base64: ')),
+        ({'json_format': 'hex'}, '"666f6f"', b'foo'),
+        ({'json_format': 'hex'}, '"666F6F"', b'foo'),
+        ({'json_format': 'hex'}, '"666f6"', Err('Data should be valid hex: Odd number of digits')),
+        ({'json_format': 'hex'}, '"666x6f"', Err('Data should be valid hex: Invalid hex digits at offset 2')),
+        ({'json_format': 'base64', 'max_length': 5}, '"Zm9vYmFy"', Err('Data should have at most 5 bytes')),
+        ({'json_format': 'hex', 'min_length': 3}, '"666f6f"', b'foo'),
+    ],
+)
+def test_json_format(opts: Dict[str, Any], input, expected):
+    v = SchemaValidator(core_schema.bytes_schema(**opts))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_json(input)
+    else:
+        assert v.validate_json(input) == expected
+
+
+def test_json_format_python():
+    # only JSON input is decoded
+    v = SchemaValidator(core_schema.bytes_schema(json_format='base64'))
+    assert v.validate_python('Zm9vYmFy') == b'Zm9vYmFy'
+    assert v.validate_python(b'Zm9vYmFy') == b'Zm9vYmFy'
+
+
+def test_json_format_errors():
+    v = SchemaValidator(core_schema.bytes_schema(json_format='hex'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"6"')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_invalid_encoding',
+            'loc': (),
+            'msg': 'Data should be valid hex: Odd number of digits',
+            'input': '6',
+            'ctx': {'encoding': 'hex', 'encoding_error': 'Odd number of digits'},
+        }
+    ]
+
+
+def test_json_format_round_trip():
+    s = SchemaSerializer(core_schema.bytes_schema(), {'ser_json_bytes': 'base64'})
+    v = SchemaValidator(core_schema.bytes_schema(json_format='base64'))
+    data = bytes(range(256))
+    assert v.validate_json(s.to_json(data)) == data
+
+
+def test_invalid_json_format():
+    with pytest.raises(SchemaError, match="Input should be 'raw', 'base64' or 'hex'"):
+        validate_core_schema({'type': 'bytes', 'json_format': 'foobar'})
+
+
+def test_length_chars():
+    v = SchemaValidator(core_schema.bytes_schema(min_length_chars=2, max_length_chars=3))
+    assert v.validate_python('éé'.encode()) == 'éé'.encode()
+    assert v.validate_python('ééé'.encode()) == 'ééé'.encode()
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('é'.encode())
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_too_short_chars',
+            'loc': (),
+            'msg': 'Data should have at least 2 characters',
+            'input': 'é'.encode(),
+            'ctx': {'min_length': 2},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('éééé'.encode())
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_too_long_chars',
+            'loc': (),
+            'msg': 'Data should have at most 3 characters',
+            'input': 'éééé'.encode(),
+            'ctx': {'max_length': 3},
+        }
+    ]
+
+
+def test_length_chars_invalid_utf8():
+    v = SchemaValidator(core_schema.bytes_schema(max_length_chars=3))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'\xff')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_invalid_encoding',
+            'loc': (),
+            'msg': 'Data should be valid utf-8: invalid utf-8 sequence of 1 bytes from index 0',
+            'input': b'\xff',
+            'ctx': {'encoding': 'utf-8', 'encoding_error': 'invalid utf-8 sequence of 1 bytes from index 0'},
+        }
+    ]
+
+
+@pytest.mark.parametrize('strict', [True, False])
+def test_allow_buffer_protocol(strict: bool):
+    v = SchemaValidator(core_schema.bytes_schema(strict=strict, allow_buffer_protocol=True))
+    assert v.validate_python(b'abc') == b'abc'
+    assert v.validate_python(memoryview(b'abc')) == b'abc'
+    assert v.validate_python(memoryview(b'abcdef')[1:3]) == b'bc'
+    assert v.validate_python(array.array('B', [1, 2, 3])) == b'\x01\x02\x03'
+    assert v.validate_python(array.array('H', [1])) == array.array('H', [1]).tobytes()
+    with pytest.raises(ValidationError, match=r'Input should be a valid bytes \[type=bytes_type'):
+        v.validate_python(123)
+
+
+def test_allow_buffer_protocol_default():
+    v = SchemaValidator(core_schema.bytes_schema(strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be a valid bytes \[type=bytes_type'):
+        v.validate_python(memoryview(b'abc'))
+
+
+def test_allow_buffer_protocol_constrained():
+    v = SchemaValidator(core_schema.bytes_schema(strict=True, allow_buffer_protocol=True, max_length=2))
+    assert v.validate_python(memoryview(b'ab')) == b'ab'
+    with pytest.raises(ValidationError, match='Data should have at most 2 bytes'):
+        v.validate_python(array.array('B', [1, 2, 3]))
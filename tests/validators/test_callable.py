@@ -1,6 +1,8 @@
+import inspect
+
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 
 def func():
@@ -59,3 +61,80 @@ def test_repr():
 
     with pytest.raises(ValidationError, match=r'callable\s+Input should be callable'):
         v.validate_python('foo')
+
+
+def two_args(a, b):
+    pass
+
+
+def one_or_two_args(a, b=None):
+    pass
+
+
+def var_args(a, *args):
+    pass
+
+
+def keyword_only(a, *, b):
+    pass
+
+
+@pytest.mark.parametrize(
+    'min_args,max_args,input_value,error',
+    [
+        (2, 2, two_args, None),
+        (1, 1, two_args, 'Callable should accept 1 positional argument, but it accepts 2'),
+        (3, 3, two_args, 'Callable should accept 3 positional arguments, but it accepts 2'),
+        (1, 2, one_or_two_args, None),
+        (0, 2, one_or_two_args, 'Callable should accept 0 to 2 positional arguments, but it accepts 1 to 2'),
+        (1, 3, one_or_two_args, 'Callable should accept 1 to 3 positional arguments, but it accepts 1 to 2'),
+        (1, 10, var_args, None),
+        (None, 10, var_args, None),
+        (0, None, var_args, 'Callable should accept 0 positional arguments, but it accepts 1 or more'),
+        (1, 1, keyword_only, None),
+        (1, 1, lambda a: a, None),
+        (1, 1, Foo, 'Callable should accept 1 positional argument, but it accepts 0'),
+        (1, 1, CallableClass(), None),
+        (1, 1, print, None),
+    ],
+)
+def test_callable_arity(min_args, max_args, input_value, error):
+    v = SchemaValidator(core_schema.callable_schema(min_args=min_args, max_args=max_args))
+    if error is None:
+        assert v.validate_python(input_value) is input_value
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['type'] == 'callable_arity_mismatch'
+        assert exc_info.value.errors(include_url=False)[0]['msg'] == error
+
+
+def test_callable_arity_ctx():
+    v = SchemaValidator(core_schema.callable_schema(min_args=2))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(lambda a, b, c: None)
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {
+        'expected_min': 2,
+        'expected_max': None,
+        'got': '3',
+    }
+
+
+def test_callable_arity_cached():
+    def one_arg(a):
+        pass
+
+    v = SchemaValidator(core_schema.callable_schema(min_args=1, max_args=1))
+    assert v.validate_python(one_arg) is one_arg
+    # the arity is cached, so changing the signature after the first validation has no effect
+    one_arg.__signature__ = inspect.signature(two_args)
+    assert v.validate_python(one_arg) is one_arg
+
+    v = SchemaValidator(core_schema.callable_schema(min_args=1, max_args=1))
+    with pytest.raises(ValidationError, match='but it accepts 2'):
+        v.validate_python(one_arg)
+
+
+def test_callable_arity_invalid_schema():
+    with pytest.raises(SchemaError, match=r'`min_args` \(2\) must not be greater than `max_args` \(1\)'):
+        SchemaValidator(core_schema.callable_schema(min_args=2, max_args=1))
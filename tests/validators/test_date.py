@@ -210,8 +210,40 @@ def test_date_kwargs(kwargs: dict[str, Any], input_value: date, expected: Err |
 
 
 def test_invalid_constraint():
-    with pytest.raises(SchemaError, match=r'date\.gt\n  Input should be a valid date or datetime'):
-        validate_core_schema({'type': 'date', 'gt': 'foobar'})
+    with pytest.raises(SchemaError, match='Invalid `gt` constraint, input is too short'):
+        SchemaValidator({'type': 'date', 'gt': 'foobar'})
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'ge': '2000-01-01', 'le': '2000-12-31'}, '2000-06-15', date(2000, 6, 15)),
+        (
+            {'ge': '2000-01-01', 'le': '2000-12-31'},
+            date(1999, 12, 31),
+            Err('Input should be greater than or equal to 2000-01-01 [type=greater_than_equal,'),
+        ),
+        (
+            {'ge': '2000-01-01', 'le': '2000-12-31'},
+            '2001-01-01',
+            Err('Input should be less than or equal to 2000-12-31 [type=less_than_equal,'),
+        ),
+        ({'lt': '2000-01-01'}, '1999-12-31', date(1999, 12, 31)),
+        ({'gt': '2000-01-01'}, '2000-01-01', Err('Input should be greater than 2000-01-01 [type=greater_than,')),
+    ],
+)
+def test_iso_date_constraints(kwargs: dict[str, Any], input_value, expected):
+    v = SchemaValidator({'type': 'date', **kwargs})  # type: ignore
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_iso_date_constraint_invalid():
+    with pytest.raises(SchemaError, match='Invalid `gt` constraint, input is too short'):
+        SchemaValidator({'type': 'date', 'gt': 'foobar'})
 
 
 def test_dict_py():
@@ -512,3 +512,99 @@ def test_tz_cmp() -> None:
 
     assert validated1 > validated2
     assert validated2 < validated1
+
+
+@pytest.mark.parametrize(
+    'microseconds_precision,input_value,expected',
+    [
+        ('truncate', '2022-06-08T12:13:14.1234567', datetime(2022, 6, 8, 12, 13, 14, 123456)),
+        ('truncate', '2022-06-08T12:13:14.1234564', datetime(2022, 6, 8, 12, 13, 14, 123456)),
+        ('round', '2022-06-08T12:13:14.1234567', datetime(2022, 6, 8, 12, 13, 14, 123457)),
+        ('round', '2022-06-08T12:13:14.1234564', datetime(2022, 6, 8, 12, 13, 14, 123456)),
+        ('round', '2022-06-08T12:13:14.123456', datetime(2022, 6, 8, 12, 13, 14, 123456)),
+        ('round', '2022-06-08T23:59:59.9999995', datetime(2022, 6, 9)),
+        (
+            'round',
+            '2022-06-08T12:13:14.12345651+05:30',
+            datetime(2022, 6, 8, 12, 13, 14, 123457, tzinfo=timezone(timedelta(hours=5, minutes=30))),
+        ),
+        ('error', '2022-06-08T12:13:14.123456', datetime(2022, 6, 8, 12, 13, 14, 123456)),
+        (
+            'error',
+            '2022-06-08T12:13:14.1234567',
+            Err('Input should not have sub-microsecond precision [type=datetime_sub_microsecond,'),
+        ),
+    ],
+)
+def test_microseconds_precision(py_and_json: PyAndJson, microseconds_precision, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(microseconds_precision=microseconds_precision))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+
+
+def test_microseconds_precision_round_datetime_object():
+    v = SchemaValidator(core_schema.datetime_schema(microseconds_precision='round'))
+    dt = datetime(2022, 6, 8, 12, 13, 14, 123456)
+    assert v.validate_python(dt) is dt
+
+
+def test_microseconds_precision_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'truncate', 'round' or 'error'"):
+        validate_core_schema(core_schema.datetime_schema(microseconds_precision='foobar'))
+
+
+@pytest.mark.parametrize(
+    'timestamp_unit,input_value,expected',
+    [
+        ('seconds', 1654646400, datetime(2022, 6, 8, tzinfo=timezone.utc)),
+        ('seconds', 1654646400.5, datetime(2022, 6, 8, 0, 0, 0, 500000, tzinfo=timezone.utc)),
+        ('seconds', -1, datetime(1969, 12, 31, 23, 59, 59, tzinfo=timezone.utc)),
+        ('seconds', 30_000_000_000, datetime(2920, 8, 30, 5, 20, tzinfo=timezone.utc)),
+        ('milliseconds', 1654646400123, datetime(2022, 6, 8, 0, 0, 0, 123000, tzinfo=timezone.utc)),
+        ('milliseconds', 1000, datetime(1970, 1, 1, 0, 0, 1, tzinfo=timezone.utc)),
+        ('microseconds', 1654646400123456, datetime(2022, 6, 8, 0, 0, 0, 123456, tzinfo=timezone.utc)),
+        ('microseconds', -1, datetime(1969, 12, 31, 23, 59, 59, 999999, tzinfo=timezone.utc)),
+        ('milliseconds', '1000', datetime(1970, 1, 1, 0, 0, 1, tzinfo=timezone.utc)),
+        ('milliseconds', '1654646400123.5', datetime(2022, 6, 8, 0, 0, 0, 123500, tzinfo=timezone.utc)),
+        ('microseconds', '-1', datetime(1969, 12, 31, 23, 59, 59, 999999, tzinfo=timezone.utc)),
+        ('seconds', '1654646400', datetime(2022, 6, 8, tzinfo=timezone.utc)),
+        ('seconds', '2022-06-08T12:13:14', datetime(2022, 6, 8, 12, 13, 14)),
+        (
+            'seconds',
+            253_402_300_800,
+            Err('Timestamp is out of range for a datetime [type=datetime_from_timestamp_overflow,'),
+        ),
+        (
+            'milliseconds',
+            -11_676_096_000_001,
+            Err('Timestamp is out of range for a datetime [type=datetime_from_timestamp_overflow,'),
+        ),
+        ('seconds', 10**30, Err('Timestamp is out of range for a datetime [type=datetime_from_timestamp_overflow,')),
+        ('seconds', 1e30, Err('Timestamp is out of range for a datetime [type=datetime_from_timestamp_overflow,')),
+    ],
+)
+def test_timestamp_unit(py_and_json: PyAndJson, timestamp_unit, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(timestamp_unit=timestamp_unit))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+
+
+def test_timestamp_unit_strict():
+    v = SchemaValidator(core_schema.datetime_schema(timestamp_unit='milliseconds', strict=True))
+    with pytest.raises(ValidationError, match='Input should be a valid datetime'):
+        v.validate_python(1654646400123)
+    with pytest.raises(ValidationError, match='Input should be a valid datetime'):
+        v.validate_python(True, strict=False)
+
+
+def test_timestamp_unit_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'seconds', 'milliseconds' or 'microseconds'"):
+        validate_core_schema(core_schema.datetime_schema(timestamp_unit='days'))
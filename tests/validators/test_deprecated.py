@@ -0,0 +1,144 @@
+import warnings
+
+import pytest
+from dirty_equals import HasRepr
+
+from pydantic_core import (
+    ArgsKwargs,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    ValidationWarning,
+    core_schema,
+    validate_core_schema,
+)
+
+
+def test_deprecated_message():
+    v = SchemaValidator({**core_schema.int_schema(), 'deprecated': 'Use `y` instead'})
+    with pytest.warns(DeprecationWarning, match='^Use `y` instead$'):
+        assert v.validate_python('1') == 1
+
+
+def test_deprecated_default_message():
+    v = SchemaValidator({**core_schema.int_schema(), 'deprecated': True, 'deprecated_since': '2.7'})
+    with pytest.warns(DeprecationWarning, match=r'^`int` is deprecated \(deprecated since 2\.7\)$'):
+        assert v.validate_json('1') == 1
+
+
+def test_not_deprecated():
+    v = SchemaValidator({**core_schema.int_schema(), 'deprecated': False})
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert v.validate_python(1) == 1
+
+
+def test_deprecated_field():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                'y': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        {**core_schema.str_schema(), 'deprecated': '`y` is deprecated'}, default=None
+                    )
+                ),
+            }
+        )
+    )
+    # the warning is only emitted when the deprecated field is validated
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert v.validate_python({'x': 1}) == {'x': 1, 'y': None}
+
+    with pytest.warns(DeprecationWarning, match='`y` is deprecated'):
+        assert v.validate_python({'x': 1, 'y': 'a'}) == {'x': 1, 'y': 'a'}
+
+
+def test_deprecated_warning_as_error():
+    v = SchemaValidator({**core_schema.int_schema(), 'deprecated': 'old'})
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        with pytest.raises(DeprecationWarning, match='old'):
+            v.validate_python(1)
+
+
+def test_deprecated_errors():
+    v = SchemaValidator({**core_schema.int_schema(), 'deprecated': True})
+    with pytest.warns(DeprecationWarning):
+        with pytest.raises(ValidationError, match='Input should be a valid integer'):
+            v.validate_python('x')
+
+
+def test_deprecated_invalid():
+    with pytest.raises(SchemaError, match='`deprecated` must be a bool or a str'):
+        SchemaValidator({**core_schema.int_schema(), 'deprecated': 1})
+
+    with pytest.raises(SchemaError, match='Input should be a valid boolean'):
+        validate_core_schema({**core_schema.int_schema(), 'deprecated': [1]})
+
+
+def test_collect_warnings():
+    schema = core_schema.typed_dict_schema(
+        {
+            'x': core_schema.typed_dict_field(core_schema.int_schema()),
+            'y': core_schema.typed_dict_field({**core_schema.str_schema(), 'deprecated': '`y` is deprecated'}),
+        }
+    )
+    v = SchemaValidator(schema, {'collect_warnings': True})
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        value, collected = v.validate_python({'x': 1, 'y': 'a'})
+        assert v.validate_json('{"x": 1, "y": "b"}')[0] == {'x': 1, 'y': 'b'}
+    assert value == {'x': 1, 'y': 'a'}
+    assert len(collected) == 1
+    warning = collected[0]
+    assert isinstance(warning, ValidationWarning)
+    assert (warning.type, warning.loc, warning.msg, warning.input) == ('deprecated', ('y',), '`y` is deprecated', 'a')
+    assert repr(warning) == "ValidationWarning(type='deprecated', loc=('y',), msg='`y` is deprecated', input='a')"
+
+    v = SchemaValidator({**core_schema.int_schema(), 'deprecated': 'old'}, {'collect_warnings': True})
+    assert v.validate_python(1) == (1, [HasRepr("ValidationWarning(type='deprecated', loc=(), msg='old', input=1)")])
+
+    # errors are still raised as usual
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('x')
+
+
+def test_deprecated_with_default():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter(
+                    'a', core_schema.with_default_schema(core_schema.int_schema(), default=1)
+                ),
+                core_schema.arguments_parameter(
+                    'b', core_schema.with_default_schema(core_schema.int_schema(), default=2, deprecated=True)
+                ),
+            ]
+        )
+    )
+    assert v.validate_python(ArgsKwargs(())) == ((), {'a': 1, 'b': 2})
+    with pytest.warns(DeprecationWarning):
+        assert v.validate_python(ArgsKwargs((1, 3))) == ((1, 3), {})
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=1, deprecated='old')
+                )
+            }
+        )
+    )
+    x = v.get_model_fields()['x']
+    assert (x.has_default, x.default) == (True, 1)
+
+
+def test_deprecated_omit_on_error_required():
+    schema = core_schema.with_default_schema(core_schema.int_schema(), on_error='omit', deprecated=True)
+    with pytest.raises(SchemaError, match="'on_error = omit' cannot be set for required fields"):
+        SchemaValidator(core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(schema, required=True)}))
+
+    with pytest.raises(SchemaError, match='omit_on_error cannot be used with arguments'):
+        SchemaValidator(core_schema.dataclass_args_schema('MyDataclass', [core_schema.dataclass_field('x', schema)]))
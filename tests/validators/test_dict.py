@@ -6,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -235,3 +235,172 @@ def test_json_dict():
     assert exc_info.value.errors(include_url=False) == [
         {'type': 'dict_type', 'loc': (), 'msg': 'Input should be an object', 'input': 1}
     ]
+
+
+def test_forbidden_keys(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'dict', 'values_schema': {'type': 'int'}, 'forbidden_keys': ['__class__', '__init__']})
+    assert v.validate_test({'a': 1, 'b': 2}) == {'a': 1, 'b': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': 1, '__class__': 2, '__init__': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'dict_forbidden_key',
+            'loc': ('__class__',),
+            'msg': "Dictionary should not contain the key '__class__'",
+            'input': '__class__',
+            'ctx': {'key_name': '__class__'},
+        },
+        {
+            'type': 'dict_forbidden_key',
+            'loc': ('__init__',),
+            'msg': "Dictionary should not contain the key '__init__'",
+            'input': '__init__',
+            'ctx': {'key_name': '__init__'},
+        },
+    ]
+
+
+def test_required_keys(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'dict', 'keys_schema': {'type': 'str'}, 'required_keys': ['id', 'name']})
+    assert v.validate_test({'id': 1, 'name': 'x', 'other': None}) == {'id': 1, 'name': 'x', 'other': None}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'id': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'dict_missing_key',
+            'loc': ('name',),
+            'msg': "Dictionary should contain the key 'name'",
+            'input': {'id': 1},
+            'ctx': {'key_name': 'name'},
+        }
+    ]
+
+
+def test_allow_pairs_list(py_and_json: PyAndJson):
+    v = py_and_json(
+        {'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': {'type': 'int'}, 'allow_pairs_list': True}
+    )
+    assert v.validate_test({'a': 1}) == {'a': 1}
+    assert v.validate_test([['a', 1], ['b', '2']]) == {'a': 1, 'b': 2}
+    assert v.validate_test([]) == {}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([['a', 1], ['b'], ['c', 3, 4], ['d', 'x']])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'dict_pairs_invalid',
+            'loc': (1,),
+            'msg': 'Item 1 should be a key-value pair with exactly 2 items',
+            'input': ['b'],
+            'ctx': {'index': 1},
+        },
+        {
+            'type': 'dict_pairs_invalid',
+            'loc': (2,),
+            'msg': 'Item 2 should be a key-value pair with exactly 2 items',
+            'input': ['c', 3, 4],
+            'ctx': {'index': 2},
+        },
+    ]
+
+
+def test_allow_pairs_list_python_tuples():
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'int'}, 'allow_pairs_list': True})
+    assert v.validate_python((('a', '1'), ['b', 2])) == {'a': 1, 'b': 2}
+    with pytest.raises(ValidationError, match='Item 0 should be a key-value pair with exactly 2 items'):
+        v.validate_python(['ab'])
+
+
+def test_allow_pairs_list_strict():
+    v = SchemaValidator({'type': 'dict', 'allow_pairs_list': True, 'strict': True})
+    with pytest.raises(ValidationError, match=r'Input should be a valid dictionary \[type=dict_type'):
+        v.validate_python([('a', 1)])
+    v = SchemaValidator({'type': 'dict'})
+    with pytest.raises(ValidationError, match=r'Input should be a valid dictionary \[type=dict_type'):
+        v.validate_python([('a', 1)])
+
+
+@pytest.mark.parametrize(
+    'behavior,expected',
+    [
+        (None, {'a': 3, 'b': 2}),
+        ('use_last', {'a': 3, 'b': 2}),
+        ('use_first', {'a': 1, 'b': 2}),
+    ],
+)
+def test_duplicate_keys_json(behavior, expected):
+    schema = core_schema.dict_schema(values_schema=core_schema.int_schema(), duplicate_keys_behavior=behavior)
+    v = SchemaValidator(schema)
+    assert v.validate_json('{"a": 1, "b": 2, "a": "3"}') == expected
+
+
+def test_duplicate_keys_use_first_skips_validation():
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'int'}, 'duplicate_keys_behavior': 'use_first'})
+    assert v.validate_json('{"a": 1, "a": "not an int"}') == {'a': 1}
+
+
+def test_duplicate_keys_error():
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'int'}, 'duplicate_keys_behavior': 'error'})
+    assert v.validate_json('{"a": 1, "b": 2}') == {'a': 1, 'b': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1, "b": 2, "a": 3}')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'dict_duplicate_key',
+            'loc': ('a',),
+            'msg': "Dictionary should not contain the key 'a' more than once",
+            'input': 'a',
+            'ctx': {'key_name': 'a'},
+        }
+    ]
+
+
+def test_duplicate_keys_after_validation():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'duplicate_keys_behavior': 'error'})
+    with pytest.raises(ValidationError, match=r"Dictionary should not contain the key '1' more than once"):
+        v.validate_python({1: 'a', '1': 'b'})
+
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'duplicate_keys_behavior': 'use_first'})
+    assert v.validate_python({1: 'a', '1': 'b'}) == {1: 'a'}
+
+
+def test_duplicate_keys_first_value_invalid():
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'int'}, 'duplicate_keys_behavior': 'error'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": "x", "a": 1}')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_parsing', 'loc': ('a',), 'msg': IsStr(), 'input': 'x'},
+        {
+            'type': 'dict_duplicate_key',
+            'loc': ('a',),
+            'msg': "Dictionary should not contain the key 'a' more than once",
+            'input': 'a',
+            'ctx': {'key_name': 'a'},
+        },
+    ]
+
+    # the first value is used even though it's invalid
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'int'}, 'duplicate_keys_behavior': 'use_first'})
+    with pytest.raises(ValidationError, match=r'\[type=int_parsing,'):
+        v.validate_json('{"a": "x", "a": 1}')
+
+
+def test_strict_keys():
+    v = SchemaValidator(core_schema.dict_schema(keys_schema=core_schema.int_schema(), strict_keys=True))
+    assert v.validate_python({'1': 1, '2': 2}) == {1: 1, 2: 2}
+    assert v.validate_json('{"1": 1}') == {1: 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 1, 'x': 2, (3,): 3})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'invalid_key', 'loc': (1, '[key]'), 'msg': 'Keys should be strings', 'input': 1},
+        {'type': 'int_parsing', 'loc': ('x', '[key]'), 'msg': IsStr(), 'input': 'x'},
+        {'type': 'invalid_key', 'loc': ('(3,)', '[key]'), 'msg': 'Keys should be strings', 'input': (3,)},
+    ]
+
+
+def test_strict_keys_str_subclass():
+    class MyStr(str):
+        pass
+
+    v = SchemaValidator(core_schema.dict_schema(strict_keys=True))
+    assert v.validate_python({MyStr('a'): 1}) == {'a': 1}
+    assert SchemaValidator(core_schema.dict_schema()).validate_python({1: 1}) == {1: 1}
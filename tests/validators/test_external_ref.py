@@ -0,0 +1,94 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import plain_repr
+
+
+@pytest.fixture(scope='module')
+def address_validator():
+    return SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('address'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'city': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'postcode': core_schema.typed_dict_field(core_schema.definition_reference_schema('postcode')),
+                    },
+                    ref='address',
+                ),
+                core_schema.str_schema(max_length=8, ref='postcode'),
+            ],
+        )
+    )
+
+
+def test_root(address_validator):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'address': core_schema.typed_dict_field(core_schema.external_reference_schema(address_validator)),
+            }
+        )
+    )
+    assert v.validate_python({'name': 'Alice', 'address': {'city': 'London', 'postcode': 'SW1A 1AA'}}) == {
+        'name': 'Alice',
+        'address': {'city': 'London', 'postcode': 'SW1A 1AA'},
+    }
+    assert v.validate_json('{"name": "Alice", "address": {"city": "Paris", "postcode": "75001"}}') == {
+        'name': 'Alice',
+        'address': {'city': 'Paris', 'postcode': '75001'},
+    }
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'Alice', 'address': {'city': 'London'}})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('address', 'postcode'), 'msg': 'Field required', 'input': {'city': 'London'}}
+    ]
+
+
+def test_path(address_validator):
+    schema = core_schema.list_schema(core_schema.external_reference_schema(address_validator, path='postcode'))
+    v = SchemaValidator(schema)
+    assert v.validate_python(['SW1A 1AA', '75001']) == ['SW1A 1AA', '75001']
+    assert 'external-ref:constrained-str' in plain_repr(v)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['SW1A 1AA', 'far too long'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_long',
+            'loc': (1,),
+            'msg': 'String should have at most 8 characters',
+            'input': 'far too long',
+            'ctx': {'max_length': 8},
+        }
+    ]
+
+
+def test_strict(address_validator):
+    v = SchemaValidator(core_schema.external_reference_schema(address_validator, path='postcode'))
+    assert v.validate_python(b'75001') == '75001'
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(b'75001', strict=True)
+
+
+def test_missing_path(address_validator):
+    with pytest.raises(SchemaError, match='Definition `phone` not found in the referenced validator'):
+        SchemaValidator(core_schema.external_reference_schema(address_validator, path='phone'))
+
+
+def test_path_removed_by_rebuild():
+    inner = SchemaValidator(core_schema.definitions_schema(core_schema.any_schema(), [core_schema.int_schema(ref='a')]))
+    v = SchemaValidator(core_schema.external_reference_schema(inner, path='a'))
+    assert v.validate_python('1') == 1
+    inner.rebuild(core_schema.str_schema())
+    with pytest.raises(KeyError, match='Definition `a` not found in the referenced validator'):
+        v.validate_python('1')
+
+
+def test_serialization(address_validator):
+    s = SchemaSerializer(core_schema.external_reference_schema(address_validator))
+    assert s.to_python({'city': 'London', 'postcode': 'SW1A 1AA'}) == {'city': 'London', 'postcode': 'SW1A 1AA'}
@@ -6,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import FunctionCheck, IsFloatNan, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -183,12 +183,14 @@ def test_float_repr():
     v = SchemaValidator({'type': 'float'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true,'
+        'allow_decimal_coercion:true,numeric_string_locale:None}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'float', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true,'
+        'allow_decimal_coercion:true,numeric_string_locale:None}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'float', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",validator=ConstrainedFloat(')
@@ -206,6 +208,23 @@ def test_float_not_json(input_value, expected):
         assert isinstance(output, float)
 
 
+@pytest.mark.parametrize('schema_extra', [{}, {'ge': 0}])
+@pytest.mark.parametrize('strict', [False, True])
+def test_float_decimal_coercion_not_allowed(schema_extra, strict):
+    v = SchemaValidator({'type': 'float', 'allow_decimal_coercion': False, 'strict': strict, **schema_extra})
+    assert v.validate_python(1.23) == 1.23
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Decimal('0.1'))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'float_from_decimal_not_allowed',
+            'loc': (),
+            'msg': 'Input should be a valid number, Decimal values may not be coerced to float',
+            'input': Decimal('0.1'),
+        }
+    ]
+
+
 def test_float_nan(py_and_json: PyAndJson):
     v = py_and_json({'type': 'float'})
     assert v.validate_test('1' * 800) == float('inf')
@@ -408,3 +427,85 @@ def test_allow_inf_nan_false_json() -> None:
     assert exc_info3.value.errors(include_url=False) == [
         {'type': 'finite_number', 'loc': (), 'msg': 'Input should be a finite number', 'input': float('-inf')}
     ]
+
+
+@pytest.mark.parametrize(
+    'decimal_places,input_value,expected',
+    [
+        (2, 1.005, 1.0),
+        (2, 1.015, 1.02),
+        (2, 2.675, 2.68),
+        (2, 1.234, 1.23),
+        (2, 1.5, 1.5),
+        (2, -1.005, -1.0),
+        (2, -2.675, -2.68),
+        (2, -1.236, -1.24),
+        (0, 0.5, 0.0),
+        (0, 1.5, 2.0),
+        (0, 2.5, 2.0),
+        (0, -2.5, -2.0),
+        (0, 123.456, 123.0),
+        (1, 0.04, 0.0),
+        (1, 0.05, 0.0),
+        (1, 0.06, 0.1),
+        (3, 1e-20, 0.0),
+        (2, 1e20, 1e20),
+        (2, '3.14159', 3.14),
+    ],
+)
+def test_float_decimal_places(decimal_places, input_value, expected):
+    v = SchemaValidator(core_schema.float_schema(decimal_places=decimal_places))
+    output = v.validate_python(input_value)
+    assert output == expected
+    assert isinstance(output, float)
+
+
+def test_float_decimal_places_constraints(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.float_schema(decimal_places=1, le=1.04))
+    assert v.validate_test(1.04) == 1.0
+    assert v.validate_test(0.25) == 0.2
+    # constraints are checked before rounding
+    with pytest.raises(ValidationError, match='Input should be less than or equal to 1.04'):
+        v.validate_test(1.05)
+    assert v.validate_test(float('-inf')) == float('-inf')
+
+
+@pytest.mark.parametrize(
+    'locale,input_value,expected',
+    [
+        ('de', '1.234,56', 1234.56),
+        ('de', '-0,5', -0.5),
+        ('de', '1.234.567', 1234567.0),
+        ('fr', '1 234,56', 1234.56),
+        ('fr', '1\u202f234,5', 1234.5),
+        ('de', 1.5, 1.5),
+        ('de', '1.5', Err('unable to parse string as a number [type=float_parsing')),
+        ('de', '12.34,5', Err('unable to parse string as a number [type=float_parsing')),
+        ('de', '1234.567', Err('unable to parse string as a number [type=float_parsing')),
+        ('de', '1,234.5', Err('unable to parse string as a number [type=float_parsing')),
+        ('fr', '1 23,5', Err('unable to parse string as a number [type=float_parsing')),
+        ('de', 'foo', Err('Input should be a valid number, unable to parse string as a number [type=float_parsing')),
+    ],
+)
+def test_float_numeric_string_locale(py_and_json: PyAndJson, locale, input_value, expected):
+    v = py_and_json(core_schema.float_schema(numeric_string_locale=locale))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_float_numeric_string_locale_constrained():
+    v = SchemaValidator(core_schema.float_schema(numeric_string_locale='de', ge=1000))
+    assert v.validate_python('1.000,5') == 1000.5
+    with pytest.raises(ValidationError, match='Input should be greater than or equal to 1000'):
+        v.validate_python('999,5')
+    # strings aren't accepted at all in strict mode
+    with pytest.raises(ValidationError, match=r'Input should be a valid number \[type=float_type'):
+        v.validate_python('1.000,5', strict=True)
+
+
+def test_float_numeric_string_locale_invalid():
+    with pytest.raises(SchemaError, match='Invalid numeric string locale: en'):
+        SchemaValidator({'type': 'float', 'numeric_string_locale': 'en'})
@@ -158,6 +158,12 @@ def generate_repeats():
         # length check after set creation
         ({'max_length': 3}, [1, 1, 2, 2, 3, 3], {1, 2, 3}),
         ({'max_length': 3}, generate_repeats(), {1, 2, 3}),
+        ({'exact_length': 2}, [1, 1, 2], {1, 2}),
+        (
+            {'exact_length': 2},
+            {1, 2, 3},
+            Err('Frozenset should have exactly 2 items after validation, not 3 [type=exact_length,'),
+        ),
         (
             {'max_length': 3},
             infinite_generator(),
@@ -247,7 +253,8 @@ def test_repr():
         'SchemaValidator('
         'title="frozenset[any]",'
         'validator=FrozenSet(FrozenSetValidator{'
-        'strict:true,item_validator:Any(AnyValidator),min_length:Some(42),max_length:None,'
+        'strict:true,item_validator:Any(AnyValidator{json_scalars_only:false}),'
+        'min_length:Some(42),max_length:None,exact_length:None,'
         'name:"frozenset[any]"'
         '}),'
         'definitions=[],'
@@ -296,3 +303,30 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
     output = v.validate_python(input_value)
     assert isinstance(output, frozenset)
     assert output == expected
+
+
+def test_frozenset_unhashable_item(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'frozenset', 'items_schema': {'type': 'any'}})
+    assert v.validate_test([1, 2]) == frozenset({1, 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, {'a': 1}])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'set_item_unhashable',
+            'loc': (1,),
+            'msg': 'Set items should be hashable, got an instance of dict',
+            'input': {'a': 1},
+            'ctx': {'type_name': 'dict'},
+        }
+    ]
+
+
+def test_frozenset_strict_json_array():
+    # JSON has no set type, so arrays are accepted even in strict mode
+    v = SchemaValidator({'type': 'frozenset', 'items_schema': {'type': 'int'}, 'strict': True})
+    assert v.validate_json('[]') == frozenset()
+    assert v.validate_json('[1, 2, 2, 3]') == frozenset({1, 2, 3})
+    with pytest.raises(ValidationError, match=r'Input should be a valid array \[type=frozen_set_type,'):
+        v.validate_json('{"a": 1}')
+    with pytest.raises(ValidationError, match=r'Input should be a valid frozenset \[type=frozen_set_type,'):
+        v.validate_python([1, 2])
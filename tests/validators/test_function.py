@@ -7,7 +7,16 @@ from typing import Any, Dict, List, Type
 import pytest
 from dirty_equals import HasRepr
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
+from pydantic_core import (
+    ArgsKwargs,
+    PydanticDropField,
+    PydanticUndefined,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    validate_core_schema,
+)
 
 from ..conftest import plain_repr
 
@@ -304,6 +313,61 @@ def test_function_wrap_invalid_location():
     ]
 
 
+def test_function_wrap_on_error():
+    calls = []
+
+    def on_error(input_value, error, info):
+        calls.append((input_value, error.errors(include_url=False)[0]['type'], info.context))
+        return -1
+
+    v = SchemaValidator(
+        core_schema.no_info_wrap_validator_function(
+            lambda input_value, validator: validator(input_value) * 2, core_schema.int_schema(), on_error=on_error
+        )
+    )
+
+    assert v.validate_python('2') == 4
+    assert calls == []
+    assert v.validate_python('wrong', context={'a': 1}) == -1
+    assert calls == [('wrong', 'int_parsing', {'a': 1})]
+
+
+def test_function_wrap_on_error_only_validation_errors():
+    def f(input_value, validator, info):
+        raise TypeError('not a validation error')
+
+    v = SchemaValidator(
+        core_schema.with_info_wrap_validator_function(f, core_schema.int_schema(), on_error=lambda *args: -1)
+    )
+
+    with pytest.raises(TypeError, match='not a validation error'):
+        v.validate_python(1)
+
+
+def test_function_wrap_on_error_raises():
+    def on_error(input_value, error, info):
+        raise ValueError(f'no fallback for {input_value!r}')
+
+    v = SchemaValidator(
+        core_schema.no_info_wrap_validator_function(
+            lambda input_value, validator: validator(input_value), core_schema.int_schema(), on_error=on_error
+        )
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('wrong')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'value_error',
+            'loc': (),
+            'msg': "Value error, no fallback for 'wrong'",
+            'input': 'wrong',
+            'ctx': {'error': HasRepr(repr(ValueError("no fallback for 'wrong'")))},
+        }
+    ]
+
+
 def test_function_after():
     def f(input_value, _info):
         return input_value + ' Changed'
@@ -322,6 +386,256 @@ def test_function_no_info():
     assert v.validate_python('input value') == 'input value Changed'
 
 
+def drop_secret(value: str) -> Any:
+    return PydanticDropField if value == 'secret' else value
+
+
+def test_function_after_drop_field():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.no_info_after_validator_function(drop_secret, core_schema.str_schema())
+                ),
+            }
+        )
+    )
+
+    assert v.validate_python({'a': 1, 'b': 'public'}) == {'a': 1, 'b': 'public'}
+    assert v.validate_python({'a': 1, 'b': 'secret'}) == {'a': 1}
+    assert v.validate_json('{"a": 1, "b": "secret"}') == {'a': 1}
+
+
+def test_function_after_drop_field_top_level():
+    v = SchemaValidator(core_schema.no_info_after_validator_function(drop_secret, core_schema.str_schema()))
+
+    assert v.validate_python('public') == 'public'
+    with pytest.raises(TypeError, match='`PydanticDropField` can only be returned by validators of typed dict'):
+        v.validate_python('secret')
+
+
+@pytest.mark.parametrize(
+    'schema_fn',
+    [
+        core_schema.list_schema,
+        core_schema.set_schema,
+        lambda items_schema: core_schema.tuple_schema([core_schema.str_schema(), items_schema]),
+        lambda items_schema: core_schema.dict_schema(values_schema=items_schema),
+    ],
+)
+def test_function_after_drop_field_in_collection(schema_fn):
+    v = SchemaValidator(schema_fn(core_schema.no_info_after_validator_function(drop_secret, core_schema.str_schema())))
+    input_value = {'a': 'secret'} if v.title.startswith('dict') else ['a', 'secret']
+
+    # items are never dropped from collections
+    with pytest.raises(TypeError, match='`PydanticDropField` can only be returned by validators of typed dict'):
+        v.validate_python(input_value)
+
+
+def test_function_after_drop_field_default():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.no_info_after_validator_function(drop_secret, core_schema.str_schema()),
+                        default='secret',
+                        validate_default=True,
+                    )
+                ),
+            }
+        )
+    )
+
+    assert v.validate_python({'b': 'public'}) == {'b': 'public'}
+    assert v.validate_python({}) == {}
+
+
+def test_function_after_drop_field_fields_set():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(
+                        core_schema.with_default_schema(
+                            core_schema.no_info_after_validator_function(drop_secret, core_schema.str_schema()),
+                            default=None,
+                        )
+                    ),
+                }
+            ),
+        )
+    )
+
+    m = v.validate_python({'a': 1, 'b': 'secret'})
+    assert m.__dict__ == {'a': 1}
+    assert m.__pydantic_fields_set__ == {'a'}
+
+    m = v.validate_python({'a': 1, 'b': 'public'})
+    assert m.__dict__ == {'a': 1, 'b': 'public'}
+    assert m.__pydantic_fields_set__ == {'a', 'b'}
+
+
+def undefined_if_blank(value: Any) -> Any:
+    return PydanticUndefined if value == '' else value
+
+
+def test_function_before_undefined_default():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.no_info_before_validator_function(undefined_if_blank, core_schema.int_schema()),
+                        default=42,
+                    )
+                ),
+            }
+        )
+    )
+
+    assert v.validate_python({'a': '1'}) == {'a': 1}
+    assert v.validate_python({'a': ''}) == {'a': 42}
+    assert v.validate_json('{"a": ""}') == {'a': 42}
+
+
+def test_function_before_undefined_no_default():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.no_info_before_validator_function(undefined_if_blank, core_schema.int_schema())
+                ),
+                'b': core_schema.typed_dict_field(
+                    core_schema.no_info_before_validator_function(undefined_if_blank, core_schema.int_schema()),
+                    required=False,
+                ),
+            }
+        )
+    )
+
+    assert v.validate_python({'a': '1', 'b': ''}) == {'a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': ''})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('a',), 'msg': 'Field required', 'input': {'a': ''}}
+    ]
+
+
+def test_function_before_undefined_model_fields():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(
+                    core_schema.with_default_schema(
+                        core_schema.no_info_before_validator_function(undefined_if_blank, core_schema.int_schema()),
+                        default=42,
+                    )
+                ),
+                'b': core_schema.model_field(
+                    core_schema.no_info_before_validator_function(undefined_if_blank, core_schema.int_schema())
+                ),
+            }
+        )
+    )
+
+    assert v.validate_python({'a': '', 'b': 1}) == ({'a': 42, 'b': 1}, None, {'a', 'b'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': ''})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 1, 'b': ''}}
+    ]
+
+
+def test_function_before_undefined_top_level():
+    v = SchemaValidator(core_schema.no_info_before_validator_function(undefined_if_blank, core_schema.int_schema()))
+
+    assert v.validate_python('1') == 1
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (), 'msg': 'Field required', 'input': ''}
+    ]
+    assert v.isinstance_python('') is False
+
+
+@pytest.mark.parametrize('mode', ['smart', 'left_to_right', 'best_match'])
+def test_function_before_undefined_union(mode):
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.no_info_before_validator_function(lambda v: PydanticUndefined, core_schema.int_schema()),
+                core_schema.str_schema(),
+            ],
+            mode=mode,
+        )
+    )
+
+    # the choice returning `PydanticUndefined` fails, rather than the whole union
+    assert v.validate_python('a') == 'a'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert [e['type'] for e in exc_info.value.errors(include_url=False)] == ['missing', 'string_type']
+
+
+def test_function_before_undefined_items():
+    item_schema = core_schema.no_info_before_validator_function(undefined_if_blank, core_schema.int_schema())
+
+    v = SchemaValidator(core_schema.list_schema(item_schema))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['1', '', '3'])
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (1,), 'msg': 'Field required', 'input': ''}
+    ]
+
+    v = SchemaValidator(core_schema.tuple_schema([core_schema.int_schema(), item_schema]))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, ''))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (1,), 'msg': 'Field required', 'input': ''}
+    ]
+
+
+def test_function_before_undefined_dataclass_args():
+    v = SchemaValidator(
+        core_schema.dataclass_args_schema(
+            'MyDataclass',
+            [
+                core_schema.dataclass_field(
+                    'a',
+                    core_schema.no_info_before_validator_function(undefined_if_blank, core_schema.int_schema()),
+                    kw_only=False,
+                ),
+                core_schema.dataclass_field(
+                    'b',
+                    core_schema.with_default_schema(
+                        core_schema.no_info_before_validator_function(undefined_if_blank, core_schema.int_schema()),
+                        default=42,
+                    ),
+                ),
+            ],
+        )
+    )
+
+    assert v.validate_python(ArgsKwargs((1,), {'b': ''})) == ({'a': 1, 'b': 42}, None)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('',)))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (0,), 'msg': 'Field required', 'input': ArgsKwargs(('',))}
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': ''})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('a',), 'msg': 'Field required', 'input': {'a': ''}}
+    ]
+
+
 def test_function_after_raise():
     def f(input_value, info):
         raise ValueError('foobar')
@@ -413,6 +727,34 @@ def test_function_plain():
     assert v.validate_python('x') == 'xx'
 
 
+def test_function_plain_info_metadata():
+    def f(input_value, info):
+        return datetime.datetime.strptime(input_value, info.metadata['format']).date()
+
+    metadata = {'format': '%d/%m/%Y'}
+    v = SchemaValidator(core_schema.with_info_plain_validator_function(f, info_metadata=metadata))
+    # changes to the original dict after building have no effect
+    metadata['format'] = '%Y'
+    assert v.validate_python('15/10/2026') == datetime.date(2026, 10, 15)
+
+
+def test_function_plain_info_metadata_read_only():
+    def f(input_value, info):
+        info.metadata['x'] = 2
+
+    v = SchemaValidator(core_schema.with_info_plain_validator_function(f, info_metadata={'x': 1}))
+    with pytest.raises(TypeError, match="'mappingproxy' object does not support item assignment"):
+        v.validate_python(1)
+
+
+def test_function_info_metadata_default():
+    def f(input_value, info):
+        return info.metadata
+
+    v = SchemaValidator(core_schema.with_info_after_validator_function(f, core_schema.int_schema()))
+    assert v.validate_python(1) is None
+
+
 def test_function_plain_no_info():
     def f(input_value):
         return input_value * 2
@@ -686,6 +1028,31 @@ def test_model_field_wrap_validator() -> None:
     assert v.validate_python({'x': b'foo'}).x == 'input: foo'
 
 
+def test_typed_dict_field_name_in_info():
+    calls = []
+
+    def f(input_value: Any, info: core_schema.ValidationInfo) -> Any:
+        calls.append((info.field_name, input_value))
+        return input_value
+
+    validator = core_schema.with_info_before_validator_function(f, core_schema.int_schema())
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(validator),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(validator)),
+                'c': core_schema.typed_dict_field(
+                    core_schema.with_info_before_validator_function(f, core_schema.int_schema(), field_name='other')
+                ),
+            }
+        )
+    )
+
+    assert v.validate_python({'a': 1, 'b': [2], 'c': 3}) == {'a': 1, 'b': [2], 'c': 3}
+    # the field name from the schema takes precedence
+    assert calls == [('a', 1), ('b', 2), ('other', 3)]
+
+
 def check_info_field_name_none(info: core_schema.ValidationInfo) -> None:
     assert info.field_name is None
     assert info.data == {}
@@ -959,3 +1326,82 @@ def test_function_after_doesnt_change_mode() -> None:
     assert v.validate_json(b'"2000-01-01"') == datetime.date(2000, 1, 1)
     with pytest.raises(ValidationError):
         v.validate_python(b'"2000-01-01"')
+
+
+def test_after_noop_marker_skips_call():
+    def check(value):
+        raise AssertionError('should not be called')
+
+    check.__pydantic_noop__ = True
+    v = SchemaValidator(core_schema.no_info_after_validator_function(check, core_schema.int_schema()))
+    assert v.validate_python('1') == 1
+
+
+@pytest.mark.parametrize(
+    'schema_fn,function_src',
+    [
+        (core_schema.no_info_after_validator_function, 'def identity(value):\n    return value'),
+        (core_schema.with_info_after_validator_function, 'def identity(value, info):\n    return value'),
+    ],
+)
+def test_after_identity_function_skips_call(schema_fn, function_src):
+    namespace = {}
+    exec(function_src, namespace)
+    identity = namespace['identity']
+    v = SchemaValidator(schema_fn(identity, core_schema.int_schema()))
+    # the function was recognised when building the validator, changing it afterwards shows it isn't called
+    identity.__code__ = (lambda *args: 1 / 0).__code__
+    assert v.validate_python('1') == 1
+
+
+@pytest.mark.parametrize(
+    'function,expected',
+    [(lambda v: v + 1, 2), (lambda *args: args, (1,)), (lambda v, w=5: w, 5)],
+)
+def test_after_non_identity_function_called(function, expected):
+    v = SchemaValidator(core_schema.no_info_after_validator_function(function, core_schema.int_schema()))
+    assert v.validate_python('1') == expected
+
+
+def check_unique(value, info):
+    if value in info.context['existing']:
+        raise ValueError(f'{value!r} already exists')
+    return value
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        core_schema.with_info_before_validator_function(
+            check_unique, core_schema.int_schema(), skip_on_missing_context=True
+        ),
+        core_schema.with_info_after_validator_function(
+            check_unique, core_schema.int_schema(), skip_on_missing_context=True
+        ),
+        core_schema.with_info_wrap_validator_function(
+            lambda v, handler, info: check_unique(handler(v), info),
+            core_schema.int_schema(),
+            skip_on_missing_context=True,
+        ),
+    ],
+)
+def test_skip_on_missing_context(schema):
+    v = SchemaValidator(schema)
+    assert v.validate_python('1') == 1
+    assert v.validate_json('1') == 1
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('x')
+
+    assert v.validate_python(2, context={'existing': [1]}) == 2
+    with pytest.raises(ValidationError, match='Value error, 1 already exists'):
+        v.validate_python(1, context={'existing': [1]})
+
+
+def test_skip_on_missing_context_plain():
+    schema = core_schema.with_info_plain_validator_function(check_unique)
+    with pytest.raises(SchemaError, match='`skip_on_missing_context` is not supported by `function-plain` validators'):
+        SchemaValidator({**schema, 'skip_on_missing_context': True})
+
+    v = SchemaValidator(schema)
+    with pytest.raises(TypeError, match="'NoneType' object is not subscriptable"):
+        v.validate_python(1)
@@ -6,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -383,12 +383,14 @@ def test_int_repr():
     v = SchemaValidator({'type': 'int'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false,strict_no_bool:false}),definitions=[],'
+        'cache_strings=True)'
     )
     v = SchemaValidator({'type': 'int', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true,strict_no_bool:false}),definitions=[],'
+        'cache_strings=True)'
     )
     v = SchemaValidator({'type': 'int', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",validator=ConstrainedInt(')
@@ -557,3 +559,65 @@ def test_json_big_int_key():
     assert v.validate_python({big_integer: 'x'}) == {big_integer: 'x'}
     assert v.validate_json('{"' + str(big_integer) + '": "x"}') == {big_integer: 'x'}
     assert v.validate_strings({str(big_integer): 'x'}) == {big_integer: 'x'}
+
+
+@pytest.mark.parametrize('schema_extra', [{}, {'ge': 0}])
+@pytest.mark.parametrize('strict', [False, True])
+def test_int_strict_no_bool(py_and_json: PyAndJson, schema_extra, strict):
+    v = py_and_json(core_schema.int_schema(strict_no_bool=True, **schema_extra))
+    assert v.validate_test(1, strict=strict) == 1
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(True, strict=strict)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_from_bool_not_allowed',
+            'loc': (),
+            'msg': 'Input should be a valid integer, boolean values are not allowed',
+            'input': True,
+        }
+    ]
+
+
+def test_int_strict_no_bool_strings():
+    v = SchemaValidator(core_schema.int_schema(strict_no_bool=True))
+    assert v.validate_strings('1') == 1
+    assert v.validate_python('1') == 1
+
+
+def test_int_bool_allowed():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python(True) == 1
+    assert v.validate_json('false') == 0
+
+
+@pytest.mark.parametrize(
+    'bit_width,unsigned,input_value,expected',
+    [
+        (8, False, 127, 127),
+        (8, False, -128, -128),
+        (8, False, 128, Err('Input should fit in a signed 8 bit integer [type=int_overflow')),
+        (8, False, -129, Err('Input should fit in a signed 8 bit integer [type=int_overflow')),
+        (8, True, 255, 255),
+        (8, True, 256, Err('Input should fit in an unsigned 8 bit integer [type=int_overflow')),
+        (8, True, -1, Err('Input should fit in an unsigned 8 bit integer [type=int_overflow')),
+        (32, True, '4294967295', 4294967295),
+        (64, False, i64_max, i64_max),
+        (64, False, i64_max + 1, Err('Input should fit in a signed 64 bit integer [type=int_overflow')),
+        (64, True, 2**64 - 1, 2**64 - 1),
+        (64, True, 2**64, Err('Input should fit in an unsigned 64 bit integer [type=int_overflow')),
+    ],
+)
+def test_int_bit_width(py_and_json: PyAndJson, bit_width, unsigned, input_value, expected):
+    v = py_and_json(core_schema.int_schema(bit_width=bit_width, unsigned=unsigned))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'bit_width': bit_width, 'unsigned': unsigned}
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_unsigned_requires_bit_width():
+    with pytest.raises(SchemaError, match='`unsigned` requires `bit_width` to be set'):
+        SchemaValidator(core_schema.int_schema(unsigned=True))
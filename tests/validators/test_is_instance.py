@@ -155,10 +155,18 @@ def test_is_instance_tuple():
     assert v.isinstance_python(1) is True
     assert v.isinstance_python('foobar') is True
     assert v.isinstance_python([1]) is False
-    with pytest.raises(ValidationError, match=r"Input should be an instance of \(<class 'int'>, <class 'str'>\)"):
+    with pytest.raises(ValidationError, match=r'Input should be an instance of int \| str \[type=is_instance_of,'):
         v.validate_python([1])
 
 
+def test_is_instance_nested_tuple():
+    v = SchemaValidator(core_schema.is_instance_schema((int, (str, bytes))))
+    assert v.isinstance_python(b'foobar') is True
+    assert v.isinstance_python(1.5) is False
+    with pytest.raises(ValidationError, match=r'Input should be an instance of int \| str \| bytes \[type='):
+        v.validate_python(1.5)
+
+
 def test_class_repr():
     v = SchemaValidator(core_schema.is_instance_schema(int, cls_repr='Foobar'))
     assert v.validate_python(1) == 1
@@ -7,7 +7,7 @@ from typing import Any, Dict, Iterator, List, Union
 import pytest
 from dirty_equals import Contains, HasRepr, IsInstance, IsList, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -167,6 +167,14 @@ def test_list_error(input_value, index):
             [0, 1, 2, 3, 4, 5, 6, 7, 8],
             Err('List should have at most 4 items after validation, not 9 [type=too_long,'),
         ),
+        ({'exact_length': 2}, [1, 2], [1, 2]),
+        ({'exact_length': 2}, (1, 2), [1, 2]),
+        ({'exact_length': 2}, [1], Err('List should have exactly 2 items after validation, not 1 [type=exact_length,')),
+        (
+            {'exact_length': 1, 'items_schema': {'type': 'int'}},
+            [1, 2, 3],
+            Err('List should have exactly 1 item after validation, not 3 [type=exact_length,'),
+        ),
     ],
 )
 def test_list_length_constraints(kwargs: Dict[str, Any], input_value, expected):
@@ -502,3 +510,70 @@ def test_list_dict_items_input(testcase: ListInputTestCase) -> None:
         output = v.validate_python(testcase.input)
         assert output == testcase.output
         assert output is not testcase.input
+
+
+@pytest.mark.parametrize(
+    'items_schema,input_value,expected',
+    [
+        (None, [1, '2'], (1, '2')),
+        (None, (1, '2'), (1, '2')),
+        (core_schema.int_schema(), [1, '2'], (1, 2)),
+        (core_schema.int_schema(), {1, 2}, (1, 2)),
+        (core_schema.int_schema(), [], ()),
+    ],
+)
+def test_list_output_type_tuple(items_schema, input_value, expected):
+    v = SchemaValidator(core_schema.list_schema(items_schema, output_type='tuple'))
+    output = v.validate_python(input_value)
+    assert isinstance(output, tuple)
+    assert output == expected
+
+
+def test_list_output_type_tuple_json():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), output_type='tuple', max_length=2))
+    assert v.validate_json('[1, 2]') == (1, 2)
+    with pytest.raises(ValidationError, match='List should have at most 2 items after validation, not 3'):
+        v.validate_json('[1, 2, 3]')
+
+
+def test_list_output_type_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'list' or 'tuple'"):
+        validate_core_schema({'type': 'list', 'output_type': 'generator'})
+
+
+def test_mutate_in_place():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), mutate_in_place=True))
+    input_value = [1, '2', 3.0]
+    output = v.validate_python(input_value)
+    assert output is input_value
+    assert output == [1, 2, 3]
+    assert [type(item) for item in output] == [int, int, int]
+
+
+def test_mutate_in_place_no_items_schema():
+    v = SchemaValidator(core_schema.list_schema(mutate_in_place=True))
+    input_value = [1, 'a']
+    assert v.validate_python(input_value) is input_value
+
+
+def test_mutate_in_place_error():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), mutate_in_place=True))
+    input_value = ['1', 'x']
+    with pytest.raises(ValidationError, match=r'1\s+Input should be a valid integer'):
+        v.validate_python(input_value)
+    assert input_value == ['1', 'x']
+
+
+@pytest.mark.parametrize('input_value', [('1', 2), {'1', 2}])
+def test_mutate_in_place_not_list(input_value):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), mutate_in_place=True))
+    output = v.validate_python(input_value)
+    assert isinstance(output, list)
+    assert sorted(output) == [1, 2]
+
+
+def test_mutate_in_place_default_copies():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    input_value = ['1', 2]
+    assert v.validate_python(input_value) is not input_value
+    assert input_value == ['1', 2]
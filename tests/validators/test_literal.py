@@ -1,3 +1,4 @@
+import math
 import re
 from enum import Enum
 from typing import Any, Callable, List
@@ -389,3 +390,22 @@ def test_big_int():
     m = r'Input should be 18446744073709551617 or 340282366920938463463374607431768211457 \[type=literal_error'
     with pytest.raises(ValidationError, match=m):
         v.validate_python(37)
+
+
+def test_float_literals(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.literal_schema([1.0, 2.5, 3.14]))
+    assert v.validate_test(2.5) == 2.5
+    assert v.validate_test(3.14) == 3.14
+    assert v.validate_test(1) == 1.0
+    with pytest.raises(ValidationError, match=r'Input should be 1\.0, 2\.5 or 3\.14 \[type=literal_error'):
+        v.validate_test(2.4)
+    with pytest.raises(ValidationError, match=r'Input should be 1\.0, 2\.5 or 3\.14 \[type=literal_error'):
+        v.validate_test('2.5')
+
+
+def test_float_literal_nan():
+    v = SchemaValidator(core_schema.literal_schema([float('nan'), 1.5]))
+    assert math.isnan(v.validate_python(float('nan')))
+    assert v.validate_python(1.5) == 1.5
+    with pytest.raises(ValidationError, match=r'Input should be nan or 1\.5 \[type=literal_error'):
+        v.validate_python(float('inf'))
@@ -1312,3 +1312,273 @@ def test_model_error():
             'ctx': {'class_name': 'MyModel'},
         }
     ]
+
+
+def test_model_class_new_args():
+    class MyStr(str):
+        pass
+
+    class MyInt(int):
+        pass
+
+    fields_schema = core_schema.model_fields_schema(fields={'a': core_schema.model_field(core_schema.int_schema())})
+
+    v = SchemaValidator(core_schema.model_schema(MyStr, fields_schema, class_new_args=['hello']))
+    m = v.validate_python({'a': '1'})
+    assert isinstance(m, MyStr)
+    assert m == 'hello'
+    assert m.a == 1
+    assert m.__pydantic_fields_set__ == {'a'}
+
+    v = SchemaValidator(core_schema.model_schema(MyInt, fields_schema, class_new_args=[42]))
+    m = v.validate_python({'a': '1'})
+    assert isinstance(m, MyInt)
+    assert m == 42
+    assert m.a == 1
+
+    v = SchemaValidator(core_schema.model_schema(MyStr, fields_schema))
+    m = v.validate_python({'a': '1'})
+    assert m == ''
+    assert m.a == 1
+
+
+def test_model_class_new_args_required():
+    class MyModel:
+        def __new__(cls, value):
+            instance = super().__new__(cls)
+            instance.value = value
+            return instance
+
+    fields_schema = core_schema.model_fields_schema(fields={'a': core_schema.model_field(core_schema.int_schema())})
+
+    v = SchemaValidator(core_schema.model_schema(MyModel, fields_schema))
+    with pytest.raises(TypeError, match=r"missing 1 required positional argument: 'value'"):
+        v.validate_python({'a': 1})
+
+    v = SchemaValidator(core_schema.model_schema(MyModel, fields_schema, class_new_args=['x']))
+    m = v.validate_python({'a': 1})
+    assert isinstance(m, MyModel)
+    assert m.a == 1
+
+
+def test_model_class_kwargs():
+    calls = []
+
+    class MyModel:
+        @classmethod
+        def __pydantic_init_subclass__(cls, **kwargs):
+            calls.append((cls, kwargs))
+
+    fields_schema = core_schema.model_fields_schema(fields={'a': core_schema.model_field(core_schema.int_schema())})
+
+    schema = core_schema.model_schema(MyModel, fields_schema, class_kwargs={'tag': 'x', 'size': 2})
+    v = SchemaValidator(schema)
+    # building the validator, including as part of another schema, doesn't call the hook
+    SchemaValidator(core_schema.list_schema(schema))
+    assert calls == []
+
+    m = v.validate_python({'a': 1})
+    assert m.a == 1
+    assert calls == [(MyModel, {'tag': 'x', 'size': 2})]
+    assert v.validate_json('{"a": 2}').a == 2
+    assert calls == [(MyModel, {'tag': 'x', 'size': 2})]
+
+    # no hook on the class, the kwargs are ignored
+    class PlainModel:
+        pass
+
+    v = SchemaValidator(core_schema.model_schema(PlainModel, fields_schema, class_kwargs={'tag': 'x'}))
+    assert v.validate_python({'a': 1}).a == 1
+
+
+def test_model_class_kwargs_once_per_class():
+    calls = []
+
+    class MyModel:
+        @classmethod
+        def __pydantic_init_subclass__(cls, **kwargs):
+            calls.append(cls)
+
+    class SubModel(MyModel):
+        pass
+
+    fields_schema = core_schema.model_fields_schema(fields={'a': core_schema.model_field(core_schema.int_schema())})
+    SchemaValidator(core_schema.model_schema(MyModel, fields_schema, class_kwargs={})).validate_python({'a': 1})
+    assert calls == [MyModel]
+
+    # a rebuilt or nested validator for the same class doesn't call the hook again
+    v = SchemaValidator(core_schema.list_schema(core_schema.model_schema(MyModel, fields_schema, class_kwargs={})))
+    v.validate_python([{'a': 1}])
+    assert calls == [MyModel]
+
+    # but a subclass gets its own call
+    SchemaValidator(core_schema.model_schema(SubModel, fields_schema, class_kwargs={})).validate_python({'a': 1})
+    assert calls == [MyModel, SubModel]
+
+
+def test_model_class_kwargs_hook_error():
+    calls = []
+
+    class MyModel:
+        @classmethod
+        def __pydantic_init_subclass__(cls, **kwargs):
+            calls.append(kwargs)
+            if len(calls) == 1:
+                raise RuntimeError('not ready')
+
+    fields_schema = core_schema.model_fields_schema(fields={'a': core_schema.model_field(core_schema.int_schema())})
+    v = SchemaValidator(core_schema.model_schema(MyModel, fields_schema, class_kwargs={'tag': 'x'}))
+    with pytest.raises(RuntimeError, match='not ready'):
+        v.validate_python({'a': 1})
+
+    # the hook is called again until it succeeds
+    assert v.validate_python({'a': 1}).a == 1
+    assert v.validate_python({'a': 2}).a == 2
+    assert calls == [{'tag': 'x'}, {'tag': 'x'}]
+
+
+def test_validate_assignment_config():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    MyModel.__pydantic_validator__ = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'field_a': core_schema.model_field(core_schema.str_schema()),
+                    'field_b': core_schema.model_field(core_schema.int_schema()),
+                }
+            ),
+            config=core_schema.CoreConfig(validate_assignment=True),
+        )
+    )
+
+    m = MyModel.__pydantic_validator__.validate_python({'field_a': 'hello', 'field_b': 1})
+    m.field_b = '321'
+    assert m.field_b == 321
+    assert m.__dict__ == {'field_a': 'hello', 'field_b': 321}
+
+    with pytest.raises(ValidationError) as exc_info:
+        m.field_b = 'wrong'
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('field_b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+    assert m.field_b == 321
+
+    # private and other non-field attributes aren't validated
+    m._private = 'x'
+    m.other = 'y'
+    assert (m._private, m.other) == ('x', 'y')
+
+
+def test_validate_assignment_config_custom_setattr():
+    class MyModel:
+        def __setattr__(self, name, value):
+            object.__setattr__(self, name, value * 2)
+
+    setattr_func = MyModel.__setattr__
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+            config=core_schema.CoreConfig(validate_assignment=True),
+        )
+    )
+    assert MyModel.__setattr__ is setattr_func
+
+    m = v.validate_python({'a': 1})
+    m.a = 'b'
+    assert m.a == 'bb'
+
+
+def test_validate_assignment_config_default():
+    class MyModel:
+        pass
+
+    SchemaValidator(
+        core_schema.model_schema(
+            MyModel, core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())})
+        )
+    )
+    assert MyModel.__setattr__ is object.__setattr__
+
+
+def test_get_validators_config():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    MyModel.__pydantic_validator__ = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema({'field_a': core_schema.model_field(core_schema.int_schema())}),
+            config=core_schema.CoreConfig(get_validators=True),
+        )
+    )
+
+    validators = MyModel.__get_validators__()
+    assert validators == [MyModel.__pydantic_validator__.validate_python]
+    m = validators[0]({'field_a': '1'})
+    assert isinstance(m, MyModel)
+    assert m.field_a == 1
+    assert m.__get_validators__() == validators
+
+    class SubModel(MyModel):
+        __pydantic_validator__ = SchemaValidator(core_schema.int_schema())
+
+    assert SubModel.__get_validators__()[0]('2') == 2
+
+
+def test_get_validators_config_default():
+    class MyModel:
+        pass
+
+    SchemaValidator(
+        core_schema.model_schema(
+            MyModel, core_schema.model_fields_schema({'field_a': core_schema.model_field(core_schema.int_schema())})
+        )
+    )
+    assert not hasattr(MyModel, '__get_validators__')
+
+
+@pytest.mark.parametrize('copy_on_model_validation', [None, 'none', 'shallow', 'deep'])
+def test_copy_on_model_validation(copy_on_model_validation):
+    class MyModel:
+        pass
+
+    config = {} if copy_on_model_validation is None else {'copy_on_model_validation': copy_on_model_validation}
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema({'items': core_schema.model_field(core_schema.list_schema())}),
+            config=config,
+        )
+    )
+    m = v.validate_python({'items': [1, 2]})
+    m2 = v.validate_python(m)
+    assert isinstance(m2, MyModel)
+    assert m2.items == [1, 2]
+    if copy_on_model_validation in (None, 'none'):
+        assert m2 is m
+    else:
+        assert m2 is not m
+        # a shallow copy shares the list with the original instance, a deep copy doesn't
+        assert (m2.items is m.items) == (copy_on_model_validation == 'shallow')
+
+
+def test_copy_on_model_validation_invalid():
+    with pytest.raises(SchemaError, match='Invalid copy_on_model_validation value: always'):
+        SchemaValidator(
+            {
+                'type': 'model',
+                'cls': type('MyModel', (), {}),
+                'copy_on_model_validation': 'always',
+                'schema': {'type': 'model-fields', 'fields': {}},
+            }
+        )
@@ -244,6 +244,24 @@ def test_validate_assignment():
     assert data == {'field_a': 'abc'}
 
 
+def test_validate_assignment_fields_set():
+    v = SchemaValidator(
+        {
+            'type': 'model-fields',
+            'fields': {
+                'field_a': {'type': 'model-field', 'schema': {'type': 'str'}},
+                'field_b': {'type': 'model-field', 'schema': {'type': 'int'}},
+            },
+        }
+    )
+
+    output, fields_set = v.validate_assignment(
+        {'field_a': 'test', 'field_b': 1}, 'field_b', '2', fields_set=frozenset({'field_a'})
+    )
+    assert output == ({'field_a': 'test', 'field_b': 2}, None, {'field_b'})
+    assert fields_set == frozenset({'field_a', 'field_b'})
+
+
 def test_validate_assignment_strict_field():
     v = SchemaValidator(
         {
@@ -1781,3 +1799,53 @@ def test_extra_behavior_ignore(config: Union[core_schema.CoreConfig, None], sche
         }
     ]
     assert 'not_f' not in m
+
+
+@pytest.mark.parametrize('include_defaults,expected_fields_set', [(None, {'a'}), (False, {'a'}), (True, {'a', 'b'})])
+def test_fields_set_include_defaults(include_defaults, expected_fields_set):
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=2)),
+            },
+            fields_set_include_defaults=include_defaults,
+        )
+    )
+    model_dict, model_extra, fields_set = v.validate_python({'a': 1})
+    assert model_dict == {'a': 1, 'b': 2}
+    assert model_extra is None
+    assert fields_set == expected_fields_set
+
+
+def test_nested_fields_set():
+    class Address:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    address_schema = core_schema.model_schema(
+        Address,
+        core_schema.model_fields_schema(
+            {
+                'city': core_schema.model_field(core_schema.str_schema()),
+                'zip': core_schema.model_field(core_schema.with_default_schema(core_schema.str_schema(), default='')),
+            }
+        ),
+    )
+    fields = {
+        'name': core_schema.model_field(core_schema.str_schema()),
+        'address': core_schema.model_field(address_schema),
+        'other': core_schema.model_field(core_schema.with_default_schema(address_schema, default=None)),
+    }
+    v = SchemaValidator(core_schema.model_fields_schema(fields, nested_fields_set=True))
+    _, _, fields_set = v.validate_python({'name': 'a', 'address': {'city': 'London'}})
+    assert fields_set == {'name', 'address', 'address.city'}
+
+    # an existing instance contributes its own fields set
+    address = SchemaValidator(address_schema).validate_python({'city': 'Paris', 'zip': '75001'})
+    _, _, fields_set = v.validate_python({'name': 'a', 'address': address})
+    assert fields_set == {'name', 'address', 'address.city', 'address.zip'}
+
+    _, _, fields_set = SchemaValidator(core_schema.model_fields_schema(fields)).validate_python(
+        {'name': 'a', 'address': {'city': 'London'}}
+    )
+    assert fields_set == {'name', 'address'}
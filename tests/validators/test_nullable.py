@@ -1,4 +1,5 @@
 import gc
+import math
 import platform
 import weakref
 
@@ -68,3 +69,63 @@ def test_leak_nullable():
     gc.collect()
 
     assert ref() is None
+
+
+def test_nullable_none_types():
+    missing = object()
+    nan = float('nan')
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.float_schema(), none_types=[missing, nan, '']))
+    assert v.validate_python(None) is None
+    assert v.validate_python(missing) is None
+    assert v.validate_python(nan) is None
+    assert v.validate_python('') is None
+    assert v.validate_python(1.5) == 1.5
+    assert v.validate_json('""') is None
+    assert v.validate_json('null') is None
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(object())
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'float_type'
+
+
+def test_nullable_none_types_nan():
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.float_schema(), none_types=[math.nan]))
+    assert v.validate_python(float('nan')) is None
+    assert v.validate_json('NaN') is None
+    assert v.validate_python(1.5) == 1.5
+
+
+def test_nullable_none_types_type_mismatch():
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.int_schema(), none_types=[0]))
+    assert v.validate_python(0) is None
+    assert v.validate_python(False) == 0
+    assert v.validate_python(0.0) == 0
+
+
+def test_nullable_none_types_eq_error():
+    class BadEq:
+        def __eq__(self, other):
+            raise ValueError('ambiguous')
+
+        __hash__ = object.__hash__
+
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.any_schema(), none_types=[BadEq()]))
+    value = BadEq()
+    assert v.validate_python(value) is value
+
+
+def test_nullable_none_types_default():
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.str_schema()))
+    assert v.validate_python('') == ''
+
+
+def test_nullable_null_value():
+    NULL = object()
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.int_schema(), null_value=NULL, none_types=['']))
+    assert v.validate_python(None) is NULL
+    assert v.validate_python('') is NULL
+    assert v.validate_json('null') is NULL
+    assert v.validate_python('1') == 1
+
+    v = SchemaValidator(core_schema.nullable_schema(core_schema.int_schema(), null_value=0))
+    assert v.validate_json('null') == 0
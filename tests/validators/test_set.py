@@ -138,6 +138,14 @@ def generate_repeats():
         # length check after set creation
         ({'max_length': 3}, [1, 1, 2, 2, 3, 3], {1, 2, 3}),
         ({'max_length': 3}, generate_repeats(), {1, 2, 3}),
+        ({'exact_length': 2}, [1, 2], {1, 2}),
+        # duplicates are removed before the length check
+        ({'exact_length': 2}, [1, 1, 2], {1, 2}),
+        (
+            {'exact_length': 2},
+            [1, 1],
+            Err('Set should have exactly 2 items after validation, not 1 [type=exact_length,'),
+        ),
         (
             {'max_length': 3},
             infinite_generator(),
@@ -277,3 +285,54 @@ def test_set_any(input_value, expected):
     output = v.validate_python(input_value)
     assert output == expected
     assert isinstance(output, set)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 'a', (1, 2)], {1, 'a', (1, 2)}),
+        (
+            [1, [2, 3]],
+            Err(
+                'Set items should be hashable, got an instance of list '
+                '[type=set_item_unhashable, input_value=[2, 3], input_type=list]'
+            ),
+        ),
+        (
+            [{'a': 1}, 2],
+            Err(
+                'Set items should be hashable, got an instance of dict '
+                "[type=set_item_unhashable, input_value={'a': 1}, input_type=dict]"
+            ),
+        ),
+    ],
+)
+def test_set_unhashable_item(input_value, expected):
+    v = SchemaValidator({'type': 'set'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_set_unhashable_item_errors(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'set', 'items_schema': {'type': 'list'}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([[1], [2]])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'set_item_unhashable',
+            'loc': (0,),
+            'msg': 'Set items should be hashable, got an instance of list',
+            'input': [1],
+            'ctx': {'type_name': 'list'},
+        },
+        {
+            'type': 'set_item_unhashable',
+            'loc': (1,),
+            'msg': 'Set items should be hashable, got an instance of list',
+            'input': [2],
+            'ctx': {'type_name': 'list'},
+        },
+    ]
@@ -1,3 +1,4 @@
+import json
 import re
 from decimal import Decimal
 from numbers import Number
@@ -5,7 +6,7 @@ from typing import Any, Dict, Union
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -208,6 +209,63 @@ def test_regex_error(engine):
     ]
 
 
+@pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
+def test_multiple_patterns(engine):
+    v = SchemaValidator(core_schema.str_schema(patterns=['.*[A-Z]', '.*[0-9]'], regex_engine=engine))
+    assert v.validate_python('Passw0rd') == 'Passw0rd'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('password')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_pattern_mismatch',
+            'loc': (),
+            'msg': "String should match pattern '.*[A-Z]'",
+            'input': 'password',
+            'ctx': {'pattern': '.*[A-Z]'},
+        }
+    ]
+    with pytest.raises(ValidationError, match=re.escape("String should match pattern '.*[0-9]'")):
+        v.validate_python('Password')
+
+
+def test_pattern_and_patterns():
+    with pytest.raises(SchemaError, match='`pattern` and `patterns` cannot be used together'):
+        SchemaValidator(core_schema.str_schema(pattern='a', patterns=['b']))
+
+
+@pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
+def test_not_patterns(engine):
+    v = SchemaValidator(core_schema.str_schema(not_patterns=['.*<[a-z]+>', '.*javascript:'], regex_engine=engine))
+    assert v.validate_python('hello world') == 'hello world'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('hello <b>world</b>')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_pattern_match',
+            'loc': (),
+            'msg': "String should not match pattern '.*<[a-z]+>'",
+            'input': 'hello <b>world</b>',
+            'ctx': {'pattern': '.*<[a-z]+>'},
+        }
+    ]
+    with pytest.raises(ValidationError, match=re.escape("String should not match pattern '.*javascript:'")):
+        v.validate_python('javascript:alert(1)')
+
+
+def test_not_pattern_with_pattern():
+    v = SchemaValidator(core_schema.str_schema(pattern='^[a-z]+$', not_pattern='^admin$'))
+    assert v.validate_python('alice') == 'alice'
+    with pytest.raises(ValidationError, match=re.escape("String should match pattern '^[a-z]+$'")):
+        v.validate_python('Alice')
+    with pytest.raises(ValidationError, match=re.escape("String should not match pattern '^admin$'")):
+        v.validate_python('admin')
+
+
+def test_not_pattern_and_not_patterns():
+    with pytest.raises(SchemaError, match='`not_pattern` and `not_patterns` cannot be used together'):
+        SchemaValidator(core_schema.str_schema(not_pattern='a', not_patterns=['b']))
+
+
 def test_default_validator():
     v = SchemaValidator(core_schema.str_schema(strict=True, to_lower=False), {'str_strip_whitespace': False})
     assert (
@@ -332,6 +390,238 @@ def test_coerce_numbers_to_str_from_json(number: str, expected_str: str) -> None
     assert v.validate_json(number) == expected_str
 
 
+def test_coerce_bool_to_str() -> None:
+    v = SchemaValidator(core_schema.str_schema(coerce_bool_to_str=True))
+    assert v.validate_python(True) == 'true'
+    assert v.validate_python(False) == 'false'
+    assert v.validate_python('True') == 'True'
+    assert v.validate_json('true') == 'true'
+    assert v.validate_json('false') == 'false'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': (), 'msg': 'Input should be a valid string', 'input': 1}
+    ]
+
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(True, strict=True)
+
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_json('true', strict=True)
+
+
+def test_coerce_bool_to_str_disabled() -> None:
+    v = SchemaValidator(core_schema.str_schema(coerce_numbers_to_str=True))
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(True)
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_json('false')
+
+
+def test_coerce_bool_to_str_constraints() -> None:
+    v = SchemaValidator(core_schema.str_schema(coerce_bool_to_str=True, to_upper=True, max_length=4))
+    assert v.validate_python(True) == 'TRUE'
+
+    with pytest.raises(ValidationError, match='String should have at most 4 characters'):
+        v.validate_python(False)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('foo', 'foo'),
+        ('  foo \n', 'foo'),
+        ('', Err('String should have at least 1 character [type=string_too_short')),
+        ('   ', Err('String should have at least 1 character [type=string_too_short')),
+        ('\t\n', Err('String should have at least 1 character [type=string_too_short')),
+    ],
+)
+def test_reject_empty_after_strip(input_value, expected):
+    v = SchemaValidator(core_schema.str_schema(reject_empty_after_strip=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'format,input_value,valid',
+    [
+        ('ipv4', '192.168.0.1', True),
+        ('ipv4', '256.0.0.1', False),
+        ('ipv4', '::1', False),
+        ('ipv6', '::1', True),
+        ('ipv6', '2001:db8::ff00:42:8329', True),
+        ('ipv6', '192.168.0.1', False),
+        ('ip', '192.168.0.1', True),
+        ('ip', '::1', True),
+        ('ip', 'localhost', False),
+        ('email', 'foo.bar+baz@example.com', True),
+        ('email', "o'neil@sub.example.co.uk", True),
+        ('email', 'foo@localhost', False),
+        ('email', 'foo..bar@example.com', False),
+        ('email', '.foo@example.com', False),
+        ('email', 'foo@-example.com', False),
+        ('email', 'foo bar@example.com', False),
+        ('email', 'example.com', False),
+        ('uri', 'https://example.com/path?q=1', True),
+        ('uri', 'mailto:foo@example.com', True),
+        ('uri', 'example.com', False),
+    ],
+)
+def test_str_format(format, input_value, valid):
+    v = SchemaValidator(core_schema.str_schema(format=format))
+    if valid:
+        output = v.validate_python(input_value)
+        assert output == input_value
+        assert type(output) is str
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        # insert_assert(exc_info.value.errors(include_url=False))
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'string_format_mismatch',
+                'loc': (),
+                'msg': f"String should match the '{format}' format",
+                'input': input_value,
+                'ctx': {'format': format},
+            }
+        ]
+
+
+def test_str_format_strip_whitespace():
+    v = SchemaValidator(core_schema.str_schema(format='ipv4', strip_whitespace=True))
+    assert v.validate_python(' 10.0.0.1\n') == '10.0.0.1'
+
+
+def test_str_format_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'ipv4', 'ipv6', 'ip', 'email', 'uri', 'hostname' or 'idna'"):
+        validate_core_schema({'type': 'str', 'format': 'uuid'})
+    with pytest.raises(SchemaError, match='Invalid string format: uuid'):
+        SchemaValidator({'type': 'str', 'format': 'uuid'})
+
+
+@pytest.mark.parametrize(
+    'format,input_value,expected_label',
+    [
+        ('hostname', 'example.com', None),
+        ('hostname', 'localhost', None),
+        ('hostname', 'Sub-1.Example.com.', None),
+        ('hostname', 'a' * 63 + '.com', None),
+        ('hostname', 'a' * 64 + '.com', 'a' * 64),
+        ('hostname', 'foo_bar.example.com', 'foo_bar'),
+        ('hostname', 'example..com', ''),
+        ('hostname', '-example.com', '-example'),
+        ('hostname', '', ''),
+        ('hostname', 'bücher.example', 'bücher'),
+        ('hostname', '.'.join(['a' * 63] * 4), '.'.join(['a' * 63] * 4)),
+        ('idna', 'example.com', None),
+        ('idna', 'bücher.example', None),
+        ('idna', 'münchen.例え.jp', None),
+        ('idna', 'xn--bcher-kva.example', None),
+        ('idna', 'foo_bar.bücher.example', 'foo_bar'),
+        ('idna', 'bü-.example', 'bü-'),
+        ('idna', 'bü cher.example', 'bü cher'),
+        ('idna', 'ü' * 60 + '.example', 'ü' * 60),
+    ],
+)
+def test_str_format_hostname(format, input_value, expected_label):
+    v = SchemaValidator(core_schema.str_schema(format=format))
+    if expected_label is None:
+        assert v.validate_python(input_value) == input_value
+        assert v.validate_json(json.dumps(input_value)) == input_value
+    else:
+        description = {'hostname': 'hostname', 'idna': 'internationalized domain name'}[format]
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': f'string_not_{format}',
+                'loc': (),
+                'msg': f"String should be a valid {description}, '{expected_label}' is invalid",
+                'input': input_value,
+                'ctx': {'label': expected_label},
+            }
+        ]
+
+
+def test_json_max_length_bytes():
+    v = SchemaValidator(core_schema.str_schema(json_max_length_bytes=4))
+    assert v.validate_json('"abcd"') == 'abcd'
+    assert v.validate_json('"éé"') == 'éé'
+    # python inputs aren't restricted
+    assert v.validate_python('ééé') == 'ééé'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"ééé"')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_long_bytes',
+            'loc': (),
+            'msg': 'String should have at most 4 bytes',
+            'input': 'ééé',
+            'ctx': {'max_length': 4},
+        }
+    ]
+
+
+def test_json_max_length_bytes_with_max_length():
+    v = SchemaValidator(core_schema.str_schema(max_length=3, json_max_length_bytes=4))
+    assert v.validate_json('"abc"') == 'abc'
+    with pytest.raises(ValidationError, match='String should have at most 3 characters'):
+        v.validate_json('"abcd"')
+    with pytest.raises(ValidationError, match='String should have at most 4 bytes'):
+        v.validate_json('"ééé"')
+
+
+def test_max_codepoints(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.str_schema(max_codepoints=3))
+    assert v.validate_test('abc') == 'abc'
+    # 'e' followed by a combining acute accent is one character on screen but two codepoints
+    assert v.validate_test('ae\u0301') == 'ae\u0301'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('abe\u0301')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_long_codepoints',
+            'loc': (),
+            'msg': 'String should have at most 3 codepoints',
+            'input': 'abe\u0301',
+            'ctx': {'max_length': 3},
+        }
+    ]
+
+
+def test_strict_subclass_check():
+    class SafeString(str):
+        pass
+
+    v = SchemaValidator(core_schema.str_schema(strict=True, strict_subclass_check=True))
+    assert v.validate_python('foo') == 'foo'
+    assert v.validate_json('"foo"') == 'foo'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(SafeString('foo'))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_type_exact',
+            'loc': (),
+            'msg': 'Input should be an exact string, not a subclass of str',
+            'input': SafeString('foo'),
+        }
+    ]
+
+    # subclasses are still accepted in lax mode, and without the check
+    v = SchemaValidator(core_schema.str_schema(strict_subclass_check=True))
+    assert v.validate_python(SafeString('foo')) == 'foo'
+    with pytest.raises(ValidationError, match=r'\[type=string_type_exact,'):
+        v.validate_python(SafeString('foo'), strict=True)
+    v = SchemaValidator(core_schema.str_schema(strict=True))
+    assert v.validate_python(SafeString('foo')) == 'foo'
+
+
 @pytest.mark.parametrize('mode', (None, 'schema', 'config'))
 def test_backtracking_regex_rust_unsupported(mode) -> None:
     pattern = r'r(#*)".*?"\1'
@@ -398,3 +688,44 @@ def test_coerce_numbers_to_str_schema_with_strict_mode(number: int):
         v.validate_python(number)
     with pytest.raises(ValidationError):
         v.validate_json(str(number))
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'starts_with': 'sk_'}, 'sk_123', 'sk_123'),
+        ({'starts_with': 'sk_'}, 'pk_123', Err("String should start with 'sk_' [type=string_starts_with")),
+        ({'starts_with_any': ['sk_', 'pk_']}, 'pk_123', 'pk_123'),
+        ({'starts_with_any': ['sk_', 'pk_']}, 'xx_1', Err("String should start with 'sk_' or 'pk_' [type=string_")),
+        ({'ends_with': '.json'}, 'a.json', 'a.json'),
+        ({'ends_with': '.json'}, 'a.yaml', Err("String should end with '.json' [type=string_ends_with")),
+        ({'starts_with': 'a', 'ends_with': 'z', 'strip_whitespace': True}, ' abcz ', 'abcz'),
+    ],
+)
+def test_starts_with_ends_with(py_and_json: PyAndJson, kwargs, input_value, expected):
+    v = py_and_json(core_schema.str_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_starts_with_error_ctx():
+    v = SchemaValidator(core_schema.str_schema(starts_with='sk_'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('pk_1')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'prefix': 'sk_'}
+
+    v = SchemaValidator(core_schema.str_schema(starts_with_any=['sk_', 'pk_']))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('xx_1')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'prefix': ['sk_', 'pk_']}
+
+    v = SchemaValidator(core_schema.str_schema(ends_with='.json'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('a.yaml')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'suffix': '.json'}
+
+    with pytest.raises(SchemaError, match='`starts_with` and `starts_with_any` cannot be used together'):
+        SchemaValidator(core_schema.str_schema(starts_with='a', starts_with_any=['b']))
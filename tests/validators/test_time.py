@@ -190,8 +190,51 @@ def test_time_bound_ctx():
 
 
 def test_invalid_constraint():
-    with pytest.raises(SchemaError, match='Input should be in a valid time format'):
-        validate_core_schema({'type': 'time', 'gt': 'foobar'})
+    with pytest.raises(SchemaError, match='Invalid `gt` constraint, invalid character in hour'):
+        SchemaValidator({'type': 'time', 'gt': 'foobar'})
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'ge': '09:00:00', 'le': '17:00:00'}, '12:30', time(12, 30)),
+        (
+            {'ge': '09:00:00', 'le': '17:00:00'},
+            time(8, 59),
+            Err('Input should be greater than or equal to 09:00:00 [type=greater_than_equal,'),
+        ),
+        (
+            {'ge': '09:00:00', 'le': '17:00:00'},
+            '17:00:01',
+            Err('Input should be less than or equal to 17:00:00 [type=less_than_equal,'),
+        ),
+        ({'lt': '12:00:00Z'}, '13:00:00+02:00', time(13, tzinfo=timezone(timedelta(hours=2)))),
+        ({'lt': '12:00:00Z'}, '13:00:00+01:00', Err('Input should be less than 12:00:00Z [type=less_than,')),
+    ],
+)
+def test_iso_time_constraints(kwargs: Dict[str, Any], input_value, expected):
+    v = SchemaValidator({'type': 'time', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_iso_time_constraint_invalid():
+    with pytest.raises(SchemaError, match='Invalid `gt` constraint, input is too short'):
+        SchemaValidator({'type': 'time', 'gt': 'foo'})
+
+
+def test_constraint_tz_awareness_mismatch():
+    v = SchemaValidator({'type': 'time', 'ge': time(9, tzinfo=timezone.utc)})
+    assert v.validate_python(time(10, tzinfo=timezone.utc)) == time(10, tzinfo=timezone.utc)
+    with pytest.raises(ValidationError, match=r'Input should have timezone info \[type=timezone_aware,'):
+        v.validate_python(time(10))
+
+    v = SchemaValidator({'type': 'time', 'ge': time(9)})
+    with pytest.raises(ValidationError, match=r'Input should not have timezone info \[type=timezone_naive,'):
+        v.validate_python(time(10, tzinfo=timezone.utc))
 
 
 def test_dict_py():
@@ -5,7 +5,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError, validate_core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson
 
@@ -195,11 +195,43 @@ def test_timedelta_kwargs_strict():
 
 
 def test_invalid_constraint():
-    with pytest.raises(SchemaError, match='timedelta.gt\n  Input should be a valid timedelta, invalid digit in'):
-        validate_core_schema({'type': 'timedelta', 'gt': 'foobar'})
+    with pytest.raises(SchemaError, match='Invalid `gt` constraint, invalid digit in duration'):
+        SchemaValidator({'type': 'timedelta', 'gt': 'foobar'})
 
-    with pytest.raises(SchemaError, match='timedelta.le\n  Input should be a valid timedelta, invalid digit in'):
-        validate_core_schema({'type': 'timedelta', 'le': 'foobar'})
+    with pytest.raises(SchemaError, match='Invalid `le` constraint, invalid digit in duration'):
+        SchemaValidator({'type': 'timedelta', 'le': 'foobar'})
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'ge': 'PT1H', 'le': 'P1D'}, timedelta(hours=2), timedelta(hours=2)),
+        (
+            {'ge': 'PT1H', 'le': 'P1D'},
+            'PT30M',
+            Err('Input should be greater than or equal to 1 hour [type=greater_than_equal'),
+        ),
+        (
+            {'ge': 'PT1H', 'le': 'P1D'},
+            'P1DT1S',
+            Err('Input should be less than or equal to 1 day [type=less_than_equal'),
+        ),
+        ({'lt': '-PT1.5S'}, timedelta(seconds=-2), timedelta(seconds=-2)),
+        ({'gt': 'P1W'}, 'P7D', Err('Input should be greater than 7 days [type=greater_than')),
+    ],
+)
+def test_iso_duration_constraints(kwargs: Dict[str, Any], input_value, expected):
+    v = SchemaValidator({'type': 'timedelta', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_iso_duration_constraint_invalid():
+    with pytest.raises(SchemaError, match='Invalid `le` constraint, invalid digit in duration'):
+        SchemaValidator({'type': 'timedelta', 'le': 'foobar'})
 
 
 def test_dict_py():
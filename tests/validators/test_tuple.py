@@ -119,6 +119,17 @@ def test_tuple_strict_fails_without_tuple(wrong_coll_type: Type[Any], variadic_i
             infinite_generator(),
             Err('Tuple should have at most 3 items after validation, not more [type=too_long,'),
         ),
+        ({'exact_length': 2}, (1, 2), (1, 2)),
+        (
+            {'exact_length': 2},
+            (1, 2, 3),
+            Err('Tuple should have exactly 2 items after validation, not 3 [type=exact_length,'),
+        ),
+        (
+            {'exact_length': 2},
+            [1],
+            Err('Tuple should have exactly 2 items after validation, not 1 [type=exact_length,'),
+        ),
     ],
     ids=repr,
 )
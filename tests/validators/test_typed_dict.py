@@ -1,14 +1,26 @@
+import dataclasses
 import gc
 import math
 import platform
 import re
 import weakref
-from typing import Any, Dict, Mapping, Union
+from collections import OrderedDict
+from datetime import date
+from types import MappingProxyType
+from typing import Any, Dict, Mapping, NamedTuple, Union
 
 import pytest
-from dirty_equals import FunctionCheck
-
-from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
+from dirty_equals import FunctionCheck, HasRepr
+
+from pydantic_core import (
+    CoreConfig,
+    FieldInfo,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    validate_core_schema,
+)
 
 from ..conftest import Err, PyAndJson
 
@@ -154,6 +166,32 @@ def test_config(config: CoreConfig, input_value, expected):
         assert output_dict == expected
 
 
+@pytest.mark.parametrize(
+    'input_value',
+    [OrderedDict(a=1, b=2.5), MappingProxyType({'a': 1, 'b': 2.5}), Map(a=1, b=2.5)],
+    ids=repr,
+)
+@pytest.mark.parametrize('from_attributes', [False, True])
+def test_mapping_input(input_value, from_attributes):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.float_schema()),
+            },
+            config=CoreConfig(from_attributes=from_attributes),
+        )
+    )
+    assert v.validate_python(input_value) == {'a': 1, 'b': 2.5}
+
+    if isinstance(input_value, dict):
+        # dict subclasses are allowed in strict mode
+        assert v.validate_python(input_value, strict=True) == {'a': 1, 'b': 2.5}
+    else:
+        with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+            v.validate_python(input_value, strict=True)
+
+
 def test_ignore_extra():
     v = SchemaValidator(
         {
@@ -211,6 +249,46 @@ def test_str_config():
         v.validate_python({'field_a': 'test long'})
 
 
+def test_str_config_field_priority():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'field_a': {'type': 'typed-dict-field', 'schema': {'type': 'str'}},
+                'field_b': {'type': 'typed-dict-field', 'schema': {'type': 'str', 'min_length': 1, 'max_length': 10}},
+            },
+            'config': {'str_min_length': 3, 'str_max_length': 5},
+        }
+    )
+    assert v.validate_python({'field_a': 'abc', 'field_b': 'a'}) == {'field_a': 'abc', 'field_b': 'a'}
+    assert v.validate_python({'field_a': 'abcde', 'field_b': 'test long'}) == {
+        'field_a': 'abcde',
+        'field_b': 'test long',
+    }
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'ab', 'field_b': ''})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_short',
+            'loc': ('field_a',),
+            'msg': 'String should have at least 3 characters',
+            'input': 'ab',
+            'ctx': {'min_length': 3},
+        },
+        {
+            'type': 'string_too_short',
+            'loc': ('field_b',),
+            'msg': 'String should have at least 1 character',
+            'input': '',
+            'ctx': {'min_length': 1},
+        },
+    ]
+
+    with pytest.raises(ValidationError, match='String should have at most 5 characters'):
+        v.validate_python({'field_a': 'test long', 'field_b': 'a'})
+
+
 def test_json_error():
     v = SchemaValidator(
         {
@@ -638,6 +716,29 @@ def test_alias_build_error(alias_schema, error):
         )
 
 
+def test_alias_validation_alias(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(core_schema.int_schema(), alias='FieldA'),
+                'field_b': core_schema.typed_dict_field(
+                    core_schema.int_schema(), alias='FieldB', validation_alias=[['b'], ['B']]
+                ),
+            }
+        )
+    )
+    assert v.validate_test({'FieldA': 1, 'b': 2}) == {'field_a': 1, 'field_b': 2}
+    assert v.validate_test({'FieldA': 1, 'B': 2}) == {'field_a': 1, 'field_b': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'field_a': 1, 'FieldB': 2})
+    # errors use the alias, or the first validation alias
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('FieldA',), 'msg': 'Field required', 'input': {'field_a': 1, 'FieldB': 2}},
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'field_a': 1, 'FieldB': 2}},
+    ]
+
+
 def test_alias_error_loc_alias(py_and_json: PyAndJson):
     v = py_and_json(
         {
@@ -1196,3 +1297,863 @@ def test_leak_typed_dict():
     gc.collect()
 
     assert ref() is None
+
+
+def test_validate_assignment():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+
+    data = {'a': 'x', 'b': 1}
+    assert v.validate_assignment(data, 'b', '42') == {'a': 'x', 'b': 42}
+    assert data == {'a': 'x', 'b': 42}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment({'a': 'x', 'b': 1}, 'b', 'wrong')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment({'a': 'x', 'b': 1}, 'c', 1)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'no_such_attribute',
+            'loc': ('c',),
+            'msg': "Object has no attribute 'c'",
+            'input': 1,
+            'ctx': {'attribute': 'c'},
+        }
+    ]
+
+
+def test_validate_assignment_extra_allow():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.str_schema())},
+            extra_behavior='allow',
+            extras_schema=core_schema.int_schema(),
+        )
+    )
+
+    assert v.validate_assignment({'a': 'x'}, 'c', '1') == {'a': 'x', 'c': 1}
+
+
+def test_validate_assignment_fields_set():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema(), required=False),
+            }
+        )
+    )
+
+    output, fields_set = v.validate_assignment({'a': 'x'}, 'b', '2', fields_set=frozenset({'a'}))
+    assert output == {'a': 'x', 'b': 2}
+    assert fields_set == {'a', 'b'}
+    assert isinstance(fields_set, frozenset)
+
+
+def test_get_fields():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    assert v.get_name() == 'typed-dict'
+    assert v.get_fields() == ['a', 'b']
+
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.get_name() == 'int'
+    assert v.get_fields() is None
+
+    v = SchemaValidator(core_schema.typed_dict_schema({}), {'title': 'MyTypedDict'})
+    assert v.get_name() == 'MyTypedDict'
+    assert v.get_fields() == []
+
+
+def test_get_model_fields():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema(), validation_alias='A'),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=42), required=False
+                ),
+                'c': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.list_schema(), default_factory=list)
+                ),
+            }
+        )
+    )
+    fields = v.get_model_fields()
+    assert list(fields) == ['a', 'b', 'c']
+
+    a = fields['a']
+    assert isinstance(a, FieldInfo)
+    assert (a.required, a.alias, a.has_default, a.default) == (True, 'A', False, None)
+    assert repr(a) == "FieldInfo(required=True, alias='A', has_default=False, default=None)"
+    assert (fields['b'].required, fields['b'].alias, fields['b'].has_default, fields['b'].default) == (
+        False,
+        None,
+        True,
+        42,
+    )
+    # default factories are not called
+    assert (fields['c'].has_default, fields['c'].default) == (True, None)
+
+    assert SchemaValidator(core_schema.int_schema()).get_model_fields() is None
+
+
+@pytest.fixture
+def partial_validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {
+                            'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                            'y': core_schema.typed_dict_field(core_schema.int_schema()),
+                        }
+                    )
+                ),
+            },
+            extra_behavior='forbid',
+        )
+    )
+
+
+def test_partial_include(partial_validator: SchemaValidator):
+    v = partial_validator
+    assert v.validate_python({'a': '1'}, include={'a'}) == {'a': 1}
+    # fields which aren't included are neither validated nor returned
+    assert v.validate_python({'a': '1', 'b': 'wrong'}, include={'a'}) == {'a': 1}
+    assert v.validate_python({'c': {'x': '1'}}, include={'c.x'}) == {'c': {'x': 1}}
+    # including the whole field takes precedence over a nested path
+    assert v.validate_python({'c': {'x': 1, 'y': 2}}, include={'c', 'c.x'}) == {'c': {'x': 1, 'y': 2}}
+    assert v.validate_python({'c': {'x': 1, 'y': 2}}, include={'c.x', 'c'}) == {'c': {'x': 1, 'y': 2}}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'b': 2}, include={'a', 'c.y'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('a',), 'msg': 'Field required', 'input': {'b': 2}},
+        {'type': 'missing', 'loc': ('c',), 'msg': 'Field required', 'input': {'b': 2}},
+    ]
+
+
+def test_partial_exclude(partial_validator: SchemaValidator):
+    v = partial_validator
+    assert v.validate_python({'a': 1, 'c': {'x': 1, 'y': 2}}, exclude={'b'}) == {'a': 1, 'c': {'x': 1, 'y': 2}}
+    assert v.validate_python({'a': 1, 'b': 'wrong', 'c': {'x': 1}}, exclude={'b', 'c.y'}) == {
+        'a': 1,
+        'c': {'x': 1},
+    }
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'c': {'y': 2}}, exclude={'b', 'c.y'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('c', 'x'), 'msg': 'Field required', 'input': {'y': 2}}
+    ]
+
+
+def test_partial_include_exclude(partial_validator: SchemaValidator):
+    assert partial_validator.validate_python({'a': 1, 'c': {'x': 1, 'y': 2}}, include={'a', 'c'}, exclude={'c.y'}) == {
+        'a': 1,
+        'c': {'x': 1},
+    }
+
+
+def test_discriminator_name():
+    def pet(pet_type: str, **fields):
+        return core_schema.typed_dict_schema(
+            {
+                'pet_type': core_schema.typed_dict_field(core_schema.literal_schema([pet_type])),
+                **{name: core_schema.typed_dict_field(schema) for name, schema in fields.items()},
+            },
+            discriminator='pet_type',
+        )
+
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [pet('cat', meows=core_schema.int_schema()), pet('dog', barks=core_schema.float_schema())]
+        )
+    )
+    assert v.validate_python({'pet_type': 'cat', 'meows': 3}) == {'pet_type': 'cat', 'meows': 3}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'pet_type': 'dog', 'barks': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'literal_error',
+            'loc': ('pet_type:cat', 'pet_type'),
+            'msg': "Input should be 'cat'",
+            'input': 'dog',
+            'ctx': {'expected': "'cat'"},
+        },
+        {
+            'type': 'missing',
+            'loc': ('pet_type:cat', 'meows'),
+            'msg': 'Field required',
+            'input': {'pet_type': 'dog', 'barks': 'x'},
+        },
+        {
+            'type': 'float_parsing',
+            'loc': ('pet_type:dog', 'barks'),
+            'msg': 'Input should be a valid number, unable to parse string as a number',
+            'input': 'x',
+        },
+    ]
+
+
+@pytest.mark.parametrize(
+    'fields,error',
+    [
+        ({}, 'Discriminator field "pet_type" is not a field'),
+        (
+            {'pet_type': core_schema.typed_dict_field(core_schema.str_schema())},
+            'Discriminator field "pet_type" must be a literal with a single expected value',
+        ),
+        (
+            {'pet_type': core_schema.typed_dict_field(core_schema.literal_schema(['cat', 'dog']))},
+            'Discriminator field "pet_type" must be a literal with a single expected value',
+        ),
+    ],
+)
+def test_discriminator_name_invalid(fields, error):
+    with pytest.raises(SchemaError, match=error):
+        SchemaValidator(core_schema.typed_dict_schema(fields, discriminator='pet_type'))
+
+
+def test_post_init_method():
+    calls = []
+
+    class MyTypedDict:
+        @staticmethod
+        def post_init(d, context):
+            calls.append(context)
+            d['b'] = d['a'] * 2
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())}, cls=MyTypedDict, post_init='post_init'
+        )
+    )
+    assert v.validate_python({'a': '1'}) == {'a': 1, 'b': 2}
+    assert v.validate_python({'a': 2}, context={'x': 1}) == {'a': 2, 'b': 4}
+    assert calls == [None, {'x': 1}]
+
+
+def test_post_init_function():
+    def post_init(d):
+        if d['a'] > d['b']:
+            raise ValueError('a must not be greater than b')
+        return {**d, 'total': d['a'] + d['b']}
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            },
+            post_init_function=post_init,
+        )
+    )
+    assert v.validate_python({'a': 1, 'b': '2'}) == {'a': 1, 'b': 2, 'total': 3}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 3, 'b': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'value_error',
+            'loc': (),
+            'msg': 'Value error, a must not be greater than b',
+            'input': {'a': 3, 'b': 2},
+            'ctx': {'error': HasRepr(repr(ValueError('a must not be greater than b')))},
+        }
+    ]
+
+
+def test_post_init_function_not_called_on_error():
+    calls = []
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())}, post_init_function=calls.append
+        )
+    )
+    with pytest.raises(ValidationError):
+        v.validate_python({'a': 'x'})
+    assert calls == []
+
+
+@pytest.mark.parametrize(
+    'kwargs,error',
+    [
+        ({'post_init': 'post_init'}, '`post_init` requires `cls` to be set'),
+        (
+            {'cls': dict, 'post_init': 'copy', 'post_init_function': lambda d: d},
+            '`post_init` and `post_init_function` cannot be used together',
+        ),
+    ],
+)
+def test_post_init_invalid(kwargs, error):
+    fields = {'a': core_schema.typed_dict_field(core_schema.int_schema())}
+    with pytest.raises(SchemaError, match=error):
+        SchemaValidator(core_schema.typed_dict_schema(fields, **kwargs))
+
+
+def test_required_if():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'type': core_schema.typed_dict_field(core_schema.literal_schema(['person', 'company'])),
+                'company_name': core_schema.typed_dict_field(
+                    core_schema.str_schema(), required_if={'field': 'type', 'value': 'company'}
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'type': 'person'}) == {'type': 'person'}
+    assert v.validate_python({'type': 'company', 'company_name': 'Acme'}) == {'type': 'company', 'company_name': 'Acme'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'type': 'company'})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'missing_conditional_field',
+            'loc': ('company_name',),
+            'msg': "Field required when 'type' is 'company'",
+            'input': {'type': 'company'},
+            'ctx': {'condition_field': 'type', 'condition_value': "'company'"},
+        }
+    ]
+
+    # the condition can't be met if the other field is invalid, so only its error is raised
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'type': 'charity'})
+    assert [e['type'] for e in exc_info.value.errors()] == ['literal_error']
+
+
+def test_required_if_default():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=0),
+                    required_if={'field': 'a', 'value': 1},
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'a': '1'}) == {'a': 1, 'b': 0}
+
+
+@pytest.mark.parametrize(
+    'field,error',
+    [
+        (
+            core_schema.typed_dict_field(
+                core_schema.int_schema(), required=True, required_if={'field': 'a', 'value': 1}
+            ),
+            "Field 'b': a required field cannot have `required_if`",
+        ),
+        (
+            core_schema.typed_dict_field(core_schema.int_schema(), required_if={'field': 'c', 'value': 1}),
+            "Field 'b': `required_if` refers to unknown field 'c'",
+        ),
+    ],
+)
+def test_required_if_invalid(field, error):
+    fields = {'a': core_schema.typed_dict_field(core_schema.int_schema()), 'b': field}
+    with pytest.raises(SchemaError, match=error):
+        SchemaValidator(core_schema.typed_dict_schema(fields))
+
+
+class PointTuple(NamedTuple):
+    x: int
+    y: int = 0
+
+
+@dataclasses.dataclass
+class PointDataclass:
+    x: int
+    y: int = 0
+
+    def __post_init__(self):
+        if self.x < 0:
+            raise ValueError('x must not be negative')
+
+
+@pytest.mark.parametrize('output_class', [PointTuple, PointDataclass])
+def test_output_class(output_class):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                'y': core_schema.typed_dict_field(core_schema.int_schema(), required=False),
+            },
+            output_class=output_class,
+        )
+    )
+    assert v.validate_python({'x': '1', 'y': 2}) == output_class(1, 2)
+    assert v.validate_python({'x': 1}) == output_class(1)
+
+    with pytest.raises(ValidationError, match=r'x\s+Input should be a valid integer'):
+        v.validate_python({'x': 'wrong'})
+
+
+def test_output_class_errors():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'x': core_schema.typed_dict_field(core_schema.int_schema())},
+            extra_behavior='allow',
+            output_class=PointDataclass,
+        )
+    )
+    with pytest.raises(ValidationError, match=r'Value error, x must not be negative \[type=value_error'):
+        v.validate_python({'x': -1})
+
+    with pytest.raises(TypeError, match="unexpected keyword argument 'z'"):
+        v.validate_python({'x': 1, 'z': 3})
+
+
+def test_output_class_post_init_function():
+    with pytest.raises(SchemaError, match='`output_class` and `post_init_function` cannot be used together'):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                {'x': core_schema.typed_dict_field(core_schema.int_schema())},
+                output_class=PointTuple,
+                post_init_function=lambda d: d,
+            )
+        )
+
+
+def test_from_sequence(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.str_schema(), validation_alias='Name'),
+                'score': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.float_schema(), default=0.0)
+                ),
+            },
+            from_sequence=['id', 'name', 'score'],
+        )
+    )
+    assert v.validate_test([1, 'foo', 2.5]) == {'id': 1, 'name': 'foo', 'score': 2.5}
+    # trailing fields use their defaults
+    assert v.validate_test(['2', 'bar']) == {'id': 2, 'name': 'bar', 'score': 0.0}
+    # surplus items are ignored unless extra is forbidden
+    assert v.validate_test([1, 'foo', 2.5, 'extra']) == {'id': 1, 'name': 'foo', 'score': 2.5}
+    assert v.validate_test({'id': 1, 'Name': 'foo'}) == {'id': 1, 'name': 'foo', 'score': 0.0}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(['x'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('id',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {'type': 'missing', 'loc': ('Name',), 'msg': 'Field required', 'input': {'id': 'x'}},
+    ]
+
+
+def test_from_sequence_python_tuple():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())}, from_sequence=['a']
+        )
+    )
+    assert v.validate_python((1,)) == {'a': 1}
+    # strings and other iterables aren't treated as sequences of fields
+    with pytest.raises(ValidationError, match=r'Input should be a valid dictionary \[type=dict_type'):
+        v.validate_python('1')
+    with pytest.raises(ValidationError, match=r'Input should be a valid dictionary \[type=dict_type'):
+        v.validate_python((1,), strict=True)
+
+
+def test_from_sequence_extra_forbid():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())},
+            from_sequence=['a'],
+            extra_behavior='forbid',
+        )
+    )
+    assert v.validate_python([1]) == {'a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2, 3])
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': (1,), 'msg': 'Extra inputs are not permitted', 'input': 2},
+        {'type': 'extra_forbidden', 'loc': (2,), 'msg': 'Extra inputs are not permitted', 'input': 3},
+    ]
+
+
+def test_from_sequence_unknown_field():
+    with pytest.raises(SchemaError, match="`from_sequence` refers to unknown field 'b'"):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                {'a': core_schema.typed_dict_field(core_schema.int_schema())}, from_sequence=['a', 'b']
+            )
+        )
+
+
+def test_strict_dict_only():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())}, strict=True, strict_dict_only=True
+        )
+    )
+    assert v.validate_python({'a': 1}) == {'a': 1}
+    assert v.validate_json('{"a": 1}') == {'a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(OrderedDict(a=1))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'dict_type_exact',
+            'loc': (),
+            'msg': 'Input should be an exact dictionary, not a subclass of dict',
+            'input': OrderedDict(a=1),
+        }
+    ]
+    with pytest.raises(ValidationError, match=r'Input should be a valid dictionary \[type=dict_type'):
+        v.validate_python([('a', 1)])
+
+    # dict subclasses are still accepted in lax mode
+    assert v.validate_python(OrderedDict(a=1), strict=False) == {'a': 1}
+
+
+def test_field_aliases(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {'new_name': core_schema.typed_dict_field(core_schema.int_schema())},
+            extra_behavior='forbid',
+            field_aliases={'new_name': 'old_name'},
+        )
+    )
+    assert v.validate_test({'new_name': 1}) == {'new_name': 1}
+    assert v.validate_test({'old_name': '2'}) == {'new_name': 2}
+    assert v.validate_test({'new_name': 3, 'old_name': 3}) == {'new_name': 3}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'new_name': 1, 'old_name': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'ambiguous_field',
+            'loc': ('new_name',),
+            'msg': "Field 'new_name' and its old name 'old_name' have different values",
+            'input': {'new_name': 1, 'old_name': 2},
+            'ctx': {'field_name': 'new_name', 'old_name': 'old_name'},
+        }
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'old_name': 'x'})
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ('old_name',)
+    with pytest.raises(ValidationError, match=r'new_name\n  Field required \[type=missing'):
+        v.validate_test({})
+
+
+def test_field_aliases_unknown_field():
+    with pytest.raises(SchemaError, match="`field_aliases` refers to unknown field 'missing'"):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                {'a': core_schema.typed_dict_field(core_schema.int_schema())}, field_aliases={'missing': 'a'}
+            )
+        )
+
+
+def test_output_follows_field_order(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'c': core_schema.typed_dict_field(core_schema.int_schema()),
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=0), required=False
+                ),
+            },
+            extra_behavior='allow',
+        )
+    )
+    assert list(v.validate_test({'x': 1, 'a': 2, 'c': 3})) == ['c', 'a', 'b', 'x']
+    assert list(v.validate_test({'b': 1, 'a': 2, 'c': 3})) == ['c', 'a', 'b']
+
+
+def optional_fields(*names: str) -> Dict[str, Any]:
+    return {name: core_schema.typed_dict_field(core_schema.int_schema(), required=False) for name in names}
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'a': 1}, {'a': 1}),
+        ({'b': 1, 'c': 2}, {'b': 1, 'c': 2}),
+        ({}, Err("Exactly one of the fields 'a', 'b' should be provided [type=exactly_one_of")),
+        ({'a': 1, 'b': 2}, Err("Exactly one of the fields 'a', 'b' should be provided [type=exactly_one_of")),
+    ],
+)
+def test_require_exactly_one_of(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.typed_dict_schema(optional_fields('a', 'b', 'c'), require_exactly_one_of=['a', 'b']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['loc'] == ()
+        assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'fields': "'a', 'b'"}
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_require_at_least_and_at_most_one_of():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            optional_fields('a', 'b', 'c', 'd'), require_at_least_one_of=['a', 'b'], require_at_most_one_of=['c', 'd']
+        )
+    )
+    assert v.validate_python({'a': 1, 'b': 2, 'c': 3}) == {'a': 1, 'b': 2, 'c': 3}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'c': 3, 'd': 4})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'at_least_one_of',
+            'loc': (),
+            'msg': "At least one of the fields 'a', 'b' should be provided",
+            'input': {'c': 3, 'd': 4},
+            'ctx': {'fields': "'a', 'b'"},
+        },
+        {
+            'type': 'at_most_one_of',
+            'loc': (),
+            'msg': "At most one of the fields 'c', 'd' should be provided",
+            'input': {'c': 3, 'd': 4},
+            'ctx': {'fields': "'c', 'd'"},
+        },
+    ]
+
+
+
+def test_require_exactly_one_of_defaults():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                name: core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.nullable_schema(core_schema.int_schema()), default=None)
+                )
+                for name in ('a', 'b')
+            },
+            require_exactly_one_of=['a', 'b'],
+        )
+    )
+    # fields set from their default don't count as provided
+    assert v.validate_python({'a': 1}) == {'a': 1, 'b': None}
+    assert v.validate_python({'b': None}) == {'a': None, 'b': None}
+    with pytest.raises(ValidationError, match="Exactly one of the fields 'a', 'b' should be provided"):
+        v.validate_python({})
+    with pytest.raises(ValidationError, match="Exactly one of the fields 'a', 'b' should be provided"):
+        v.validate_python({'a': 1, 'b': 2})
+
+def test_require_exactly_one_of_invalid_field():
+    v = SchemaValidator(core_schema.typed_dict_schema(optional_fields('a', 'b'), require_exactly_one_of=['a', 'b']))
+    # the group isn't checked when a field is invalid, so only the field's error is reported
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_parsing']
+
+
+def test_require_exactly_one_of_unknown_field():
+    with pytest.raises(SchemaError, match="`require_exactly_one_of` refers to unknown field 'x'"):
+        SchemaValidator(core_schema.typed_dict_schema(optional_fields('a'), require_exactly_one_of=['a', 'x']))
+
+
+def test_field_ordering(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'start': core_schema.typed_dict_field(core_schema.date_schema()),
+                'end': core_schema.typed_dict_field(core_schema.date_schema(), required=False),
+            },
+            field_ordering=[{'field': 'end', 'ge_field': 'start'}],
+        )
+    )
+    assert v.validate_test({'start': '2024-01-01', 'end': '2024-01-01'}) == {
+        'start': date(2024, 1, 1),
+        'end': date(2024, 1, 1),
+    }
+    assert v.validate_test({'start': '2024-01-01'}) == {'start': date(2024, 1, 1)}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'start': '2024-01-02', 'end': '2024-01-01'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'field_ordering_violation',
+            'loc': ('end',),
+            'msg': "Field 'end' should be greater than or equal to field 'start'",
+            'input': date(2024, 1, 1),
+            'ctx': {'field': 'end', 'operator': '>=', 'other_field': 'start'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'operator,a,b,expected',
+    [
+        ('gt_field', 2, 1, None),
+        ('gt_field', 1, 1, "Field 'a' should be greater than field 'b'"),
+        ('lt_field', 1, 2, None),
+        ('le_field', 2, 1, "Field 'a' should be less than or equal to field 'b'"),
+    ],
+)
+def test_field_ordering_operators(operator, a, b, expected):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            },
+            field_ordering=[{'field': 'a', operator: 'b'}],
+        )
+    )
+    if expected is None:
+        assert v.validate_python({'a': a, 'b': b}) == {'a': a, 'b': b}
+    else:
+        with pytest.raises(ValidationError, match=re.escape(expected)):
+            v.validate_python({'a': a, 'b': b})
+
+
+
+def test_field_ordering_optional():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'start': core_schema.typed_dict_field(core_schema.date_schema()),
+                'end': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.nullable_schema(core_schema.date_schema()), default=None
+                    )
+                ),
+            },
+            field_ordering=[{'field': 'end', 'ge_field': 'start'}],
+        )
+    )
+    assert v.validate_python({'start': '2020-01-01'}) == {'start': date(2020, 1, 1), 'end': None}
+    assert v.validate_python({'start': '2020-01-01', 'end': None}) == {'start': date(2020, 1, 1), 'end': None}
+
+
+def test_field_ordering_incomparable():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.any_schema()),
+                'b': core_schema.typed_dict_field(core_schema.any_schema()),
+            },
+            field_ordering=[{'field': 'a', 'gt_field': 'b'}],
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'field_ordering_violation',
+            'loc': ('a',),
+            'msg': "Field 'a' should be greater than field 'b'",
+            'input': 1,
+            'ctx': {'field': 'a', 'operator': '>', 'other_field': 'b'},
+        }
+    ]
+
+def test_field_ordering_invalid_schema():
+    fields = {'a': core_schema.typed_dict_field(core_schema.int_schema())}
+    with pytest.raises(SchemaError, match="`field_ordering` refers to unknown field 'b'"):
+        SchemaValidator(core_schema.typed_dict_schema(fields, field_ordering=[{'field': 'a', 'ge_field': 'b'}]))
+    with pytest.raises(SchemaError, match="`field_ordering` entry for 'a' must have exactly one operator"):
+        SchemaValidator(
+            core_schema.typed_dict_schema(fields, field_ordering=[{'field': 'a', 'ge_field': 'a', 'lt_field': 'a'}])
+        )
+
+
+def test_schema_title():
+    fields = {'a': core_schema.typed_dict_field(core_schema.int_schema())}
+    v = SchemaValidator(core_schema.typed_dict_schema(fields, title='UserProfile'))
+    assert v.title == 'UserProfile'
+    with pytest.raises(ValidationError, match='1 validation error for UserProfile'):
+        v.validate_python({'a': 'x'})
+
+    # a title from config takes precedence
+    v = SchemaValidator(core_schema.typed_dict_schema(fields, title='UserProfile'), {'title': 'FromConfig'})
+    assert v.title == 'FromConfig'
+
+    assert SchemaValidator(core_schema.typed_dict_schema(fields)).title == 'typed-dict'
+
+
+def test_pattern_properties(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {'name': core_schema.typed_dict_field(core_schema.str_schema())},
+            pattern_properties=[
+                {'pattern': '^x-id', 'schema': core_schema.str_schema()},
+                {'pattern': '^x-', 'schema': core_schema.int_schema()},
+            ],
+        )
+    )
+    assert v.validate_test({'name': 'a', 'x-count': '1', 'x-id': 'abc', 'other': 3}) == {
+        'name': 'a',
+        'x-count': 1,
+        'x-id': 'abc',
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'name': 'a', 'x-count': 'many', 'x-idx': 'foo'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('x-count',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'many',
+        }
+    ]
+
+
+def test_pattern_properties_extra_forbid():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'name': core_schema.typed_dict_field(core_schema.str_schema())},
+            pattern_properties=[{'pattern': '^x-', 'schema': core_schema.any_schema()}],
+            extra_behavior='forbid',
+        )
+    )
+    assert v.validate_python({'name': 'a', 'x-a': 1}) == {'name': 'a', 'x-a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'a', 'x-a': 1, 'y-a': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('y-a',), 'msg': 'Extra inputs are not permitted', 'input': 2}
+    ]
+
+    assert v.validate_assignment({'name': 'a'}, 'x-b', 2) == {'name': 'a', 'x-b': 2}
+    with pytest.raises(ValidationError, match="Object has no attribute 'y-b'"):
+        v.validate_assignment({'name': 'a'}, 'y-b', 2)
+
+
+def test_pattern_properties_invalid_pattern():
+    with pytest.raises(SchemaError, match="`pattern_properties` has an invalid pattern '\\(x'"):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                {}, pattern_properties=[{'pattern': '(x', 'schema': core_schema.any_schema()}]
+            )
+        )
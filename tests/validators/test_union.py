@@ -1,3 +1,4 @@
+import logging
 from dataclasses import dataclass
 from datetime import date, time
 from enum import Enum, IntEnum
@@ -495,6 +496,66 @@ def test_left_to_right_union_strict():
     assert isinstance(out, float)
 
 
+def test_best_match_union():
+    choices = [core_schema.float_schema(), core_schema.int_schema()]
+
+    # smart union prefers the exact type match
+    v = SchemaValidator(core_schema.union_schema(choices, mode='smart'))
+    out = v.validate_python(1)
+    assert out == 1
+    assert isinstance(out, int)
+
+    # best_match union selects the leftmost choice which succeeds in strict mode
+    v = SchemaValidator(core_schema.union_schema(choices, mode='best_match'))
+    out = v.validate_python(1)
+    assert out == 1.0
+    assert isinstance(out, float)
+
+    out = v.validate_python('1')
+    assert out == 1.0
+    assert isinstance(out, float)
+
+    # strict int doesn't accept a float, so the float choice is used even though it's to the right
+    v = SchemaValidator(core_schema.union_schema(list(reversed(choices)), mode='best_match'))
+    out = v.validate_python(1.0)
+    assert out == 1.0
+    assert isinstance(out, float)
+
+    # neither choice succeeds in strict mode, so the leftmost lax success is used
+    out = v.validate_python('1')
+    assert out == 1
+    assert isinstance(out, int)
+
+
+def test_best_match_union_errors():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()], mode='best_match')
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('int',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'bool_parsing',
+            'loc': ('bool',),
+            'msg': 'Input should be a valid boolean, unable to interpret input',
+            'input': 'x',
+        },
+    ]
+
+
+def test_union_invalid_mode():
+    with pytest.raises(
+        SchemaError, match='Invalid union mode: `foo`, expected `smart`, `left_to_right` or `best_match`'
+    ):
+        SchemaValidator({'type': 'union', 'choices': [{'type': 'int'}, {'type': 'str'}], 'mode': 'foo'})
+
+
 def test_union_function_before_called_once():
     # See https://github.com/pydantic/pydantic/issues/6830 - in particular the
     # smart union validator used to call `remove_prefix` twice, which is not
@@ -803,3 +864,119 @@ def test_model_and_literal_union() -> None:
     assert isinstance(m, ModelA)
     assert m.a == 42
     assert validator.validate_python(True) is True
+
+
+def test_union_priority():
+    v = SchemaValidator(
+        validate_core_schema(
+            core_schema.union_schema(
+                [
+                    core_schema.str_schema(),
+                    {**core_schema.int_schema(), 'priority': 1},
+                    {**core_schema.float_schema(), 'priority': 2},
+                    core_schema.bool_schema(),
+                ],
+                mode='left_to_right',
+            )
+        )
+    )
+    assert plain_repr(v).startswith('SchemaValidator(title="union[float,int,str,bool]"')
+    assert v.validate_python(1) == IsFloat(exactly=1.0)
+    assert v.validate_python('1') == IsFloat(exactly=1.0)
+    assert v.validate_python('x') == 'x'
+
+
+def test_union_priority_ties_keep_order():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                {**core_schema.str_schema(), 'priority': -1},
+                core_schema.int_schema(),
+                core_schema.float_schema(),
+            ],
+            mode='left_to_right',
+        )
+    )
+    assert plain_repr(v).startswith('SchemaValidator(title="union[int,float,str]"')
+    assert v.validate_python('1') == IsInt(exactly=1)
+
+
+@pytest.mark.parametrize('mode', ['smart', 'left_to_right', 'best_match'])
+def test_union_debug(mode):
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.int_schema(strict=True), (core_schema.str_schema(), 'text')], mode=mode, union_debug=True
+        )
+    )
+    logger = logging.getLogger('pydantic_core.union')
+    messages = []
+
+    class ListHandler(logging.Handler):
+        def emit(self, record: logging.LogRecord) -> None:
+            messages.append(record.getMessage())
+
+    handler = ListHandler()
+    logger.addHandler(handler)
+    old_level = logger.level
+    try:
+        # nothing is logged unless the logger is enabled for DEBUG
+        logger.setLevel(logging.INFO)
+        assert v.validate_python('a') == 'a'
+        assert messages == []
+
+        logger.setLevel(logging.DEBUG)
+        assert v.validate_python('a') == 'a'
+        assert messages[0] == 'union[int,text]: `int` failed with 1 error(s)'
+        assert messages[1].startswith('union[int,text]: `text` succeeded')
+        assert messages[-1] == 'union[int,text]: selected `text`'
+
+        messages.clear()
+        with pytest.raises(ValidationError):
+            v.validate_python(1.5)
+        assert messages == [
+            'union[int,text]: `int` failed with 1 error(s)',
+            'union[int,text]: `text` failed with 1 error(s)',
+            'union[int,text]: no choice succeeded',
+        ]
+    finally:
+        logger.removeHandler(handler)
+        logger.setLevel(old_level)
+
+
+@pytest.mark.parametrize(
+    'choices,input_value,expected',
+    [
+        ([core_schema.int_schema(), core_schema.bool_schema()], 'true', True),
+        ([core_schema.int_schema(), core_schema.bool_schema()], '1', 1),
+        ([core_schema.bool_schema(), core_schema.int_schema()], '1', 1),
+        ([core_schema.bool_schema(), core_schema.int_schema()], 'false', False),
+        ([core_schema.int_schema(), core_schema.str_schema()], '"1"', '1'),
+        ([core_schema.float_schema(), core_schema.str_schema()], '"1.5"', '1.5'),
+        # an exact match is preferred over a strict one
+        ([core_schema.float_schema(), core_schema.int_schema()], '1', 1),
+        ([core_schema.float_schema(), core_schema.int_schema()], '1.5', 1.5),
+        ([core_schema.str_schema(), core_schema.list_schema()], '["a"]', ['a']),
+        # no choice accepts the JSON type, so the union's mode is used
+        ([core_schema.int_schema(), core_schema.bool_schema()], '"1"', 1),
+    ],
+)
+@pytest.mark.parametrize('mode', ['smart', 'left_to_right', 'best_match'])
+def test_union_exact_json_type(mode, choices, input_value, expected):
+    v = SchemaValidator(core_schema.union_schema(choices, mode=mode, exact_json_type=True))
+    output = v.validate_json(input_value)
+    assert output == expected
+    assert type(output) is type(expected)
+
+
+def test_union_exact_json_type_python_input():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.int_schema(), core_schema.bool_schema()], mode='left_to_right', exact_json_type=True
+        )
+    )
+    # python input is unaffected
+    assert v.validate_python(True) == 1
+    assert type(v.validate_python(True)) is int
+    assert v.validate_json('true') is True
+    with pytest.raises(ValidationError):
+        v.validate_json('[]')